@@ -114,10 +114,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// and set impl_version to 0. If only runtime
 	// implementation changes and behavior does not, then leave spec_version as
 	// is and increment impl_version.
-	spec_version: 265,
+	spec_version: 266,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 2,
+	transaction_version: 3,
 };
 
 /// The BABE epoch configuration at genesis.
@@ -1039,7 +1039,20 @@ impl pallet_lottery::Config for Runtime {
 parameter_types! {
 	pub const AssetDeposit: Balance = 100 * DOLLARS;
 	pub const ApprovalDeposit: Balance = 1 * DOLLARS;
+	pub const AccountDeposit: Balance = 1 * DOLLARS;
 	pub const StringLimit: u32 = 50;
+	pub const UriLimit: u32 = 256;
+	pub const MemoLimit: u32 = 32;
+	pub const AssetMemoEncoding: pallet_assets::MemoEncoding = pallet_assets::MemoEncoding::Utf8;
+	pub const MaxFanOut: u32 = 64;
+	pub const MaxMintTargets: u32 = 500;
+	pub const MaxMultiTransfer: u32 = 64;
+	pub const MaxFreezeBatch: u32 = 64;
+	pub const MaxRevocationBatch: u32 = 64;
+	pub const MaxScheduledTransfers: u32 = 50;
+	pub const MaxAccountsPerAsset: u32 = u32::max_value() / 2;
+	pub const MaxApprovalsPerAsset: u32 = u32::max_value() / 2;
+	pub const ApprovalExpiry: Option<BlockNumber> = None;
 	pub const MetadataDepositBase: Balance = 10 * DOLLARS;
 	pub const MetadataDepositPerByte: Balance = 1 * DOLLARS;
 }
@@ -1054,9 +1067,26 @@ impl pallet_assets::Config for Runtime {
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
+	type AccountDeposit = AccountDeposit;
 	type StringLimit = StringLimit;
+	type UriLimit = UriLimit;
+	type MemoLimit = MemoLimit;
+	type MemoEncoding = AssetMemoEncoding;
+	type MaxFanOut = MaxFanOut;
+	type MaxMintTargets = MaxMintTargets;
+	type MaxMultiTransfer = MaxMultiTransfer;
+	type MaxFreezeBatch = MaxFreezeBatch;
+	type MaxRevocationBatch = MaxRevocationBatch;
+	type MaxScheduledPerBlock = MaxScheduledTransfers;
+	type MaxAccountsPerAsset = MaxAccountsPerAsset;
+	type MaxApprovalsPerAsset = MaxApprovalsPerAsset;
+	type ApprovalExpiry = ApprovalExpiry;
 	type Freezer = ();
+	type OnTransfer = ();
+	type TransferGuard = ();
 	type Extra = ();
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as traits::Verify>::Signer;
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1382,6 +1412,85 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_assets_rpc_runtime_api::AssetsApi<
+		Block,
+		u32,
+		AccountId,
+		u64,
+	> for Runtime {
+		fn get_approval(id: u32, owner: AccountId, delegate: AccountId) -> u64 {
+			Assets::approval_allowance(id, &owner, &delegate)
+		}
+
+		fn asset_ids(start: Option<u32>, limit: u32) -> Vec<u32> {
+			Assets::asset_ids_page(start, limit.min(pallet_assets_rpc_runtime_api::MAX_PAGE_SIZE))
+		}
+
+		fn holders_of(id: u32, start: Option<AccountId>, limit: u32) -> Vec<(AccountId, u64)> {
+			Assets::holders_page(id, start, limit.min(pallet_assets_rpc_runtime_api::MAX_PAGE_SIZE))
+		}
+
+		fn approvals_of(
+			id: u32,
+			start: Option<(AccountId, AccountId)>,
+			limit: u32,
+		) -> Vec<(AccountId, AccountId, u64)> {
+			let limit = limit.min(pallet_assets_rpc_runtime_api::MAX_PAGE_SIZE);
+			Assets::approvals_page(id, start, limit)
+		}
+
+		fn balance(id: u32, who: AccountId) -> u64 {
+			Assets::balance(id, who)
+		}
+
+		fn total_supply(id: u32) -> u64 {
+			Assets::total_supply(id)
+		}
+
+		fn minimum_balance(id: u32) -> u64 {
+			Assets::asset_details(id).map(|d| d.min_balance()).unwrap_or_default()
+		}
+
+		fn asset_details(
+			id: u32,
+		) -> Option<pallet_assets_rpc_runtime_api::AssetDetailsRpc<AccountId, u64>> {
+			Assets::asset_details(id).map(|d| pallet_assets_rpc_runtime_api::AssetDetailsRpc {
+				owner: d.owner().clone(),
+				issuer: d.issuer().clone(),
+				admin: d.admin().clone(),
+				freezer: d.freezer().clone(),
+				supply: d.supply(),
+				min_balance: d.min_balance(),
+				is_sufficient: d.is_sufficient(),
+				accounts: d.accounts(),
+				sufficients: d.sufficients(),
+				approvals: d.approvals(),
+				is_frozen: d.is_frozen(),
+			})
+		}
+
+		fn metadata(id: u32) -> Option<pallet_assets_rpc_runtime_api::AssetMetadataRpc> {
+			Assets::metadata(id).map(|m| pallet_assets_rpc_runtime_api::AssetMetadataRpc {
+				name: m.name().to_vec(),
+				symbol: m.symbol().to_vec(),
+				decimals: m.decimals(),
+				is_frozen: m.is_frozen(),
+			})
+		}
+
+		fn destroy_witness(id: u32) -> Option<pallet_assets_rpc_runtime_api::DestroyWitnessRpc> {
+			Assets::destroy_witness(id).map(|w| pallet_assets_rpc_runtime_api::DestroyWitnessRpc {
+				accounts: w.accounts(),
+				sufficients: w.sufficients(),
+				approvals: w.approvals(),
+			})
+		}
+
+		fn pending_owner(id: u32) -> Option<AccountId> {
+			Assets::pending_owner(id)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
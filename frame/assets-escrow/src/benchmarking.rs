@@ -0,0 +1,122 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Assets escrow pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use sp_runtime::traits::Saturating;
+use frame_system::RawOrigin as SystemOrigin;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller, impl_benchmark_test_suite};
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+use frame_support::traits::tokens::fungibles::Mutate;
+
+use crate::Pallet as AssetsEscrow;
+
+const SEED: u32 = 0;
+
+/// Create the default asset, minting some into the whitelisted caller's account, and place
+/// `amount` of it in escrow for a beneficiary with its arbiter.
+fn create_default_escrow<T: Config>(
+	amount: BalanceOf<T>,
+) -> (T::AccountId, T::AccountId, T::AccountId) {
+	let caller: T::AccountId = whitelisted_caller();
+	let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+	let arbiter: T::AccountId = account("arbiter", 0, SEED);
+	let call = pallet_assets::Call::<T>::force_create(
+		Default::default(),
+		T::Lookup::unlookup(caller.clone()),
+		true,
+		1u32.into(),
+		None,
+	);
+	let origin = <T as pallet_assets::Config>::ForceOrigin::successful_origin();
+	assert!(call.dispatch_bypass_filter(origin).is_ok());
+	assert!(<pallet_assets::Pallet<T> as Mutate<T::AccountId>>::mint_into(
+		Default::default(),
+		&caller,
+		amount.saturating_add(amount),
+	).is_ok());
+	(caller, beneficiary, arbiter)
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	create_escrow {
+		let amount = 100u32.into();
+		let (caller, beneficiary, arbiter) = create_default_escrow::<T>(amount);
+		let beneficiary_lookup = T::Lookup::unlookup(beneficiary.clone());
+		let arbiter_lookup = T::Lookup::unlookup(arbiter.clone());
+	}: _(
+		SystemOrigin::Signed(caller.clone()),
+		Default::default(),
+		beneficiary_lookup,
+		arbiter_lookup,
+		amount,
+		Zero::zero()
+	)
+	verify {
+		let event = Event::EscrowCreated(
+			0,
+			Default::default(),
+			caller,
+			beneficiary,
+			arbiter,
+			amount,
+			Zero::zero(),
+		);
+		assert_last_event::<T>(event.into());
+	}
+
+	release_escrow {
+		let amount = 100u32.into();
+		let (caller, beneficiary, arbiter) = create_default_escrow::<T>(amount);
+		AssetsEscrow::<T>::create_escrow(
+			SystemOrigin::Signed(caller).into(),
+			Default::default(),
+			T::Lookup::unlookup(beneficiary),
+			T::Lookup::unlookup(arbiter.clone()),
+			amount,
+			Zero::zero(),
+		)?;
+	}: _(SystemOrigin::Signed(arbiter), 0)
+	verify {
+		assert_last_event::<T>(Event::EscrowReleased(0).into());
+	}
+
+	refund_escrow {
+		let amount = 100u32.into();
+		let (caller, beneficiary, arbiter) = create_default_escrow::<T>(amount);
+		AssetsEscrow::<T>::create_escrow(
+			SystemOrigin::Signed(caller).into(),
+			Default::default(),
+			T::Lookup::unlookup(beneficiary),
+			T::Lookup::unlookup(arbiter.clone()),
+			amount,
+			Zero::zero(),
+		)?;
+	}: _(SystemOrigin::Signed(arbiter), 0)
+	verify {
+		assert_last_event::<T>(Event::EscrowRefunded(0).into());
+	}
+}
+
+impl_benchmark_test_suite!(AssetsEscrow, crate::mock::new_test_ext(), crate::mock::Test);
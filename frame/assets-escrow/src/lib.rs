@@ -0,0 +1,280 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Assets Escrow Pallet
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! A simple pallet providing conditional transfers of assets from the Assets pallet. A depositor
+//! places some of an asset in escrow for a beneficiary, and a third party arbiter decides whether
+//! the funds are released to the beneficiary or refunded to the depositor.
+//!
+//! Escrowed funds stay in the depositor's account, held there by the Assets pallet through its
+//! `fungibles::MutateHold` implementation. Held funds are kept apart from any frozen balance, so
+//! they cannot be spent until the escrow is released or refunded.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `create_escrow` - Place some of an asset in escrow for a beneficiary.
+//! - `release_escrow` - Transfer the escrowed funds to the beneficiary. The sender must be the
+//!   arbiter of the escrow.
+//! - `refund_escrow` - Return the escrowed funds to the depositor. The sender must be the arbiter
+//!   of the escrow or, once it has expired, the depositor.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use sp_std::prelude::*;
+use codec::{Encode, Decode};
+use sp_runtime::{RuntimeDebug, traits::{StaticLookup, Zero}};
+use frame_support::{ensure, transactional, pallet_prelude::*};
+use frame_support::traits::tokens::fungibles::MutateHold;
+use frame_system::{ensure_signed, pallet_prelude::*};
+pub use weights::WeightInfo;
+pub use pallet::*;
+
+type AssetIdOf<T> = <T as pallet_assets::Config>::AssetId;
+type BalanceOf<T> = <T as pallet_assets::Config>::Balance;
+type EscrowDetailsOf<T> = EscrowDetails<
+	AssetIdOf<T>,
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+/// The identifier of an escrow.
+pub type EscrowId = u32;
+
+/// The details of a conditional transfer held in escrow.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct EscrowDetails<AssetId, AccountId, Balance, BlockNumber> {
+	/// The asset held in escrow.
+	pub asset: AssetId,
+	/// The account whose funds are held.
+	pub depositor: AccountId,
+	/// The account which receives the funds when they are released.
+	pub beneficiary: AccountId,
+	/// The account which may release or refund the funds.
+	pub arbiter: AccountId,
+	/// The amount held.
+	pub amount: Balance,
+	/// The block after which the depositor may refund the funds themselves.
+	pub expiry: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_assets::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The identifier of the next escrow to be created.
+	#[pallet::storage]
+	pub type NextEscrowId<T: Config> = StorageValue<_, EscrowId, ValueQuery>;
+
+	/// Details of the open escrows.
+	#[pallet::storage]
+	#[pallet::getter(fn escrow)]
+	pub type Escrows<T: Config> = StorageMap<_, Blake2_128Concat, EscrowId, EscrowDetailsOf<T>>;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(
+		AssetIdOf<T> = "AssetId",
+		T::AccountId = "AccountId",
+		BalanceOf<T> = "Balance",
+		T::BlockNumber = "BlockNumber",
+	)]
+	pub enum Event<T: Config> {
+		/// Some of an asset was placed in escrow.
+		/// \[escrow_id, asset_id, depositor, beneficiary, arbiter, amount, expiry\]
+		EscrowCreated(
+			EscrowId,
+			AssetIdOf<T>,
+			T::AccountId,
+			T::AccountId,
+			T::AccountId,
+			BalanceOf<T>,
+			T::BlockNumber,
+		),
+		/// The funds of an escrow were transferred to the beneficiary. \[escrow_id\]
+		EscrowReleased(EscrowId),
+		/// The funds of an escrow were returned to the depositor. \[escrow_id\]
+		EscrowRefunded(EscrowId),
+	}
+
+	/// Error for the assets escrow pallet.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The given asset ID is unknown.
+		Unknown,
+		/// The given escrow ID is unknown.
+		UnknownEscrow,
+		/// The signing account has no permission to do the operation.
+		NoPermission,
+		/// The escrow has not yet expired.
+		NotExpired,
+		/// An escrow must hold a non-zero amount.
+		AmountZero,
+		/// No further escrows can be created.
+		Overflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Place some of asset `id` in escrow for a `beneficiary`.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have at least
+		/// `amount` of the asset transferable while staying alive. The funds remain in the
+		/// sender's account, held, until the escrow is released or refunded.
+		///
+		/// - `id`: The identifier of the asset to be held.
+		/// - `beneficiary`: The account which receives the funds when they are released.
+		/// - `arbiter`: The account which may release or refund the funds.
+		/// - `amount`: The amount to be held.
+		/// - `expiry`: The block after which the sender may refund the funds themselves.
+		///
+		/// Emits `EscrowCreated` with the identifier of the new escrow.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::create_escrow())]
+		pub fn create_escrow(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: AssetIdOf<T>,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+			arbiter: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: BalanceOf<T>,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let depositor = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			let arbiter = T::Lookup::lookup(arbiter)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			ensure!(pallet_assets::Pallet::<T>::asset_details(id).is_some(), Error::<T>::Unknown);
+
+			let escrow_id = NextEscrowId::<T>::get();
+			let next_id = escrow_id.checked_add(1).ok_or(Error::<T>::Overflow)?;
+
+			<pallet_assets::Pallet<T> as MutateHold<T::AccountId>>::hold(id, &depositor, amount)?;
+			NextEscrowId::<T>::put(next_id);
+			Escrows::<T>::insert(escrow_id, EscrowDetails {
+				asset: id,
+				depositor: depositor.clone(),
+				beneficiary: beneficiary.clone(),
+				arbiter: arbiter.clone(),
+				amount,
+				expiry,
+			});
+			Self::deposit_event(Event::EscrowCreated(
+				escrow_id,
+				id,
+				depositor,
+				beneficiary,
+				arbiter,
+				amount,
+				expiry,
+			));
+			Ok(())
+		}
+
+		/// Transfer the funds of an escrow to its beneficiary.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the arbiter
+		/// of the escrow.
+		///
+		/// - `escrow_id`: The identifier of the escrow to be released.
+		///
+		/// Emits `EscrowReleased`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::release_escrow())]
+		#[transactional]
+		pub fn release_escrow(origin: OriginFor<T>, escrow_id: EscrowId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let escrow = Escrows::<T>::take(escrow_id).ok_or(Error::<T>::UnknownEscrow)?;
+			ensure!(origin == escrow.arbiter, Error::<T>::NoPermission);
+
+			<pallet_assets::Pallet<T> as MutateHold<T::AccountId>>::transfer_held(
+				escrow.asset,
+				&escrow.depositor,
+				&escrow.beneficiary,
+				escrow.amount,
+				false,
+				false,
+			)?;
+			Self::deposit_event(Event::EscrowReleased(escrow_id));
+			Ok(())
+		}
+
+		/// Return the funds of an escrow to its depositor.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be either the
+		/// arbiter of the escrow or, once the escrow has expired, its depositor.
+		///
+		/// - `escrow_id`: The identifier of the escrow to be refunded.
+		///
+		/// Emits `EscrowRefunded`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::refund_escrow())]
+		pub fn refund_escrow(origin: OriginFor<T>, escrow_id: EscrowId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let escrow = Escrows::<T>::get(escrow_id).ok_or(Error::<T>::UnknownEscrow)?;
+			if origin != escrow.arbiter {
+				ensure!(origin == escrow.depositor, Error::<T>::NoPermission);
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(now > escrow.expiry, Error::<T>::NotExpired);
+			}
+
+			Escrows::<T>::remove(escrow_id);
+			// Nothing is left held should the asset have been destroyed since.
+			<pallet_assets::Pallet<T> as MutateHold<T::AccountId>>::release(
+				escrow.asset,
+				&escrow.depositor,
+				escrow.amount,
+				true,
+			)?;
+			Self::deposit_event(Event::EscrowRefunded(escrow_id));
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,161 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for Assets Escrow pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_ok, assert_noop, dispatch::DispatchResultWithPostInfo};
+use frame_support::traits::UnfilteredDispatchable;
+use frame_support::traits::tokens::fungibles::InspectHold;
+
+fn assets(origin: Origin, call: pallet_assets::Call<Test>) -> DispatchResultWithPostInfo {
+	call.dispatch_bypass_filter(origin)
+}
+
+fn held(who: u64) -> u64 {
+	<Assets as InspectHold<u64>>::balance_on_hold(0, &who)
+}
+
+fn create_asset() {
+	assert_ok!(assets(Origin::root(), pallet_assets::Call::force_create(0, 1, true, 1, None)));
+	assert_ok!(assets(Origin::signed(1), pallet_assets::Call::mint(0, 1, 100)));
+}
+
+#[test]
+fn create_escrow_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsEscrow::create_escrow(Origin::signed(1), 0, 2, 3, 50, 10),
+			Error::<Test>::Unknown,
+		);
+		create_asset();
+		assert_noop!(
+			AssetsEscrow::create_escrow(Origin::signed(1), 0, 2, 3, 0, 10),
+			Error::<Test>::AmountZero,
+		);
+		assert_noop!(
+			AssetsEscrow::create_escrow(Origin::signed(1), 0, 2, 3, 100, 10),
+			pallet_assets::Error::<Test>::BalanceLow,
+		);
+
+		assert_ok!(AssetsEscrow::create_escrow(Origin::signed(1), 0, 2, 3, 50, 10));
+		let event = crate::Event::EscrowCreated(0, 0, 1, 2, 3, 50, 10);
+		System::assert_last_event(mock::Event::pallet_assets_escrow(event));
+		assert_eq!(held(1), 50);
+		assert_eq!(AssetsEscrow::escrow(0).map(|e| e.amount), Some(50));
+
+		// Held funds can neither be transferred nor placed in escrow again.
+		assert_noop!(
+			assets(Origin::signed(1), pallet_assets::Call::transfer(0, 2, 50)),
+			pallet_assets::Error::<Test>::BalanceLow,
+		);
+		assert_noop!(
+			AssetsEscrow::create_escrow(Origin::signed(1), 0, 2, 3, 50, 10),
+			pallet_assets::Error::<Test>::BalanceLow,
+		);
+		assert_ok!(assets(Origin::signed(1), pallet_assets::Call::transfer(0, 2, 20)));
+		assert_ok!(AssetsEscrow::create_escrow(Origin::signed(1), 0, 2, 3, 29, 10));
+		assert_eq!(held(1), 79);
+	});
+}
+
+#[test]
+fn release_escrow_should_work() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(AssetsEscrow::create_escrow(Origin::signed(1), 0, 2, 3, 50, 10));
+		assert_noop!(
+			AssetsEscrow::release_escrow(Origin::signed(3), 1),
+			Error::<Test>::UnknownEscrow,
+		);
+		assert_noop!(
+			AssetsEscrow::release_escrow(Origin::signed(1), 0),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(
+			AssetsEscrow::release_escrow(Origin::signed(2), 0),
+			Error::<Test>::NoPermission,
+		);
+
+		assert_ok!(AssetsEscrow::release_escrow(Origin::signed(3), 0));
+		let event = crate::Event::EscrowReleased(0);
+		System::assert_last_event(mock::Event::pallet_assets_escrow(event));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_eq!(held(1), 0);
+		assert_eq!(AssetsEscrow::escrow(0), None);
+		assert_ok!(assets(Origin::signed(1), pallet_assets::Call::transfer(0, 2, 50)));
+	});
+}
+
+#[test]
+fn refund_escrow_should_work() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(AssetsEscrow::create_escrow(Origin::signed(1), 0, 2, 3, 50, 10));
+		assert_ok!(AssetsEscrow::create_escrow(Origin::signed(1), 0, 2, 3, 40, 10));
+
+		// The arbiter may refund at any time.
+		assert_ok!(AssetsEscrow::refund_escrow(Origin::signed(3), 0));
+		let event = crate::Event::EscrowRefunded(0);
+		System::assert_last_event(mock::Event::pallet_assets_escrow(event));
+		assert_eq!(held(1), 40);
+		assert_eq!(Assets::balance(0, 1), 100);
+
+		// The depositor only once the escrow has expired, and the beneficiary never.
+		System::set_block_number(10);
+		assert_noop!(AssetsEscrow::refund_escrow(Origin::signed(1), 1), Error::<Test>::NotExpired);
+		System::set_block_number(11);
+		assert_noop!(
+			AssetsEscrow::refund_escrow(Origin::signed(2), 1),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(AssetsEscrow::refund_escrow(Origin::signed(1), 1));
+		assert_eq!(held(1), 0);
+		assert_eq!(AssetsEscrow::escrow(1), None);
+		assert_noop!(
+			AssetsEscrow::release_escrow(Origin::signed(3), 1),
+			Error::<Test>::UnknownEscrow,
+		);
+	});
+}
+
+#[test]
+fn escrowed_funds_are_kept_apart_from_frozen_funds() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(assets(Origin::signed(1), pallet_assets::Call::mint(0, 1, 100)));
+		set_frozen_balance(1, 100);
+
+		// Only what is neither frozen nor needed for the minimum balance may be placed in escrow.
+		assert_noop!(
+			AssetsEscrow::create_escrow(Origin::signed(1), 0, 2, 3, 100, 10),
+			pallet_assets::Error::<Test>::BalanceLow,
+		);
+		assert_ok!(AssetsEscrow::create_escrow(Origin::signed(1), 0, 2, 3, 99, 10));
+
+		// The escrow adds to the frozen amount rather than overlapping it.
+		assert_noop!(
+			assets(Origin::signed(1), pallet_assets::Call::transfer(0, 4, 1)),
+			pallet_assets::Error::<Test>::BalanceLow,
+		);
+		assert_ok!(AssetsEscrow::release_escrow(Origin::signed(3), 0));
+		assert_eq!(Assets::balance(0, 1), 101);
+		assert_eq!(Assets::balance(0, 2), 99);
+	});
+}
@@ -0,0 +1,136 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Assets vesting pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use sp_runtime::traits::Saturating;
+use frame_system::RawOrigin as SystemOrigin;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller, impl_benchmark_test_suite};
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+
+use crate::Pallet as AssetsVesting;
+
+const SEED: u32 = 0;
+
+/// Create the default asset with the whitelisted caller as its owner and team, minting `amount`
+/// into the caller's account.
+fn create_default_asset<T: Config>(amount: BalanceOf<T>) -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	let caller_lookup = T::Lookup::unlookup(caller.clone());
+	let call = pallet_assets::Call::<T>::force_create(
+		Default::default(),
+		caller_lookup,
+		true,
+		1u32.into(),
+		None,
+	);
+	let origin = <T as pallet_assets::Config>::ForceOrigin::successful_origin();
+	assert!(call.dispatch_bypass_filter(origin).is_ok());
+	if !amount.is_zero() {
+		assert!(<pallet_assets::Pallet<T> as Mutate<T::AccountId>>::mint_into(
+			Default::default(),
+			&caller,
+			amount,
+		).is_ok());
+	}
+	caller
+}
+
+/// Give `who` the minimum balance of the default asset, which must be kept besides held funds.
+fn fund<T: Config>(who: &T::AccountId) {
+	assert!(<pallet_assets::Pallet<T> as Mutate<T::AccountId>>::mint_into(
+		Default::default(),
+		who,
+		1u32.into(),
+	).is_ok());
+}
+
+fn schedule<T: Config>() -> VestingInfoOf<T> {
+	VestingInfo {
+		locked: T::MinVestedTransfer::get().max(100u32.into()),
+		per_block: 1u32.into(),
+		starting_block: Zero::zero(),
+	}
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	vest {
+		let caller = create_default_asset::<T>(1u32.into());
+		let schedule = schedule::<T>();
+		AssetsVesting::<T>::add_vesting_schedule(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			T::Lookup::unlookup(caller.clone()),
+			schedule,
+		)?;
+		frame_system::Pallet::<T>::set_block_number(u32::max_value().into());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default())
+	verify {
+		assert_last_event::<T>(Event::VestingCompleted(Default::default(), caller).into());
+	}
+
+	vest_other {
+		let caller = create_default_asset::<T>(Zero::zero());
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		fund::<T>(&target);
+		AssetsVesting::<T>::add_vesting_schedule(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			target_lookup.clone(),
+			schedule::<T>(),
+		)?;
+		frame_system::Pallet::<T>::set_block_number(u32::max_value().into());
+	}: _(SystemOrigin::Signed(caller), Default::default(), target_lookup)
+	verify {
+		assert_last_event::<T>(Event::VestingCompleted(Default::default(), target).into());
+	}
+
+	vested_transfer {
+		let schedule = schedule::<T>();
+		let amount = schedule.locked.saturating_add(schedule.locked);
+		let caller = create_default_asset::<T>(amount);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		fund::<T>(&target);
+	}: _(SystemOrigin::Signed(caller), Default::default(), target_lookup, schedule)
+	verify {
+		let event = Event::VestingCreated(Default::default(), target, schedule.locked);
+		assert_last_event::<T>(event.into());
+	}
+
+	add_vesting_schedule {
+		let schedule = schedule::<T>();
+		let caller = create_default_asset::<T>(Zero::zero());
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		fund::<T>(&target);
+	}: _(SystemOrigin::Signed(caller), Default::default(), target_lookup, schedule)
+	verify {
+		let event = Event::VestingCreated(Default::default(), target, schedule.locked);
+		assert_last_event::<T>(event.into());
+	}
+}
+
+impl_benchmark_test_suite!(AssetsVesting, crate::mock::new_test_ext(), crate::mock::Test);
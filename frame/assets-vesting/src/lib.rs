@@ -0,0 +1,295 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Assets Vesting Pallet
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! A simple pallet providing a means of placing a linear curve on an account's balance of an
+//! asset from the Assets pallet. The amount placed under a vesting schedule is held in the
+//! account by the Assets pallet through its `fungibles::MutateHold` implementation, so it adds to
+//! any frozen balance or other holds of the account rather than overlapping them.
+//!
+//! As with the Vesting pallet, vested funds only become transferable once `vest` or `vest_other`
+//! releases them from the hold. The account must keep at least the minimum balance of the asset
+//! besides the held amount, so a schedule can only be placed on an account which has, or is given,
+//! more than the `locked` amount of the schedule.
+//!
+//! The Assets pallet keeps a single held amount per account, shared by all of its holders, so
+//! other pallets releasing held funds of an account may release funds held for its vesting.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `vest` - Release the funds of the sender's vesting schedule of an asset vested so far.
+//! - `vest_other` - Release the funds of another account's vesting schedule of an asset vested so
+//!   far.
+//! - `vested_transfer` - Transfer some of an asset to an account, vesting it under a schedule.
+//! - `add_vesting_schedule` - Mint some of an asset into an account, vesting it under a schedule.
+//!   The sender must be the Issuer of the asset.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use sp_std::prelude::*;
+use sp_runtime::traits::{StaticLookup, Zero, Convert, Saturating};
+use frame_support::{ensure, pallet_prelude::*, transactional};
+use frame_support::traits::tokens::fungibles::{Mutate, MutateHold, Transfer};
+use frame_system::{ensure_signed, pallet_prelude::*};
+pub use pallet_vesting::VestingInfo;
+pub use weights::WeightInfo;
+pub use pallet::*;
+
+type AssetIdOf<T> = <T as pallet_assets::Config>::AssetId;
+type BalanceOf<T> = <T as pallet_assets::Config>::Balance;
+type VestingInfoOf<T> = VestingInfo<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_assets::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Convert the block number into a balance.
+		type BlockNumberToBalance: Convert<Self::BlockNumber, BalanceOf<Self>>;
+
+		/// The minimum amount transferred to call `vested_transfer`.
+		#[pallet::constant]
+		type MinVestedTransfer: Get<BalanceOf<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Information regarding the vesting of a given account of a given asset.
+	#[pallet::storage]
+	#[pallet::getter(fn vesting)]
+	pub type Vesting<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetIdOf<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		VestingInfoOf<T>,
+	>;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(
+		AssetIdOf<T> = "AssetId",
+		T::AccountId = "AccountId",
+		BalanceOf<T> = "Balance",
+	)]
+	pub enum Event<T: Config> {
+		/// Some of an asset has been placed under a vesting schedule. \[asset_id, account, locked\]
+		VestingCreated(AssetIdOf<T>, T::AccountId, BalanceOf<T>),
+		/// The vested funds of an account have been released, leaving an amount still held.
+		/// \[asset_id, account, unvested\]
+		VestingUpdated(AssetIdOf<T>, T::AccountId, BalanceOf<T>),
+		/// An \[asset_id, account\] has become fully vested. No further vesting can happen.
+		VestingCompleted(AssetIdOf<T>, T::AccountId),
+	}
+
+	/// Error for the assets vesting pallet.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account given is not vesting.
+		NotVesting,
+		/// An existing vesting schedule already exists for this account that cannot be clobbered.
+		ExistingVestingSchedule,
+		/// Amount being transferred is too low to create a vesting schedule.
+		AmountLow,
+		/// The given asset ID is unknown.
+		Unknown,
+		/// The signing account has no permission to do the operation.
+		NoPermission,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Release the funds of the sender's vesting schedule for asset `id` vested so far.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have a vesting
+		/// schedule for the asset. The schedule is removed once it has completed.
+		///
+		/// Emits `VestingCompleted` if the schedule has completed, or `VestingUpdated` otherwise.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::vest())]
+		pub fn vest(origin: OriginFor<T>, #[pallet::compact] id: AssetIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_vest(id, who)
+		}
+
+		/// Release the funds of a `target` account's vesting schedule for asset `id` vested so
+		/// far.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `id`: The identifier of the vesting asset.
+		/// - `target`: The account whose vested funds should be released. The schedule is removed
+		/// once it has completed.
+		///
+		/// Emits `VestingCompleted` if the schedule has completed, or `VestingUpdated` otherwise.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::vest_other())]
+		pub fn vest_other(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: AssetIdOf<T>,
+			target: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_vest(id, T::Lookup::lookup(target)?)
+		}
+
+		/// Create a vested transfer of asset `id`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `id`: The identifier of the asset to be transferred.
+		/// - `target`: The account that should be transferred the vested funds.
+		/// - `schedule`: The vesting schedule attached to the transfer. Its `locked` amount is
+		/// transferred from the sender and held in the target account.
+		///
+		/// Emits `VestingCreated`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::vested_transfer())]
+		#[transactional]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: AssetIdOf<T>,
+			target: <T::Lookup as StaticLookup>::Source,
+			schedule: VestingInfoOf<T>,
+		) -> DispatchResult {
+			let transactor = ensure_signed(origin)?;
+			ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+
+			let who = T::Lookup::lookup(target)?;
+			ensure!(!Vesting::<T>::contains_key(id, &who), Error::<T>::ExistingVestingSchedule);
+
+			<pallet_assets::Pallet<T> as Transfer<T::AccountId>>::transfer(
+				id,
+				&transactor,
+				&who,
+				schedule.locked,
+				false,
+			)?;
+			Self::do_add_vesting_schedule(id, who, schedule)
+		}
+
+		/// Mint some of asset `id` into a `target` account under a vesting schedule.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the Issuer of
+		/// the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be minted.
+		/// - `target`: The account that should be minted the vested funds.
+		/// - `schedule`: The vesting schedule attached to the funds. Its `locked` amount is minted
+		/// and held in the target account.
+		///
+		/// Emits `VestingCreated`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::add_vesting_schedule())]
+		#[transactional]
+		pub fn add_vesting_schedule(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: AssetIdOf<T>,
+			target: <T::Lookup as StaticLookup>::Source,
+			schedule: VestingInfoOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			ensure!(!schedule.locked.is_zero(), Error::<T>::AmountLow);
+
+			let details = pallet_assets::Pallet::<T>::asset_details(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(details.issuer() == &origin, Error::<T>::NoPermission);
+			let who = T::Lookup::lookup(target)?;
+			ensure!(!Vesting::<T>::contains_key(id, &who), Error::<T>::ExistingVestingSchedule);
+
+			let locked = schedule.locked;
+			<pallet_assets::Pallet<T> as Mutate<T::AccountId>>::mint_into(id, &who, locked)?;
+			Self::do_add_vesting_schedule(id, who, schedule)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The amount of asset `id` which is still unvested for `who`.
+	///
+	/// This may be less than the amount held for the schedule, which is its `locked` amount,
+	/// until the vested funds are released with `vest` or `vest_other`.
+	pub fn locked(id: AssetIdOf<T>, who: &T::AccountId) -> Option<BalanceOf<T>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		Vesting::<T>::get(id, who).map(|v| v.locked_at::<T::BlockNumberToBalance>(now))
+	}
+
+	/// Hold the `locked` amount of `schedule` in the account of `who` and record the schedule.
+	fn do_add_vesting_schedule(
+		id: AssetIdOf<T>,
+		who: T::AccountId,
+		schedule: VestingInfoOf<T>,
+	) -> DispatchResult {
+		<pallet_assets::Pallet<T> as MutateHold<T::AccountId>>::hold(id, &who, schedule.locked)?;
+		Vesting::<T>::insert(id, &who, schedule);
+		Self::deposit_event(Event::<T>::VestingCreated(id, who, schedule.locked));
+		Ok(())
+	}
+
+	/// Release the funds of the vesting schedule of `who` for asset `id` vested so far, removing
+	/// the schedule if it has completed.
+	fn do_vest(id: AssetIdOf<T>, who: T::AccountId) -> DispatchResult {
+		let schedule = Vesting::<T>::get(id, &who).ok_or(Error::<T>::NotVesting)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let locked = schedule.locked_at::<T::BlockNumberToBalance>(now);
+		let vested = schedule.locked.saturating_sub(locked);
+		// Nothing is left held should the asset have been destroyed since.
+		<pallet_assets::Pallet<T> as MutateHold<T::AccountId>>::release(id, &who, vested, true)?;
+
+		if locked.is_zero() {
+			Vesting::<T>::remove(id, &who);
+			Self::deposit_event(Event::<T>::VestingCompleted(id, who));
+		} else {
+			// The remaining schedule follows the same curve from now on.
+			let starting_block = schedule.starting_block.max(now);
+			Vesting::<T>::insert(id, &who, VestingInfo { locked, starting_block, ..schedule });
+			Self::deposit_event(Event::<T>::VestingUpdated(id, who, locked));
+		}
+		Ok(())
+	}
+}
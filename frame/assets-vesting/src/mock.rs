@@ -0,0 +1,160 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Assets Vesting pallet.
+
+use super::*;
+use crate as pallet_assets_vesting;
+
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup, Identity},
+	testing::{Header, TestSignature, UintAuthorityId},
+};
+use frame_support::{parameter_types, construct_runtime};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		AssetsVesting: pallet_assets_vesting::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+}
+
+parameter_types! {
+	pub const AssetDeposit: u64 = 1;
+	pub const ApprovalDeposit: u64 = 1;
+	pub const AccountDeposit: u64 = 10;
+	pub const StringLimit: u32 = 50;
+	pub const UriLimit: u32 = 256;
+	pub const MemoLimit: u32 = 32;
+	pub const AssetMemoEncoding: pallet_assets::MemoEncoding = pallet_assets::MemoEncoding::Utf8;
+	pub const MaxFanOut: u32 = 5;
+	pub const MaxMintTargets: u32 = 5;
+	pub const MaxMultiTransfer: u32 = 5;
+	pub const MaxFreezeBatch: u32 = 5;
+	pub const MaxRevocationBatch: u32 = 5;
+	pub const MaxScheduledPerBlock: u32 = 2;
+	pub const MaxAccountsPerAsset: u32 = u32::max_value() / 2;
+	pub const MaxApprovalsPerAsset: u32 = u32::max_value() / 2;
+	pub const ApprovalExpiry: Option<u64> = None;
+	pub const MetadataDepositBase: u64 = 1;
+	pub const MetadataDepositPerByte: u64 = 1;
+}
+
+impl pallet_assets::Config for Test {
+	type Event = Event;
+	type Balance = u64;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type AssetDeposit = AssetDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type AccountDeposit = AccountDeposit;
+	type StringLimit = StringLimit;
+	type UriLimit = UriLimit;
+	type MemoLimit = MemoLimit;
+	type MemoEncoding = AssetMemoEncoding;
+	type MaxFanOut = MaxFanOut;
+	type MaxMintTargets = MaxMintTargets;
+	type MaxMultiTransfer = MaxMultiTransfer;
+	type MaxFreezeBatch = MaxFreezeBatch;
+	type MaxRevocationBatch = MaxRevocationBatch;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type MaxAccountsPerAsset = MaxAccountsPerAsset;
+	type MaxApprovalsPerAsset = MaxApprovalsPerAsset;
+	type ApprovalExpiry = ApprovalExpiry;
+	type Freezer = ();
+	type OnTransfer = ();
+	type TransferGuard = ();
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	type WeightInfo = ();
+	type Extra = ();
+}
+
+parameter_types! {
+	pub const MinVestedTransfer: u64 = 10;
+}
+
+impl Config for Test {
+	type Event = Event;
+	type BlockNumberToBalance = Identity;
+	type MinVestedTransfer = MinVestedTransfer;
+	type WeightInfo = ();
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
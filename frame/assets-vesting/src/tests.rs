@@ -0,0 +1,162 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for Assets Vesting pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_ok, assert_noop, dispatch::DispatchResultWithPostInfo};
+use frame_support::traits::{UnfilteredDispatchable, tokens::fungibles::InspectHold};
+
+fn assets(origin: Origin, call: pallet_assets::Call<Test>) -> DispatchResultWithPostInfo {
+	call.dispatch_bypass_filter(origin)
+}
+
+fn create_asset() {
+	assert_ok!(assets(Origin::root(), pallet_assets::Call::force_create(0, 1, true, 1, None)));
+}
+
+const SCHEDULE: VestingInfo<u64, u64> =
+	VestingInfo { locked: 100, per_block: 10, starting_block: 10 };
+
+fn held(who: u64) -> u64 {
+	<Assets as InspectHold<u64>>::balance_on_hold(0, &who)
+}
+
+#[test]
+fn add_vesting_schedule_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsVesting::add_vesting_schedule(Origin::signed(1), 0, 2, SCHEDULE),
+			Error::<Test>::Unknown,
+		);
+		create_asset();
+		assert_noop!(
+			AssetsVesting::add_vesting_schedule(Origin::signed(2), 0, 2, SCHEDULE),
+			Error::<Test>::NoPermission,
+		);
+		// The minimum balance must be kept besides the held funds.
+		assert_noop!(
+			AssetsVesting::add_vesting_schedule(Origin::signed(1), 0, 2, SCHEDULE),
+			pallet_assets::Error::<Test>::BalanceLow,
+		);
+
+		assert_ok!(assets(Origin::signed(1), pallet_assets::Call::mint(0, 2, 1)));
+		assert_ok!(AssetsVesting::add_vesting_schedule(Origin::signed(1), 0, 2, SCHEDULE));
+		let event = crate::Event::VestingCreated(0, 2, 100);
+		System::assert_last_event(mock::Event::pallet_assets_vesting(event));
+		assert_eq!(Assets::balance(0, 2), 101);
+		assert_eq!(held(2), 100);
+		assert_eq!(AssetsVesting::locked(0, &2), Some(100));
+		assert_noop!(
+			AssetsVesting::add_vesting_schedule(Origin::signed(1), 0, 2, SCHEDULE),
+			Error::<Test>::ExistingVestingSchedule,
+		);
+		assert_noop!(
+			assets(Origin::signed(2), pallet_assets::Call::transfer(0, 3, 1)),
+			pallet_assets::Error::<Test>::BalanceLow,
+		);
+	});
+}
+
+#[test]
+fn vest_releases_vested_funds() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(assets(Origin::signed(1), pallet_assets::Call::mint(0, 2, 10)));
+		assert_ok!(AssetsVesting::add_vesting_schedule(Origin::signed(1), 0, 2, SCHEDULE));
+
+		// Only funds which are not held are transferable, minus the minimum balance.
+		assert_ok!(assets(Origin::signed(2), pallet_assets::Call::transfer(0, 3, 9)));
+		assert_noop!(
+			assets(Origin::signed(2), pallet_assets::Call::transfer(0, 3, 1)),
+			pallet_assets::Error::<Test>::BalanceLow,
+		);
+
+		// Vested funds stay held until they are released.
+		System::set_block_number(13);
+		assert_eq!(AssetsVesting::locked(0, &2), Some(70));
+		assert_eq!(held(2), 100);
+		assert_noop!(
+			assets(Origin::signed(2), pallet_assets::Call::transfer(0, 3, 1)),
+			pallet_assets::Error::<Test>::BalanceLow,
+		);
+		assert_ok!(AssetsVesting::vest(Origin::signed(2), 0));
+		let event = crate::Event::VestingUpdated(0, 2, 70);
+		System::assert_last_event(mock::Event::pallet_assets_vesting(event));
+		assert_eq!(held(2), 70);
+		assert_eq!(AssetsVesting::locked(0, &2), Some(70));
+		assert_ok!(assets(Origin::signed(2), pallet_assets::Call::transfer(0, 3, 30)));
+		assert_noop!(
+			assets(Origin::signed(2), pallet_assets::Call::transfer(0, 3, 1)),
+			pallet_assets::Error::<Test>::BalanceLow,
+		);
+
+		// The remaining schedule keeps to the same curve.
+		System::set_block_number(15);
+		assert_eq!(AssetsVesting::locked(0, &2), Some(50));
+		assert_ok!(AssetsVesting::vest(Origin::signed(2), 0));
+		assert_eq!(held(2), 50);
+
+		System::set_block_number(20);
+		assert_eq!(AssetsVesting::locked(0, &2), Some(0));
+		assert_ok!(AssetsVesting::vest(Origin::signed(2), 0));
+		let event = crate::Event::VestingCompleted(0, 2);
+		System::assert_last_event(mock::Event::pallet_assets_vesting(event));
+		assert_eq!(held(2), 0);
+		assert_eq!(AssetsVesting::vesting(0, 2), None);
+		assert_noop!(AssetsVesting::vest(Origin::signed(2), 0), Error::<Test>::NotVesting);
+		assert_ok!(assets(Origin::signed(2), pallet_assets::Call::transfer(0, 3, 71)));
+	});
+}
+
+#[test]
+fn vested_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(assets(Origin::signed(1), pallet_assets::Call::mint(0, 1, 200)));
+		assert_ok!(assets(Origin::signed(1), pallet_assets::Call::mint(0, 2, 1)));
+
+		let low = VestingInfo { locked: 9, ..SCHEDULE };
+		assert_noop!(
+			AssetsVesting::vested_transfer(Origin::signed(1), 0, 2, low),
+			Error::<Test>::AmountLow,
+		);
+		assert_ok!(AssetsVesting::vested_transfer(Origin::signed(1), 0, 2, SCHEDULE));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::balance(0, 2), 101);
+		assert_eq!(held(2), 100);
+		assert_noop!(
+			AssetsVesting::vested_transfer(Origin::signed(1), 0, 2, SCHEDULE),
+			Error::<Test>::ExistingVestingSchedule,
+		);
+		// Nothing is transferred if the funds cannot be held.
+		assert_noop!(
+			AssetsVesting::vested_transfer(Origin::signed(1), 0, 3, SCHEDULE),
+			pallet_assets::Error::<Test>::BalanceLow,
+		);
+
+		System::set_block_number(20);
+		assert_noop!(
+			AssetsVesting::vest_other(Origin::signed(3), 0, 1),
+			Error::<Test>::NotVesting,
+		);
+		assert_ok!(AssetsVesting::vest_other(Origin::signed(3), 0, 2));
+		assert_eq!(AssetsVesting::vesting(0, 2), None);
+		assert_eq!(held(2), 0);
+	});
+}
@@ -0,0 +1,178 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for assets module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Encode, Decode};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+
+/// The most entries returned by a single call of a paged query, whatever its `limit`.
+pub const MAX_PAGE_SIZE: u32 = 1_000;
+
+/// The details of an asset class, as returned by [`AssetsApi::asset_details`].
+#[derive(Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AssetDetailsRpc<AccountId, Balance> {
+	/// The account which can change the team of the asset and destroy it.
+	pub owner: AccountId,
+	/// The account which can mint tokens.
+	pub issuer: AccountId,
+	/// The account which can thaw tokens, force transfers and burn tokens from any account.
+	pub admin: AccountId,
+	/// The account which can freeze tokens.
+	pub freezer: AccountId,
+	/// The total supply across all accounts.
+	pub supply: Balance,
+	/// The minimum balance any single account may have.
+	pub min_balance: Balance,
+	/// Whether any account with this asset is given a provider reference.
+	pub is_sufficient: bool,
+	/// The total number of accounts.
+	pub accounts: u32,
+	/// The total number of accounts for which a self-sufficient reference is placed.
+	pub sufficients: u32,
+	/// The total number of approvals.
+	pub approvals: u32,
+	/// Whether the asset is frozen for non-admin transfers.
+	pub is_frozen: bool,
+}
+
+impl<AccountId, Balance> AssetDetailsRpc<AccountId, Balance> {
+	/// Convert the balances of these details with `f`, failing if any conversion fails.
+	pub fn try_map_balance<B, E>(
+		self,
+		f: impl Fn(Balance) -> Result<B, E>,
+	) -> Result<AssetDetailsRpc<AccountId, B>, E> {
+		Ok(AssetDetailsRpc {
+			owner: self.owner,
+			issuer: self.issuer,
+			admin: self.admin,
+			freezer: self.freezer,
+			supply: f(self.supply)?,
+			min_balance: f(self.min_balance)?,
+			is_sufficient: self.is_sufficient,
+			accounts: self.accounts,
+			sufficients: self.sufficients,
+			approvals: self.approvals,
+			is_frozen: self.is_frozen,
+		})
+	}
+}
+
+/// The metadata of an asset class, as returned by [`AssetsApi::metadata`].
+#[derive(Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AssetMetadataRpc {
+	/// The user friendly name of the asset.
+	pub name: Vec<u8>,
+	/// The ticker symbol of the asset.
+	pub symbol: Vec<u8>,
+	/// The number of decimals the asset uses to represent one unit.
+	pub decimals: u8,
+	/// Whether the metadata may be changed by a non Force origin.
+	pub is_frozen: bool,
+}
+
+/// The witness data needed to destroy an asset class, as returned by
+/// [`AssetsApi::destroy_witness`].
+#[derive(Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct DestroyWitnessRpc {
+	/// The number of accounts holding the asset.
+	pub accounts: u32,
+	/// The number of accounts holding the asset with a self-sufficient reference.
+	pub sufficients: u32,
+	/// The number of transfer-approvals of the asset.
+	pub approvals: u32,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the state of the assets pallet.
+	#[api_version(2)]
+	pub trait AssetsApi<AssetId, AccountId, Balance> where
+		AssetId: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// The amount of asset `id` which `delegate` may still transfer on behalf of `owner`.
+		///
+		/// See [`pallet_assets::Pallet::approval_allowance`].
+		fn get_approval(id: AssetId, owner: AccountId, delegate: AccountId) -> Balance;
+
+		/// Up to `limit` asset identifiers, starting after `start` if given. At most
+		/// `MAX_PAGE_SIZE` are returned.
+		///
+		/// See [`pallet_assets::Pallet::asset_ids_page`].
+		fn asset_ids(start: Option<AssetId>, limit: u32) -> Vec<AssetId>;
+
+		/// Up to `limit` of the accounts holding asset `id` with their balances, starting after
+		/// the account `start` if given. At most `MAX_PAGE_SIZE` are returned.
+		///
+		/// See [`pallet_assets::Pallet::holders_page`].
+		fn holders_of(
+			id: AssetId,
+			start: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, Balance)>;
+
+		/// Up to `limit` of the approvals of asset `id` as owner, delegate and remaining amount,
+		/// starting after the owner and delegate `start` if given. At most `MAX_PAGE_SIZE` are
+		/// returned.
+		///
+		/// See [`pallet_assets::Pallet::approvals_page`].
+		fn approvals_of(
+			id: AssetId,
+			start: Option<(AccountId, AccountId)>,
+			limit: u32,
+		) -> Vec<(AccountId, AccountId, Balance)>;
+
+		/// The balance of asset `id` held by `who`.
+		fn balance(id: AssetId, who: AccountId) -> Balance;
+
+		/// The total supply of asset `id`.
+		fn total_supply(id: AssetId) -> Balance;
+
+		/// The minimum balance any single account of asset `id` may have.
+		fn minimum_balance(id: AssetId) -> Balance;
+
+		/// The details of asset `id`, or `None` if it doesn't exist.
+		fn asset_details(id: AssetId) -> Option<AssetDetailsRpc<AccountId, Balance>>;
+
+		/// The metadata of asset `id`, or `None` if none is set.
+		fn metadata(id: AssetId) -> Option<AssetMetadataRpc>;
+
+		/// The witness with which asset `id` may currently be destroyed, or `None` if it doesn't
+		/// exist.
+		///
+		/// See [`pallet_assets::AssetDetails::destroy_witness`].
+		fn destroy_witness(id: AssetId) -> Option<DestroyWitnessRpc>;
+
+		/// The account proposed by the Owner of asset `id` to become its new Owner, or `None` if
+		/// there is no such proposal awaiting acceptance.
+		///
+		/// See [`pallet_assets::Pallet::pending_owner`].
+		fn pending_owner(id: AssetId) -> Option<AccountId>;
+	}
+}
@@ -0,0 +1,340 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the assets module.
+
+use std::sync::Arc;
+use std::convert::TryInto;
+use codec::Codec;
+use sp_blockchain::HeaderBackend;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_runtime::{generic::BlockId, traits::{Block as BlockT, MaybeDisplay}};
+use sp_api::ProvideRuntimeApi;
+use sp_rpc::number::NumberOrHex;
+pub use pallet_assets_rpc_runtime_api::{
+	AssetsApi as AssetsRuntimeApi, AssetDetailsRpc, AssetMetadataRpc, DestroyWitnessRpc,
+	MAX_PAGE_SIZE,
+};
+pub use self::gen_client::Client as AssetsClient;
+
+const RUNTIME_ERROR: i64 = 1;
+
+#[rpc]
+pub trait AssetsApi<BlockHash, AssetId, AccountId> {
+	/// Returns the balance of asset `id` held by `who`.
+	#[rpc(name = "assets_balance")]
+	fn balance(&self, id: AssetId, who: AccountId, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+	/// Returns the total supply of asset `id`.
+	#[rpc(name = "assets_totalSupply")]
+	fn total_supply(&self, id: AssetId, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+	/// Returns the amount of asset `id` which `delegate` may still transfer on behalf of `owner`.
+	#[rpc(name = "assets_allowance")]
+	fn allowance(
+		&self,
+		id: AssetId,
+		owner: AccountId,
+		delegate: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<NumberOrHex>;
+
+	/// Returns the minimum balance any single account of asset `id` may have.
+	#[rpc(name = "assets_minimumBalance")]
+	fn minimum_balance(&self, id: AssetId, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+	/// Returns the details of asset `id`, or `None` if it doesn't exist.
+	#[rpc(name = "assets_assetDetails")]
+	fn asset_details(
+		&self,
+		id: AssetId,
+		at: Option<BlockHash>,
+	) -> Result<Option<AssetDetailsRpc<AccountId, NumberOrHex>>>;
+
+	/// Returns the metadata of asset `id`, or `None` if none is set.
+	#[rpc(name = "assets_metadata")]
+	fn metadata(&self, id: AssetId, at: Option<BlockHash>) -> Result<Option<AssetMetadataRpc>>;
+
+	/// Returns the witness with which asset `id` may be destroyed, or `None` if it doesn't exist.
+	///
+	/// The witness reflects the state at the given block only. If accounts or approvals change
+	/// before the `destroy` extrinsic is included, it fails with `BadWitness`.
+	#[rpc(name = "assets_destroyWitness")]
+	fn destroy_witness(
+		&self,
+		id: AssetId,
+		at: Option<BlockHash>,
+	) -> Result<Option<DestroyWitnessRpc>>;
+
+	/// Returns the account proposed to become the new Owner of asset `id`, or `None` if there is
+	/// no proposal awaiting acceptance.
+	#[rpc(name = "assets_pendingOwner")]
+	fn pending_owner(&self, id: AssetId, at: Option<BlockHash>) -> Result<Option<AccountId>>;
+
+	/// Returns up to `limit` asset identifiers, starting after `start` if given.
+	///
+	/// At most `MAX_PAGE_SIZE` are returned. Pass the last identifier of a page as `start` to
+	/// fetch the next one.
+	#[rpc(name = "assets_assetIds")]
+	fn asset_ids(
+		&self,
+		start: Option<AssetId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<AssetId>>;
+
+	/// Returns up to `limit` of the accounts holding asset `id` with their balances, starting
+	/// after the account `start` if given.
+	///
+	/// At most `MAX_PAGE_SIZE` are returned.
+	#[rpc(name = "assets_holdersOf")]
+	fn holders_of(
+		&self,
+		id: AssetId,
+		start: Option<AccountId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(AccountId, NumberOrHex)>>;
+
+	/// Returns up to `limit` of the approvals of asset `id` as owner, delegate and the amount
+	/// which the delegate may still transfer, starting after the owner and delegate `start` if
+	/// given.
+	///
+	/// At most `MAX_PAGE_SIZE` are returned.
+	#[rpc(name = "assets_approvalsOf")]
+	fn approvals_of(
+		&self,
+		id: AssetId,
+		start: Option<(AccountId, AccountId)>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(AccountId, AccountId, NumberOrHex)>>;
+}
+
+/// A struct that implements the [`AssetsApi`].
+///
+/// `P` is the pair of the block type and the balance type of the assets in the runtime.
+pub struct Assets<C, P> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Assets<C, P> {
+	/// Create new `Assets` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: "Runtime error".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+fn balance_into_rpc<Balance>(value: Balance) -> Result<NumberOrHex> where
+	Balance: MaybeDisplay + Copy + TryInto<NumberOrHex>,
+{
+	value.try_into().map_err(|_| RpcError {
+		code: ErrorCode::InvalidParams,
+		message: format!("{} doesn't fit in NumberOrHex representation", value),
+		data: None,
+	})
+}
+
+impl<C, Block, AssetId, AccountId, Balance> AssetsApi<<Block as BlockT>::Hash, AssetId, AccountId>
+	for Assets<C, (Block, Balance)>
+where
+	Block: BlockT,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: AssetsRuntimeApi<Block, AssetId, AccountId, Balance>,
+	AssetId: Codec,
+	AccountId: Codec,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+{
+	fn balance(
+		&self,
+		id: AssetId,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.balance(&at, id, who).map_err(runtime_error_into_rpc_err).and_then(balance_into_rpc)
+	}
+
+	fn total_supply(&self, id: AssetId, at: Option<<Block as BlockT>::Hash>) -> Result<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.total_supply(&at, id).map_err(runtime_error_into_rpc_err).and_then(balance_into_rpc)
+	}
+
+	fn allowance(
+		&self,
+		id: AssetId,
+		owner: AccountId,
+		delegate: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.get_approval(&at, id, owner, delegate)
+			.map_err(runtime_error_into_rpc_err)
+			.and_then(balance_into_rpc)
+	}
+
+	fn minimum_balance(
+		&self,
+		id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.minimum_balance(&at, id).map_err(runtime_error_into_rpc_err).and_then(balance_into_rpc)
+	}
+
+	fn asset_details(
+		&self,
+		id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<AssetDetailsRpc<AccountId, NumberOrHex>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		let details = api.asset_details(&at, id).map_err(runtime_error_into_rpc_err)?;
+		details.map(|d| d.try_map_balance(balance_into_rpc)).transpose()
+	}
+
+	fn metadata(
+		&self,
+		id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<AssetMetadataRpc>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.metadata(&at, id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn destroy_witness(
+		&self,
+		id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<DestroyWitnessRpc>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.destroy_witness(&at, id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn pending_owner(
+		&self,
+		id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.pending_owner(&at, id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn asset_ids(
+		&self,
+		start: Option<AssetId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<AssetId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.asset_ids(&at, start, limit).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn holders_of(
+		&self,
+		id: AssetId,
+		start: Option<AccountId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(AccountId, NumberOrHex)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.holders_of(&at, id, start, limit)
+			.map_err(runtime_error_into_rpc_err)?
+			.into_iter()
+			.map(|(who, balance)| Ok((who, balance_into_rpc(balance)?)))
+			.collect()
+	}
+
+	fn approvals_of(
+		&self,
+		id: AssetId,
+		start: Option<(AccountId, AccountId)>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(AccountId, AccountId, NumberOrHex)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.approvals_of(&at, id, start, limit)
+			.map_err(runtime_error_into_rpc_err)?
+			.into_iter()
+			.map(|(owner, delegate, amount)| Ok((owner, delegate, balance_into_rpc(amount)?)))
+			.collect()
+	}
+}
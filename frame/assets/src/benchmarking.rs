@@ -21,7 +21,7 @@
 
 use sp_std::prelude::*;
 use super::*;
-use sp_runtime::traits::Bounded;
+use sp_runtime::traits::{Bounded, TrailingZeroInput};
 use frame_system::RawOrigin as SystemOrigin;
 use frame_benchmarking::{
 	benchmarks_instance_pallet, account, whitelisted_caller, whitelist_account, impl_benchmark_test_suite
@@ -45,6 +45,7 @@ fn create_default_asset<T: Config<I>, I: 'static>(is_sufficient: bool)
 		caller_lookup.clone(),
 		is_sufficient,
 		1u32.into(),
+		None,
 	).is_ok());
 	(caller, caller_lookup)
 }
@@ -132,7 +133,16 @@ benchmarks_instance_pallet! {
 		let caller: T::AccountId = whitelisted_caller();
 		let caller_lookup = T::Lookup::unlookup(caller.clone());
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
-	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, 1u32.into())
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, 1u32.into(), None)
+	verify {
+		assert_last_event::<T, I>(Event::Created(Default::default(), caller.clone(), caller).into());
+	}
+
+	create_auto {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+	}: _(SystemOrigin::Signed(caller.clone()), caller_lookup, 1u32.into())
 	verify {
 		assert_last_event::<T, I>(Event::Created(Default::default(), caller.clone(), caller).into());
 	}
@@ -140,7 +150,7 @@ benchmarks_instance_pallet! {
 	force_create {
 		let caller: T::AccountId = whitelisted_caller();
 		let caller_lookup = T::Lookup::unlookup(caller.clone());
-	}: _(SystemOrigin::Root, Default::default(), caller_lookup, true, 1u32.into())
+	}: _(SystemOrigin::Root, Default::default(), caller_lookup, true, 1u32.into(), None)
 	verify {
 		assert_last_event::<T, I>(Event::ForceCreated(Default::default(), caller).into());
 	}
@@ -153,10 +163,49 @@ benchmarks_instance_pallet! {
 		add_consumers::<T, I>(caller.clone(), c);
 		add_sufficients::<T, I>(caller.clone(), s);
 		add_approvals::<T, I>(caller.clone(), a);
-		let witness = Asset::<T, I>::get(T::AssetId::default()).unwrap().destroy_witness();
+		let details = Asset::<T, I>::get(T::AssetId::default()).unwrap();
+		let witness = details.destroy_witness();
 	}: _(SystemOrigin::Signed(caller), Default::default(), witness)
 	verify {
-		assert_last_event::<T, I>(Event::Destroyed(Default::default()).into());
+		assert_last_event::<T, I>(
+			Event::Destroyed(Default::default(), witness.accounts, witness.approvals, details.supply)
+				.into()
+		);
+	}
+
+	start_destroy {
+		let (caller, _) = create_default_asset::<T, I>(true);
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::DestructionStarted(Default::default()).into());
+	}
+
+	destroy_accounts {
+		let c in 0 .. 5_000;
+		let (caller, _) = create_default_asset::<T, I>(true);
+		add_sufficients::<T, I>(caller.clone(), c);
+		Assets::<T, I>::start_destroy(SystemOrigin::Signed(caller.clone()).into(), Default::default())?;
+	}: _(SystemOrigin::Signed(caller), Default::default(), c)
+	verify {
+		assert_last_event::<T, I>(Event::AccountsDestroyed(Default::default(), c, 0).into());
+	}
+
+	destroy_approvals {
+		let a in 0 .. 5_00;
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		add_approvals::<T, I>(caller.clone(), a);
+		Assets::<T, I>::start_destroy(SystemOrigin::Signed(caller.clone()).into(), Default::default())?;
+	}: _(SystemOrigin::Signed(caller), Default::default(), a)
+	verify {
+		assert_last_event::<T, I>(Event::ApprovalsDestroyed(Default::default(), a).into());
+	}
+
+	finish_destroy {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		Assets::<T, I>::start_destroy(SystemOrigin::Signed(caller.clone()).into(), Default::default())?;
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::Destroyed(Default::default(), 0, 0, Zero::zero()).into());
 	}
 
 	mint {
@@ -167,6 +216,21 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Issued(Default::default(), caller, amount).into());
 	}
 
+	mint_to_many {
+		let n in 1 .. T::MaxMintTargets::get();
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let amount = T::Balance::from(100u32);
+		let targets = (0..n).map(|i| {
+			let target: T::AccountId = account("target", i, SEED);
+			(T::Lookup::unlookup(target), amount)
+		}).collect::<Vec<_>>();
+		let targets: BoundedVec<_, T::MaxMintTargets> = targets.try_into().unwrap();
+		let last: T::AccountId = account("target", n - 1, SEED);
+	}: _(SystemOrigin::Signed(caller), Default::default(), targets)
+	verify {
+		assert_last_event::<T, I>(Event::Issued(Default::default(), last, amount).into());
+	}
+
 	burn {
 		let amount = T::Balance::from(100u32);
 		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
@@ -175,6 +239,15 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Burned(Default::default(), caller, amount).into());
 	}
 
+	soft_burn {
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, amount)
+	verify {
+		let burned = amount - 1u32.into();
+		assert_last_event::<T, I>(Event::Burned(Default::default(), caller, burned).into());
+	}
+
 	transfer {
 		let amount = T::Balance::from(100u32);
 		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
@@ -185,6 +258,31 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Transferred(Default::default(), caller, target, amount).into());
 	}
 
+	transfer_with_memo {
+		let m in 0 .. T::MemoLimit::get();
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let memo = vec![b'0'; m as usize];
+		let bounded: BoundedVec<_, T::MemoLimit> = memo.clone().try_into().unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), target_lookup, amount, bounded)
+	verify {
+		assert_last_event::<T, I>(
+			Event::TransferredWithMemo(Default::default(), caller, target, amount, memo).into()
+		);
+	}
+
+	transfer_best_effort {
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), target_lookup, amount * 2u32.into())
+	verify {
+		assert_last_event::<T, I>(Event::Transferred(Default::default(), caller, target, amount).into());
+	}
+
 	transfer_keep_alive {
 		let mint_amount = T::Balance::from(200u32);
 		let amount = T::Balance::from(100u32);
@@ -197,6 +295,69 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Transferred(Default::default(), caller, target, amount).into());
 	}
 
+	transfer_to_many {
+		let n in 1 .. T::MaxFanOut::get();
+		let amount = T::Balance::from(100u32);
+		let (caller, _) = create_default_minted_asset::<T, I>(true, T::Balance::from(100u32 * n));
+		let targets = (0..n).map(|i| {
+			let target: T::AccountId = account("target", i, SEED);
+			(T::Lookup::unlookup(target), amount)
+		}).collect::<Vec<_>>();
+		let targets: BoundedVec<_, T::MaxFanOut> = targets.try_into().unwrap();
+		let last: T::AccountId = account("target", n - 1, SEED);
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), targets)
+	verify {
+		assert!(!Account::<T, I>::contains_key(T::AssetId::default(), &caller));
+		assert_last_event::<T, I>(Event::Transferred(Default::default(), caller, last, amount).into());
+	}
+
+	multi_asset_transfer {
+		let n in 1 .. T::MaxMultiTransfer::get();
+		let amount = T::Balance::from(100u32);
+		let (owner, owner_lookup) =
+			create_default_minted_asset::<T, I>(true, T::Balance::from(100u32 * n));
+		T::Currency::make_free_balance_be(&owner, DepositBalanceOf::<T, I>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		whitelist_account!(delegate);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let origin = SystemOrigin::Signed(owner.clone()).into();
+		Assets::<T, I>::approve_transfer(origin, id, delegate_lookup, T::Balance::from(100u32 * n))?;
+		let transfers = (0..n).map(|i| {
+			let target: T::AccountId = account("target", i, SEED);
+			MultiAssetTransfer {
+				asset: id,
+				source: owner_lookup.clone(),
+				dest: T::Lookup::unlookup(target),
+				amount,
+			}
+		}).collect::<Vec<_>>();
+		let transfers: BoundedVec<_, T::MaxMultiTransfer> = transfers.try_into().unwrap();
+		let last: T::AccountId = account("target", n - 1, SEED);
+	}: _(SystemOrigin::Signed(delegate), transfers)
+	verify {
+		assert!(T::Currency::reserved_balance(&owner).is_zero());
+		assert_event::<T, I>(Event::Transferred(id, owner, last, amount).into());
+	}
+
+	force_batch_transfer {
+		let n in 1 .. T::MaxFanOut::get();
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) =
+			create_default_minted_asset::<T, I>(true, T::Balance::from(100u32 * n));
+		let targets = (0..n).map(|i| {
+			let target: T::AccountId = account("target", i, SEED);
+			(T::Lookup::unlookup(target), amount)
+		}).collect::<Vec<_>>();
+		let targets: BoundedVec<_, T::MaxFanOut> = targets.try_into().unwrap();
+		let last: T::AccountId = account("target", n - 1, SEED);
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, targets)
+	verify {
+		assert!(!Account::<T, I>::contains_key(T::AssetId::default(), &caller));
+		assert_last_event::<T, I>(Event::Transferred(Default::default(), caller, last, amount).into());
+	}
+
 	force_transfer {
 		let amount = T::Balance::from(100u32);
 		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
@@ -228,11 +389,87 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Thawed(Default::default(), caller).into());
 	}
 
+	bulk_freeze_accounts {
+		let n in 1 .. T::MaxFreezeBatch::get();
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		add_sufficients::<T, I>(caller.clone(), n);
+		let targets = (0..n).map(|i| T::Lookup::unlookup(account("sufficient", i, SEED)))
+			.collect::<Vec<_>>();
+		let targets: BoundedVec<_, T::MaxFreezeBatch> = targets.try_into().unwrap();
+		let last: T::AccountId = account("sufficient", n - 1, SEED);
+	}: _(SystemOrigin::Signed(caller), Default::default(), targets)
+	verify {
+		assert_last_event::<T, I>(Event::Frozen(Default::default(), last).into());
+	}
+
+	bulk_thaw_accounts {
+		let n in 1 .. T::MaxFreezeBatch::get();
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		add_sufficients::<T, I>(caller.clone(), n);
+		let targets = (0..n).map(|i| T::Lookup::unlookup(account("sufficient", i, SEED)))
+			.collect::<Vec<_>>();
+		let targets: BoundedVec<_, T::MaxFreezeBatch> = targets.try_into().unwrap();
+		Assets::<T, I>::bulk_freeze_accounts(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			targets.clone(),
+		)?;
+		let last: T::AccountId = account("sufficient", n - 1, SEED);
+	}: _(SystemOrigin::Signed(caller), Default::default(), targets)
+	verify {
+		assert_last_event::<T, I>(Event::Thawed(Default::default(), last).into());
+	}
+
 	freeze_asset {
 		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
 	}: _(SystemOrigin::Signed(caller.clone()), Default::default())
 	verify {
-		assert_last_event::<T, I>(Event::AssetFrozen(Default::default()).into());
+		assert_last_event::<T, I>(Event::AssetFrozen(Default::default(), None).into());
+	}
+
+	freeze_asset_with_reason {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let reason = vec![0u8; T::StringLimit::get() as usize];
+	}: _(SystemOrigin::Signed(caller), Default::default(), reason.clone())
+	verify {
+		assert_last_event::<T, I>(Event::AssetFrozen(Default::default(), Some(reason)).into());
+	}
+
+	pause_minting {
+		let (caller, _) = create_default_asset::<T, I>(true);
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::MintingPaused(Default::default()).into());
+	}
+
+	resume_minting {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		Assets::<T, I>::pause_minting(SystemOrigin::Signed(caller.clone()).into(), Default::default())?;
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::MintingResumed(Default::default()).into());
+	}
+
+	retire_asset {
+		let (caller, _) = create_default_asset::<T, I>(true);
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::AssetRetired(Default::default()).into());
+	}
+
+	unretire_asset {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		Assets::<T, I>::retire_asset(SystemOrigin::Signed(caller.clone()).into(), Default::default())?;
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::AssetUnretired(Default::default()).into());
+	}
+
+	set_asset_sufficient {
+		let (caller, _) = create_default_asset::<T, I>(true);
+	}: _(SystemOrigin::Signed(caller), Default::default(), false)
+	verify {
+		assert_last_event::<T, I>(Event::SufficiencyChanged(Default::default(), false).into());
 	}
 
 	thaw_asset {
@@ -243,7 +480,19 @@ benchmarks_instance_pallet! {
 		)?;
 	}: _(SystemOrigin::Signed(caller.clone()), Default::default())
 	verify {
-		assert_last_event::<T, I>(Event::AssetThawed(Default::default()).into());
+		assert_last_event::<T, I>(Event::AssetThawed(Default::default(), None).into());
+	}
+
+	thaw_asset_with_reason {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		Assets::<T, I>::freeze_asset(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+		)?;
+		let reason = vec![0u8; T::StringLimit::get() as usize];
+	}: _(SystemOrigin::Signed(caller), Default::default(), reason.clone())
+	verify {
+		assert_last_event::<T, I>(Event::AssetThawed(Default::default(), Some(reason)).into());
 	}
 
 	transfer_ownership {
@@ -255,6 +504,85 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::OwnerChanged(Default::default(), target).into());
 	}
 
+	force_transfer_ownership {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::force_transfer_ownership(Default::default(), target_lookup);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::OwnerChanged(Default::default(), target).into());
+	}
+
+	// No valid signature can be made generically, so this measures a verification which fails.
+	transfer_ownership_by_operator {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let signature = T::OffchainSignature::decode(&mut TrailingZeroInput::new(&[])).unwrap();
+		let expiry = T::BlockNumber::max_value();
+	}: {
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		let result = Assets::<T, I>::transfer_ownership_by_operator(
+			origin,
+			Default::default(),
+			target_lookup,
+			expiry,
+			signature,
+		);
+		assert!(result.is_err());
+	}
+	verify {
+		assert_eq!(Asset::<T, I>::get(T::AssetId::default()).unwrap().owner, caller);
+	}
+
+	propose_new_owner {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller), Default::default(), target_lookup)
+	verify {
+		assert_last_event::<T, I>(
+			Event::PendingOwnerChanged(Default::default(), Some(target)).into()
+		);
+	}
+
+	accept_ownership {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		Assets::<T, I>::propose_new_owner(
+			SystemOrigin::Signed(caller).into(),
+			Default::default(),
+			target_lookup,
+		)?;
+	}: _(SystemOrigin::Signed(target.clone()), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::OwnershipAccepted(Default::default(), target).into());
+	}
+
+	cancel_proposed_owner {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let target_lookup = T::Lookup::unlookup(account("target", 0, SEED));
+		Assets::<T, I>::propose_new_owner(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			target_lookup,
+		)?;
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::PendingOwnerChanged(Default::default(), None).into());
+	}
+
+	renounce_ownership {
+		let (caller, _) = create_default_asset::<T, I>(true);
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::OwnershipRenounced(Default::default()).into());
+	}
+
 	set_team {
 		let (caller, _) = create_default_asset::<T, I>(true);
 		let target0 = T::Lookup::unlookup(account("target", 0, SEED));
@@ -283,7 +611,42 @@ benchmarks_instance_pallet! {
 	}: _(SystemOrigin::Signed(caller), Default::default(), name.clone(), symbol.clone(), decimals)
 	verify {
 		let id = Default::default();
-		assert_last_event::<T, I>(Event::MetadataSet(id, name, symbol, decimals, false).into());
+		let event = Event::MetadataSet(id, name, symbol, decimals, false, None, None);
+		assert_last_event::<T, I>(event.into());
+	}
+
+	set_metadata_extended {
+		let n in 0 .. T::StringLimit::get();
+		let s in 0 .. T::StringLimit::get();
+
+		let name = vec![0u8; n as usize];
+		let symbol = vec![0u8; s as usize];
+		let decimals = 12;
+		let image_hash = Some([1u8; 32]);
+
+		let (caller, _) = create_default_asset::<T, I>(true);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+	}: _(SystemOrigin::Signed(caller), Default::default(), name.clone(), symbol.clone(), decimals, image_hash)
+	verify {
+		let id = Default::default();
+		let event = Event::MetadataSet(id, name, symbol, decimals, false, image_hash, None);
+		assert_last_event::<T, I>(event.into());
+	}
+
+	set_metadata_url {
+		let u in 0 .. T::UriLimit::get();
+
+		let (caller, _) = create_default_asset::<T, I>(true);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let dummy = vec![0u8; T::StringLimit::get() as usize];
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::set_metadata(origin, Default::default(), dummy.clone(), dummy.clone(), 12)?;
+		let uri = vec![0u8; u as usize];
+	}: _(SystemOrigin::Signed(caller), Default::default(), Some(uri.clone()))
+	verify {
+		let id = Default::default();
+		let event = Event::MetadataSet(id, dummy.clone(), dummy, 12, false, None, Some(uri));
+		assert_last_event::<T, I>(event.into());
 	}
 
 	clear_metadata {
@@ -291,10 +654,10 @@ benchmarks_instance_pallet! {
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
 		let dummy = vec![0u8; T::StringLimit::get() as usize];
 		let origin = SystemOrigin::Signed(caller.clone()).into();
-		Assets::<T, I>::set_metadata(origin, Default::default(), dummy.clone(), dummy, 12)?;
+		Assets::<T, I>::set_metadata(origin, Default::default(), dummy.clone(), dummy.clone(), 12)?;
 	}: _(SystemOrigin::Signed(caller), Default::default())
 	verify {
-		assert_last_event::<T, I>(Event::MetadataCleared(Default::default()).into());
+		assert_last_event::<T, I>(Event::MetadataCleared(Default::default(), dummy).into());
 	}
 
 	force_set_metadata {
@@ -318,21 +681,49 @@ benchmarks_instance_pallet! {
 	}: { call.dispatch_bypass_filter(origin)? }
 	verify {
 		let id = Default::default();
-		assert_last_event::<T, I>(Event::MetadataSet(id, name, symbol, decimals, false).into());
+		let event = Event::MetadataSet(id, name, symbol, decimals, false, None, None);
+		assert_last_event::<T, I>(event.into());
 	}
 
-	force_clear_metadata {
+	freeze_metadata {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let dummy = vec![0u8; T::StringLimit::get() as usize];
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::set_metadata(origin, Default::default(), dummy.clone(), dummy, 12)?;
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::MetadataFrozen(Default::default()).into());
+	}
+
+	thaw_metadata {
 		let (caller, _) = create_default_asset::<T, I>(true);
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
 		let dummy = vec![0u8; T::StringLimit::get() as usize];
 		let origin = SystemOrigin::Signed(caller.clone()).into();
 		Assets::<T, I>::set_metadata(origin, Default::default(), dummy.clone(), dummy, 12)?;
+		let origin = SystemOrigin::Signed(caller).into();
+		Assets::<T, I>::freeze_metadata(origin, Default::default())?;
+
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::thaw_metadata(Default::default());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::MetadataThawed(Default::default()).into());
+	}
+
+	force_clear_metadata {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let dummy = vec![0u8; T::StringLimit::get() as usize];
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::set_metadata(origin, Default::default(), dummy.clone(), dummy.clone(), 12)?;
 
 		let origin = T::ForceOrigin::successful_origin();
 		let call = Call::<T, I>::force_clear_metadata(Default::default());
 	}: { call.dispatch_bypass_filter(origin)? }
 	verify {
-		assert_last_event::<T, I>(Event::MetadataCleared(Default::default()).into());
+		assert_last_event::<T, I>(Event::MetadataCleared(Default::default(), dummy).into());
 	}
 
 	force_asset_status {
@@ -348,6 +739,8 @@ benchmarks_instance_pallet! {
 			100u32.into(),
 			true,
 			false,
+			None,
+			false,
 		);
 	}: { call.dispatch_bypass_filter(origin)? }
 	verify {
@@ -367,6 +760,43 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::ApprovedTransfer(id, caller, delegate, amount).into());
 	}
 
+	sweep_approval_expiry {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::approve_transfer(origin, id, delegate_lookup, 100u32.into())?;
+
+		let expiry = frame_system::Pallet::<T>::block_number();
+		ApprovalExpiresAt::<T, I>::insert((id, &caller, &delegate), expiry);
+		ApprovalExpiryQueue::<T, I>::insert(expiry, (id, caller.clone(), delegate.clone()), ());
+		ApprovalExpiryCursor::<T, I>::put(expiry);
+	}: {
+		Assets::<T, I>::sweep_approval_expiries(expiry, T::WeightInfo::sweep_approval_expiry());
+	}
+	verify {
+		assert!(Approvals::<T, I>::get((id, &caller, &delegate)).is_none());
+		assert_last_event::<T, I>(Event::ApprovalExpired(id, caller, delegate).into());
+	}
+
+	approve_transfer_exact {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::approve_transfer(origin, id, delegate_lookup.clone(), 50u32.into())?;
+		let amount = 100u32.into();
+	}: _(SystemOrigin::Signed(caller.clone()), id, delegate_lookup, amount)
+	verify {
+		assert_last_event::<T, I>(Event::ApprovedTransfer(id, caller, delegate, amount).into());
+	}
+
 	transfer_approved {
 		let (owner, owner_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
 		T::Currency::make_free_balance_be(&owner, DepositBalanceOf::<T, I>::max_value());
@@ -387,6 +817,35 @@ benchmarks_instance_pallet! {
 		assert_event::<T, I>(Event::Transferred(id, owner, dest, amount).into());
 	}
 
+	transfer_approved_keep_alive {
+		let (owner, owner_lookup) = create_default_minted_asset::<T, I>(true, 200u32.into());
+		T::Currency::make_free_balance_be(&owner, DepositBalanceOf::<T, I>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		whitelist_account!(delegate);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let amount = 100u32.into();
+		let origin = SystemOrigin::Signed(owner.clone()).into();
+		Assets::<T, I>::approve_transfer(origin, id, delegate_lookup.clone(), amount)?;
+
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let dest_lookup = T::Lookup::unlookup(dest.clone());
+	}: _(SystemOrigin::Signed(delegate.clone()), id, owner_lookup, dest_lookup, amount)
+	verify {
+		assert!(T::Currency::reserved_balance(&owner).is_zero());
+		assert_event::<T, I>(Event::Transferred(id, owner, dest, amount).into());
+	}
+
+	revoke_all_approvals {
+		let n in 0 .. T::MaxRevocationBatch::get();
+		let (caller, _) = create_default_asset::<T, I>(true);
+		add_approvals::<T, I>(caller.clone(), n);
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::ApprovalsRevoked(Default::default(), caller, n).into());
+	}
+
 	cancel_approval {
 		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
@@ -402,6 +861,113 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::ApprovalCancelled(id, caller, delegate).into());
 	}
 
+	set_supply_cap {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let max_supply = Some(1000u32.into());
+	}: _(SystemOrigin::Signed(caller), Default::default(), max_supply)
+	verify {
+		assert_last_event::<T, I>(Event::CapChanged(Default::default(), max_supply).into());
+	}
+
+	force_set_owner_deposit {
+		create_default_asset::<T, I>(true);
+		let deposit = 10u32.into();
+
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::force_set_owner_deposit(Default::default(), deposit);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		let event = Event::DepositCorrected(Default::default(), Zero::zero(), deposit);
+		assert_last_event::<T, I>(event.into());
+	}
+
+	force_set_supply_cap {
+		create_default_minted_asset::<T, I>(true, 100u32.into());
+		let max_supply = Some(1000u32.into());
+
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::force_set_supply_cap(Default::default(), max_supply);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::CapChanged(Default::default(), max_supply).into());
+	}
+
+	burn_excess_above_cap {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let call = Call::<T, I>::force_set_supply_cap(Default::default(), Some(60u32.into()));
+		let origin = T::ForceOrigin::successful_origin();
+		call.dispatch_bypass_filter(origin)?;
+	}: _(SystemOrigin::Signed(caller), Default::default(), false)
+	verify {
+		assert_last_event::<T, I>(Event::SupplyCapEnforced(Default::default(), 40u32.into()).into());
+	}
+
+	set_min_balance {
+		let a in 0 .. 5_000;
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		add_sufficients::<T, I>(caller.clone(), a);
+		let min_balance = 2u32.into();
+		let accounts = Asset::<T, I>::get(T::AssetId::default()).unwrap().accounts;
+	}: _(SystemOrigin::Signed(caller), Default::default(), min_balance, accounts)
+	verify {
+		assert_last_event::<T, I>(
+			Event::MinBalanceChanged(Default::default(), 1u32.into(), min_balance).into()
+		);
+	}
+
+	set_transfer_fee {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let beneficiary_lookup = T::Lookup::unlookup(beneficiary.clone());
+		let rate = Perbill::from_percent(1);
+	}: _(SystemOrigin::Signed(caller), Default::default(), rate, beneficiary_lookup)
+	verify {
+		assert_last_event::<T, I>(
+			Event::TransferFeeChanged(Default::default(), rate, beneficiary).into()
+		);
+	}
+
+	set_transfer_rate_limit {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let max_volume: T::Balance = 1_000u32.into();
+		let window_blocks: T::BlockNumber = 10u32.into();
+	}: _(SystemOrigin::Signed(caller), Default::default(), max_volume, window_blocks)
+	verify {
+		assert_last_event::<T, I>(
+			Event::TransferRateLimitChanged(Default::default(), max_volume, window_blocks).into()
+		);
+	}
+
+	set_transfer_guard {
+		let (caller, _) = create_default_asset::<T, I>(true);
+		let guard: T::AccountId = account("guard", 0, SEED);
+		let guard_lookup = T::Lookup::unlookup(guard.clone());
+	}: _(SystemOrigin::Signed(caller), Default::default(), Some(guard_lookup))
+	verify {
+		assert_last_event::<T, I>(
+			Event::TransferGuardChanged(Default::default(), Some(guard)).into()
+		);
+	}
+
+	touch {
+		create_default_asset::<T, I>(false);
+		let caller: T::AccountId = account("touched", 0, SEED);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default())
+	verify {
+		assert_last_event::<T, I>(Event::Touched(Default::default(), caller).into());
+	}
+
+	refund {
+		create_default_asset::<T, I>(false);
+		let caller: T::AccountId = account("touched", 0, SEED);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		Assets::<T, I>::touch(SystemOrigin::Signed(caller.clone()).into(), Default::default())?;
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), true)
+	verify {
+		assert_last_event::<T, I>(Event::Refunded(Default::default(), caller).into());
+	}
+
 	force_cancel_approval {
 		let (caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
@@ -416,6 +982,101 @@ benchmarks_instance_pallet! {
 	verify {
 		assert_last_event::<T, I>(Event::ApprovalCancelled(id, caller, delegate).into());
 	}
+
+	migrate_asset_id {
+		let a in 0 .. 1_000;
+		let p in 0 .. 5_00;
+		let (caller, _) = create_default_asset::<T, I>(true);
+		add_sufficients::<T, I>(caller.clone(), a);
+		add_approvals::<T, I>(caller, p);
+		let old_id = T::AssetId::default();
+		let new_id = T::AssetId::decode(&mut TrailingZeroInput::new(&[1u8])).unwrap();
+	}: _(SystemOrigin::Root, old_id, new_id)
+	verify {
+		assert_last_event::<T, I>(Event::AssetIdMigrated(old_id, new_id).into());
+	}
+
+	propose_swap {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let id = Default::default();
+		let amount = 10u32.into();
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let dest_lookup = Some(T::Lookup::unlookup(dest));
+	}: _(SystemOrigin::Signed(caller.clone()), id, amount, id, amount, Zero::zero(), dest_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::SwapProposed(0, caller, id, amount, id, amount).into());
+	}
+
+	accept_swap {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let id = Default::default();
+		let amount = 10u32.into();
+		let acceptor: T::AccountId = account("acceptor", 0, SEED);
+		let acceptor_lookup = T::Lookup::unlookup(acceptor.clone());
+		let origin = SystemOrigin::Signed(caller.clone());
+		Assets::<T, I>::transfer(origin.clone().into(), id, acceptor_lookup, 50u32.into())?;
+		let expiry = u32::max_value().into();
+		Assets::<T, I>::propose_swap(origin.into(), id, amount, id, amount, expiry, None)?;
+	}: _(SystemOrigin::Signed(acceptor.clone()), 0)
+	verify {
+		assert_last_event::<T, I>(Event::SwapAccepted(0, acceptor).into());
+	}
+
+	cancel_swap {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let id = Default::default();
+		let amount = 10u32.into();
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::propose_swap(origin, id, amount, id, amount, Zero::zero(), None)?;
+	}: _(SystemOrigin::Signed(caller), 0)
+	verify {
+		assert_last_event::<T, I>(Event::SwapCancelled(0).into());
+	}
+
+	schedule_transfer {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let id = Default::default();
+		let amount = 10u32.into();
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let dest_lookup = T::Lookup::unlookup(dest.clone());
+		let execute_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	}: _(SystemOrigin::Signed(caller.clone()), id, dest_lookup, amount, execute_at)
+	verify {
+		let event = Event::TransferScheduled(0, id, caller, dest, amount, execute_at);
+		assert_last_event::<T, I>(event.into());
+	}
+
+	cancel_scheduled_transfer {
+		let (caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let id = Default::default();
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let dest_lookup = T::Lookup::unlookup(dest);
+		let execute_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::schedule_transfer(origin, id, dest_lookup, 10u32.into(), execute_at)?;
+	}: _(SystemOrigin::Signed(caller), 0)
+	verify {
+		assert_last_event::<T, I>(Event::ScheduledTransferCancelled(0).into());
+	}
+
+	execute_scheduled_transfers {
+		let n in 0 .. T::MaxScheduledPerBlock::get();
+		let (caller, _) = create_default_minted_asset::<T, I>(true, (100 + n).into());
+		let id = Default::default();
+		let execute_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		for i in 0..n {
+			let dest: T::AccountId = account("dest", i, SEED);
+			let dest_lookup = T::Lookup::unlookup(dest);
+			let origin = SystemOrigin::Signed(caller.clone()).into();
+			Assets::<T, I>::schedule_transfer(origin, id, dest_lookup, 1u32.into(), execute_at)?;
+		}
+	}: {
+		Assets::<T, I>::execute_scheduled_transfers(execute_at);
+	}
+	verify {
+		assert_eq!(Assets::<T, I>::balance(id, &caller), 100u32.into());
+		assert!(ScheduledTransfers::<T, I>::iter_prefix(execute_at).next().is_none());
+	}
 }
 
 impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Test);
@@ -25,6 +25,9 @@ use super::*;
 /// any uncommitted changes (see `commit` function) will be automatically committed to storage when
 /// dropped. Changes, even after committed, may be reverted to their original values with the
 /// `revert` function.
+///
+/// Committing fails if the asset account no longer exists. Since a failure when dropped can only
+/// be logged, callers should prefer to `try_commit` explicitly and handle the result.
 pub struct ExtraMutator<T: Config<I>, I: 'static = ()> {
 	id: T::AssetId,
 	who: T::AccountId,
@@ -34,10 +37,14 @@ pub struct ExtraMutator<T: Config<I>, I: 'static = ()> {
 
 impl<T: Config<I>, I: 'static> Drop for ExtraMutator<T, I> {
 	fn drop(&mut self) {
-		debug_assert!(
-			self.commit().is_ok(),
-			"attempt to write to non-existent asset account"
-		);
+		if self.try_commit().is_err() {
+			log::debug!(
+				target: "runtime::assets",
+				"Discarding changes to the extra data of non-existent asset account {:?} of {:?}.",
+				self.who,
+				self.id,
+			);
+		}
 	}
 }
 
@@ -78,7 +85,16 @@ impl<T: Config<I>, I: 'static> ExtraMutator<T, I> {
 	}
 
 	/// Commit any changes to storage.
+	///
+	/// This is the same as `try_commit`.
 	pub fn commit(&mut self) -> Result<(), ()> {
+		self.try_commit()
+	}
+
+	/// Commit any changes to storage, failing if the asset account no longer exists.
+	///
+	/// Any pending changes are discarded on failure.
+	pub fn try_commit(&mut self) -> Result<(), ()> {
 		if let Some(extra) = self.pending.take() {
 			Account::<T, I>::try_mutate_exists(self.id, self.who.borrow(), |maybe_account| {
 				if let Some(ref mut account) = maybe_account {
@@ -94,6 +110,8 @@ impl<T: Config<I>, I: 'static> ExtraMutator<T, I> {
 	}
 
 	/// Revert any changes, even those already committed by `self` and drop self.
+	///
+	/// Fails, without writing anything, if the asset account no longer exists.
 	pub fn revert(mut self) -> Result<(), ()> {
 		self.pending = None;
 		Account::<T, I>::try_mutate_exists(self.id, self.who.borrow(), |maybe_account| {
@@ -18,6 +18,9 @@
 //! Functions for the Assets pallet.
 
 use super::*;
+use frame_support::{
+	Blake2_128Concat, ReversibleStorageHasher, StorageHasher, storage::StoragePrefixedMap,
+};
 
 // The main implementation block for the module.
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -43,23 +46,262 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			.unwrap_or_else(Zero::zero)
 	}
 
+	/// Get the minimum balance of asset `id`, or `None` if the asset doesn't exist.
+	///
+	/// Unlike `fungibles::Inspect::minimum_balance`, which returns zero for an unknown asset, this
+	/// lets callers handle that case explicitly.
+	pub fn minimum_balance_of(id: T::AssetId) -> Option<T::Balance> {
+		Asset::<T, I>::get(id).map(|d| d.min_balance)
+	}
+
+	/// Whether asset `id` is frozen, so nothing may be withdrawn or transferred out of any of its
+	/// accounts, though they may still receive it. Returns `false` if the asset doesn't exist.
+	pub fn asset_is_frozen(id: T::AssetId) -> bool {
+		Asset::<T, I>::get(id).map_or(false, |d| d.is_frozen)
+	}
+
+	/// Whether the account of `who` for asset `id` is frozen. This does not consider whether the
+	/// asset itself is frozen; see `asset_is_frozen`. Returns `false` if there is no such account.
+	pub fn account_is_frozen(id: T::AssetId, who: &T::AccountId) -> bool {
+		Account::<T, I>::get(id, who).is_frozen
+	}
+
+	/// The payload which the Owner of asset `id` must sign to authorise
+	/// `transfer_ownership_by_operator` to `owner` with the given `nonce` and `expiry`.
+	///
+	/// This is the SCALE encoding of `TRANSFER_OWNERSHIP_TAG`, the genesis hash of the chain and
+	/// the index of this pallet in the runtime followed by `(id, owner, nonce, expiry)`, so that
+	/// a signature is valid for neither another chain nor another instance of the pallet.
+	pub fn transfer_ownership_payload(
+		id: T::AssetId,
+		owner: &T::AccountId,
+		nonce: u32,
+		expiry: T::BlockNumber,
+	) -> Vec<u8> {
+		let genesis = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+		let index = <Self as frame_support::traits::PalletInfoAccess>::index() as u32;
+		(TRANSFER_OWNERSHIP_TAG, genesis, index, id, owner, nonce, expiry).encode()
+	}
+
+	/// Get the details of asset `id`, or `None` if the asset doesn't exist.
+	pub fn asset_details(
+		id: T::AssetId,
+	) -> Option<AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>> {
+		Asset::<T, I>::get(id)
+	}
+
+	/// Get the witness with which asset `id` may currently be destroyed, or `None` if the asset
+	/// doesn't exist.
+	pub fn destroy_witness(id: T::AssetId) -> Option<DestroyWitness> {
+		Asset::<T, I>::get(id).map(|d| d.destroy_witness())
+	}
+
+	/// Get the metadata of asset `id`, or `None` if none is set.
+	pub fn metadata(id: T::AssetId) -> Option<MetadataOf<T, I>> {
+		if Metadata::<T, I>::contains_key(id) {
+			Some(Metadata::<T, I>::get(id))
+		} else {
+			None
+		}
+	}
+
+	/// Get the account proposed by the Owner of asset `id` to become its new Owner, if any.
+	pub fn pending_owner(id: T::AssetId) -> Option<T::AccountId> {
+		PendingOwner::<T, I>::get(id)
+	}
+
+	/// Get the account set by the Owner of asset `id` for the `TransferGuard` to consult, if any.
+	pub fn transfer_guard(id: T::AssetId) -> Option<T::AccountId> {
+		TransferGuardAccount::<T, I>::get(id)
+	}
+
+	/// Get the amount of asset `id` which `delegate` may still transfer on behalf of `owner`.
+	pub fn approval_allowance(
+		id: T::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+	) -> T::Balance {
+		Approvals::<T, I>::get((id, owner, delegate))
+			.map(|x| x.amount)
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Get up to `limit` asset identifiers in storage order, starting after `start` if given.
+	///
+	/// Only `limit` entries are read, so all assets may be listed a page at a time by passing the
+	/// last identifier of each page as the `start` of the next.
+	pub fn asset_ids_page(start: Option<T::AssetId>, limit: u32) -> Vec<T::AssetId> {
+		let prefix = Asset::<T, I>::final_prefix().to_vec();
+		let start = start.map(Asset::<T, I>::hashed_key_for);
+		Self::page_keys(prefix, start, limit)
+			.into_iter()
+			.filter_map(|key| T::AssetId::decode(&mut Blake2_128Concat::reverse(&key)).ok())
+			.collect()
+	}
+
+	/// Get up to `limit` of the accounts holding asset `id`, together with their balances, in
+	/// storage order and starting after `start` if given.
+	pub fn holders_page(
+		id: T::AssetId,
+		start: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<(T::AccountId, T::Balance)> {
+		let prefix = [
+			&Account::<T, I>::final_prefix()[..],
+			&Blake2_128Concat::hash(&id.encode()),
+		].concat();
+		let start = start.map(|who| Account::<T, I>::hashed_key_for(id, who));
+		Self::page_keys(prefix, start, limit)
+			.into_iter()
+			.filter_map(|key| T::AccountId::decode(&mut Blake2_128Concat::reverse(&key)).ok())
+			.map(|who| {
+				let balance = Account::<T, I>::get(id, &who).balance;
+				(who, balance)
+			})
+			.collect()
+	}
+
+	/// Get up to `limit` of the approvals of asset `id` as owner, delegate and the amount which
+	/// the delegate may still transfer, in storage order and starting after the `start` owner and
+	/// delegate if given.
+	pub fn approvals_page(
+		id: T::AssetId,
+		start: Option<(T::AccountId, T::AccountId)>,
+		limit: u32,
+	) -> Vec<(T::AccountId, T::AccountId, T::Balance)> {
+		let prefix = [
+			&Approvals::<T, I>::final_prefix()[..],
+			&Blake2_128Concat::hash(&id.encode()),
+		].concat();
+		let start = start.map(|(owner, delegate)| {
+			Approvals::<T, I>::hashed_key_for((id, owner, delegate))
+		});
+		Self::page_keys(prefix, start, limit)
+			.into_iter()
+			.filter_map(|key| {
+				let mut rest = Blake2_128Concat::reverse(&key);
+				let owner = T::AccountId::decode(&mut rest).ok()?;
+				let delegate = T::AccountId::decode(&mut Blake2_128Concat::reverse(rest)).ok()?;
+				let amount = Approvals::<T, I>::get((id, &owner, &delegate))?.amount;
+				Some((owner, delegate, amount))
+			})
+			.collect()
+	}
+
+	/// Get up to `limit` storage keys under `prefix`, with the prefix removed, following the key
+	/// `start` or from the beginning of `prefix` if not given.
+	fn page_keys(prefix: Vec<u8>, start: Option<Vec<u8>>, limit: u32) -> Vec<Vec<u8>> {
+		let mut keys = Vec::new();
+		let mut previous = start.unwrap_or_else(|| prefix.clone());
+		while keys.len() < limit as usize {
+			let next = frame_support::sp_io::storage::next_key(&previous)
+				.filter(|key| key.starts_with(&prefix));
+			match next {
+				Some(key) => {
+					keys.push(key[prefix.len()..].to_vec());
+					previous = key;
+				}
+				None => break,
+			}
+		}
+		keys
+	}
+
+	/// Iterate over the accounts holding asset `id`, together with their balances, in no
+	/// particular order. Yields nothing if the asset doesn't exist.
+	pub fn accounts_for(
+		id: T::AssetId,
+	) -> impl Iterator<
+		Item = (T::AccountId, AssetBalance<T::Balance, DepositBalanceOf<T, I>, T::Extra>),
+	> {
+		Account::<T, I>::iter_prefix(id)
+	}
+
+	/// Iterate over the approvals of asset `id`, keyed by owner and delegate, in no particular
+	/// order. Yields nothing if the asset doesn't exist.
+	pub fn all_approvals_for(
+		id: T::AssetId,
+	) -> impl Iterator<
+		Item = ((T::AccountId, T::AccountId), Approval<T::Balance, DepositBalanceOf<T, I>>),
+	> {
+		Approvals::<T, I>::iter_prefix((id,))
+	}
+
+	/// Get the number of approvals of asset `id` granted by `owner`.
+	///
+	/// This is `O(k)` in the number of approvals granted by `owner`, since `Approvals` is keyed by
+	/// owner after the asset.
+	pub fn approval_count_for(id: T::AssetId, owner: &T::AccountId) -> u32 {
+		Approvals::<T, I>::iter_prefix((id, owner)).count() as u32
+	}
+
+	/// Get the total amount of asset `id` frozen across all of its accounts. The frozen amount of
+	/// each account is counted up to its balance.
+	///
+	/// This iterates over every account of the asset, so it is `O(accounts)` and meant for RPC
+	/// and off-chain use only; it should not be called from within a block.
+	pub fn total_frozen_balance(id: T::AssetId) -> T::Balance {
+		Self::accounts_for(id).fold(Zero::zero(), |total: T::Balance, (who, account)| {
+			let frozen = Self::frozen_balance(id, &who).unwrap_or_else(Zero::zero);
+			total.saturating_add(frozen.min(account.balance))
+		})
+	}
+
+	/// Get the total supply of asset `id` which is not frozen.
+	///
+	/// This is `O(accounts)`, see `total_frozen_balance`.
+	pub fn total_transferable_balance(id: T::AssetId) -> T::Balance {
+		Self::total_supply(id).saturating_sub(Self::total_frozen_balance(id))
+	}
+
+	/// Get the non-zero balances of `who` in each asset, in no particular order.
+	///
+	/// This is `O(k)` in the number of assets held by `who`.
+	pub fn aggregated_balance(who: &T::AccountId) -> Vec<(T::AssetId, T::Balance)> {
+		AccountAssets::<T, I>::iter_prefix(who)
+			.map(|(id, ())| (id, Self::balance(id, who)))
+			.filter(|(_, balance)| !balance.is_zero())
+			.collect()
+	}
+
 	pub(super) fn new_account(
+		what: T::AssetId,
 		who: &T::AccountId,
 		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
 	) -> Result<bool, DispatchError> {
+		ensure!(d.status != AssetStatus::Retiring, Error::<T, I>::AssetRetired);
+		ensure!(d.accounts < T::MaxAccountsPerAsset::get(), Error::<T, I>::TooManyAccounts);
 		let accounts = d.accounts.checked_add(1).ok_or(ArithmeticError::Overflow)?;
 		let is_sufficient = if d.is_sufficient {
 			frame_system::Pallet::<T>::inc_sufficients(who);
 			d.sufficients += 1;
 			true
 		} else {
-			frame_system::Pallet::<T>::inc_consumers(who).map_err(|_| Error::<T, I>::NoProvider)?;
+			frame_system::Pallet::<T>::inc_consumers(who).map_err(|_| Error::<T, I>::InsufficientProviders)?;
 			false
 		};
 		d.accounts = accounts;
+		AccountAssets::<T, I>::insert(who, what, ());
+		#[cfg(feature = "emit-account-events")]
+		Self::deposit_event(Event::AccountCreated(what, who.clone()));
 		Ok(is_sufficient)
 	}
 
+	/// Note that the asset `id` balance of `who` changed from `old` to `new`.
+	///
+	/// Emits `BalanceChanged` with the `emit-balance-changed-events` feature, otherwise does
+	/// nothing.
+	#[cfg_attr(not(feature = "emit-balance-changed-events"), allow(unused_variables))]
+	pub(super) fn balance_changed(
+		id: T::AssetId,
+		who: &T::AccountId,
+		old: T::Balance,
+		new: T::Balance,
+	) {
+		#[cfg(feature = "emit-balance-changed-events")]
+		Self::deposit_event(Event::BalanceChanged(id, who.clone(), old, new));
+	}
+
 	pub(super) fn dead_account(
 		what: T::AssetId,
 		who: &T::AccountId,
@@ -73,10 +315,151 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			frame_system::Pallet::<T>::dec_consumers(who);
 		}
 		d.accounts = d.accounts.saturating_sub(1);
-		T::Freezer::died(what, who)
+		AccountAssets::<T, I>::remove(who, what);
+		T::Freezer::died(what, who);
+		#[cfg(feature = "emit-account-events")]
+		Self::deposit_event(Event::AccountDied(what, who.clone()));
+	}
+
+	/// Record a new approval of asset `id`.
+	pub(super) fn inc_approvals(id: T::AssetId) {
+		Asset::<T, I>::mutate(id, |maybe_details| if let Some(details) = maybe_details {
+			details.approvals = details.approvals.saturating_add(1);
+		});
+	}
+
+	/// Record the removal of an approval of asset `id`.
+	pub(super) fn dec_approvals(id: T::AssetId) {
+		Asset::<T, I>::mutate(id, |maybe_details| if let Some(details) = maybe_details {
+			details.approvals = details.approvals.saturating_sub(1);
+		});
+	}
+
+	/// (Re)start the expiry period of the approval of asset `id` from `owner` to `delegate`, or
+	/// clear its expiry if `ApprovalExpiry` is not set.
+	pub(super) fn schedule_approval_expiry(
+		id: T::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+	) {
+		Self::clear_approval_expiry(id, owner, delegate);
+		let period = match T::ApprovalExpiry::get() {
+			Some(period) => period,
+			None => return,
+		};
+		let expiry = frame_system::Pallet::<T>::block_number().saturating_add(period);
+		ApprovalExpiresAt::<T, I>::insert((id, owner, delegate), expiry);
+		ApprovalExpiryQueue::<T, I>::insert(expiry, (id, owner.clone(), delegate.clone()), ());
+		ApprovalExpiryCursor::<T, I>::mutate(|cursor| match cursor {
+			Some(c) if *c <= expiry => {}
+			_ => *cursor = Some(expiry),
+		});
+	}
+
+	/// Clear the expiry of the approval of asset `id` from `owner` to `delegate`, if it has one.
+	pub(super) fn clear_approval_expiry(
+		id: T::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+	) {
+		if let Some(expiry) = ApprovalExpiresAt::<T, I>::take((id, owner, delegate)) {
+			ApprovalExpiryQueue::<T, I>::remove(expiry, (id, owner.clone(), delegate.clone()));
+		}
+	}
+
+	/// Remove expired approvals queued for blocks up to `now`, oldest first, returning their
+	/// deposits, for as long as each step fits within `remaining_weight`.
+	///
+	/// Returns the weight used.
+	pub(super) fn sweep_approval_expiries(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+		let step = T::WeightInfo::sweep_approval_expiry();
+		if remaining_weight < step {
+			return 0
+		}
+		let mut cursor = match ApprovalExpiryCursor::<T, I>::get() {
+			Some(cursor) if cursor <= now => cursor,
+			_ => return T::DbWeight::get().reads(1),
+		};
+		let mut used: Weight = 0;
+		while cursor <= now && used.saturating_add(step) <= remaining_weight {
+			used = used.saturating_add(step);
+			match ApprovalExpiryQueue::<T, I>::iter_prefix(cursor).next() {
+				Some((key, ())) => {
+					ApprovalExpiryQueue::<T, I>::remove(cursor, &key);
+					Self::expire_approval(cursor, key);
+				}
+				None => cursor = cursor.saturating_add(One::one()),
+			}
+		}
+		ApprovalExpiryCursor::<T, I>::put(cursor);
+		used
+	}
+
+	/// Remove the approval `key` if it is still due to expire at `expiry`.
+	fn expire_approval(expiry: T::BlockNumber, key: (T::AssetId, T::AccountId, T::AccountId)) {
+		let (id, owner, delegate) = key;
+		if ApprovalExpiresAt::<T, I>::get((id, &owner, &delegate)) != Some(expiry) {
+			return
+		}
+		ApprovalExpiresAt::<T, I>::remove((id, &owner, &delegate));
+		if let Some(approval) = Approvals::<T, I>::take((id, &owner, &delegate)) {
+			T::Currency::unreserve(&owner, approval.deposit);
+			Self::dec_approvals(id);
+			Self::deposit_event(Event::ApprovalExpired(id, owner, delegate));
+		}
+	}
+
+	/// The amount of asset `id` which must remain in the account of `who`: any frozen by the
+	/// `Freezer` together with any reserved by holds.
+	pub(super) fn frozen_balance(id: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+		let reserved = Account::<T, I>::get(id, who).reserved;
+		match T::Freezer::frozen_balance(id, who) {
+			Some(frozen) => Some(frozen.saturating_add(reserved)),
+			None if !reserved.is_zero() => Some(reserved),
+			None => None,
+		}
+	}
+
+	/// Note that a swap proposal or scheduled transfer which refers to asset `id` was opened.
+	pub(super) fn add_reference(id: T::AssetId) -> DispatchResult {
+		OpenReferences::<T, I>::try_mutate(id, |count| -> DispatchResult {
+			*count = count.checked_add(1).ok_or(Error::<T, I>::Overflow)?;
+			Ok(())
+		})
+	}
+
+	/// Note that a swap proposal or scheduled transfer which refers to asset `id` was closed.
+	pub(super) fn remove_reference(id: T::AssetId) {
+		OpenReferences::<T, I>::mutate_exists(id, |maybe_count| {
+			*maybe_count = maybe_count.and_then(|count| count.checked_sub(1)).filter(|c| *c > 0);
+		});
+	}
+
+	/// Hold `amount` of asset `id` in the account of `who`, failing if that much is not
+	/// transferable while keeping the account alive.
+	pub(super) fn hold(id: T::AssetId, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		ensure!(amount <= Self::reducible_balance(id, who, true)?, Error::<T, I>::BalanceLow);
+		Account::<T, I>::try_mutate_exists(id, who, |maybe_account| -> DispatchResult {
+			let account = maybe_account.as_mut().ok_or(Error::<T, I>::BalanceLow)?;
+			account.reserved = account.reserved.saturating_add(amount);
+			Ok(())
+		})
+	}
+
+	/// Release `amount` of asset `id` previously held in the account of `who`.
+	pub(super) fn release(id: T::AssetId, who: &T::AccountId, amount: T::Balance) {
+		Account::<T, I>::mutate_exists(id, who, |maybe_account| {
+			if let Some(account) = maybe_account {
+				account.reserved = account.reserved.saturating_sub(amount);
+			}
+		});
 	}
 
-	pub(super) fn can_increase(
+	/// Return the consequence of depositing `amount` of asset `id` into the account of `who`.
+	///
+	/// This does not change any state, so may be used to check whether a deposit would succeed
+	/// before attempting it.
+	pub fn can_increase(
 		id: T::AssetId,
 		who: &T::AccountId,
 		amount: T::Balance,
@@ -85,6 +468,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Some(details) => details,
 			None => return DepositConsequence::UnknownAsset,
 		};
+		if details.status == AssetStatus::Destroying {
+			return DepositConsequence::UnknownAsset
+		}
 		if details.supply.checked_add(&amount).is_none() {
 			return DepositConsequence::Overflow
 		}
@@ -96,41 +482,75 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			if amount < details.min_balance {
 				return DepositConsequence::BelowMinimum
 			}
-			if !details.is_sufficient && frame_system::Pallet::<T>::providers(who) == 0 {
-				return DepositConsequence::CannotCreate
-			}
-			if details.is_sufficient && details.sufficients.checked_add(1).is_none() {
-				return DepositConsequence::Overflow
+			// An account created with `touch` already exists and needs no further reference.
+			if account.deposit.is_none() {
+				if !details.is_sufficient && frame_system::Pallet::<T>::providers(who) == 0 {
+					return DepositConsequence::CannotCreate
+				}
+				if details.is_sufficient && details.sufficients.checked_add(1).is_none() {
+					return DepositConsequence::Overflow
+				}
+				if details.accounts >= T::MaxAccountsPerAsset::get() {
+					return DepositConsequence::Overflow
+				}
 			}
 		}
 
 		DepositConsequence::Success
 	}
 
-	/// Return the consequence of a withdraw.
-	pub(super) fn can_decrease(
+	/// Convert the consequence of a deposit into a result, explaining that the recipient lacks an
+	/// existential deposit rather than only that its account cannot be created.
+	pub(super) fn deposit_result(conseq: DepositConsequence) -> DispatchResult {
+		match conseq {
+			DepositConsequence::CannotCreate => Err(Error::<T, I>::InsufficientProviders.into()),
+			c => c.into_result().map_err(Into::into),
+		}
+	}
+
+	/// Return the consequence of withdrawing `amount` of asset `id` from the account of `who`.
+	///
+	/// If `keep_alive` is `true`, then the withdrawal must leave the account of `who` alive. This
+	/// does not change any state, so may be used to check whether a withdrawal would succeed
+	/// before attempting it.
+	pub fn can_decrease(
+		id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		keep_alive: bool,
+	) -> WithdrawConsequence<T::Balance> {
+		Self::can_decrease_inner(id, who, amount, keep_alive, true)
+	}
+
+	/// As `can_decrease`, but freezes on the account or on the asset class are ignored unless
+	/// `respect_freezer` is `true`.
+	fn can_decrease_inner(
 		id: T::AssetId,
 		who: &T::AccountId,
 		amount: T::Balance,
 		keep_alive: bool,
+		respect_freezer: bool,
 	) -> WithdrawConsequence<T::Balance> {
 		use WithdrawConsequence::*;
 		let details = match Asset::<T, I>::get(id) {
 			Some(details) => details,
 			None => return UnknownAsset,
 		};
+		if details.status == AssetStatus::Destroying {
+			return UnknownAsset
+		}
 		if details.supply.checked_sub(&amount).is_none() {
 			return Underflow
 		}
-		if details.is_frozen {
+		if respect_freezer && details.is_frozen {
 			return Frozen
 		}
 		let account = Account::<T, I>::get(id, who);
-		if account.is_frozen {
+		if respect_freezer && account.is_frozen {
 			return Frozen
 		}
 		if let Some(rest) = account.balance.checked_sub(&amount) {
-			if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
+			if let Some(frozen) = Self::frozen_balance(id, who) {
 				match frozen.checked_add(&details.min_balance) {
 					Some(required) if rest < required => return Frozen,
 					None => return Overflow,
@@ -156,20 +576,71 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
-	// Maximum `amount` that can be passed into `can_withdraw` to result in a `WithdrawConsequence`
-	// of `Success`.
-	pub(super) fn reducible_balance(
+	/// Return why withdrawals of asset `id` from the account of `who` are suspended, if they are.
+	///
+	/// This distinguishes the two cases in which `can_decrease` returns `Frozen` regardless of the
+	/// amount: the asset class being frozen, which takes precedence, and the account being frozen.
+	pub fn frozen_kind(id: T::AssetId, who: &T::AccountId) -> Option<FrozenKind> {
+		if Asset::<T, I>::get(id)?.is_frozen {
+			Some(FrozenKind::AssetClass)
+		} else if Account::<T, I>::get(id, who).is_frozen {
+			Some(FrozenKind::Account)
+		} else {
+			None
+		}
+	}
+
+	/// Check whether `amount` of asset `id` could be transferred from `source` to `dest`, without
+	/// changing any state.
+	///
+	/// If `keep_alive` is `true`, then the transfer must leave `source` alive.
+	///
+	/// On success, returns the amount which would be credited to `dest`. This may be greater than
+	/// `amount` in the case that the transfer would otherwise leave `source` with less than the
+	/// minimum balance.
+	pub fn can_transfer(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		keep_alive: bool,
+	) -> Result<T::Balance, DispatchError> {
+		let f = DebitFlags { keep_alive, best_effort: false, respect_freezer: true };
+		let debit = Self::prep_debit(id, source, amount, f)?;
+		let (credit, _) = Self::prep_credit(id, dest, amount, debit, false)?;
+		Ok(credit)
+	}
+
+	/// Maximum `amount` that can be passed into `can_withdraw` to result in a `WithdrawConsequence`
+	/// of `Success`.
+	///
+	/// This respects the minimum balance, `keep_alive` and any frozen balance of `who`. Unlike
+	/// `fungibles::Inspect::reducible_balance`, the reason an account cannot be reduced at all is
+	/// returned as an error rather than as a zero balance.
+	pub fn reducible_balance(
+		id: T::AssetId,
+		who: &T::AccountId,
+		keep_alive: bool,
+	) -> Result<T::Balance, DispatchError> {
+		Self::reducible_balance_inner(id, who, keep_alive, true)
+	}
+
+	/// As `reducible_balance`, but freezes on the account or on the asset class are ignored unless
+	/// `respect_freezer` is `true`.
+	fn reducible_balance_inner(
 		id: T::AssetId,
 		who: &T::AccountId,
 		keep_alive: bool,
+		respect_freezer: bool,
 	) -> Result<T::Balance, DispatchError> {
 		let details = Asset::<T, I>::get(id).ok_or_else(|| Error::<T, I>::Unknown)?;
-		ensure!(!details.is_frozen, Error::<T, I>::Frozen);
+		ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+		ensure!(!respect_freezer || !details.is_frozen, Error::<T, I>::AssetClassFrozen);
 
 		let account = Account::<T, I>::get(id, who);
-		ensure!(!account.is_frozen, Error::<T, I>::Frozen);
+		ensure!(!respect_freezer || !account.is_frozen, Error::<T, I>::AccountFrozen);
 
-		let amount = if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
+		let amount = if let Some(frozen) = Self::frozen_balance(id, who) {
 			// Frozen balance: account CANNOT be deleted
 			let required = frozen
 				.checked_add(&details.min_balance)
@@ -196,7 +667,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///   less (in the case of `best_effort` being `true`) or greater by up to the minimum balance
 	///   less one.
 	/// - `keep_alive`: Require that `target` must stay alive.
-	/// - `respect_freezer`: Respect any freezes on the account or token (or not).
+	/// - `respect_freezer`: Respect any freezes on the account or token (or not). Balance frozen by
+	///   `T::Freezer` is respected either way.
 	/// - `best_effort`: The debit amount may be less than `amount`.
 	///
 	/// On success, the amount which should be debited (this will always be at least `amount` unless
@@ -210,10 +682,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		amount: T::Balance,
 		f: DebitFlags,
 	) -> Result<T::Balance, DispatchError> {
-		let actual = Self::reducible_balance(id, target, f.keep_alive)?.min(amount);
+		let actual =
+			Self::reducible_balance_inner(id, target, f.keep_alive, f.respect_freezer)?.min(amount);
 		ensure!(f.best_effort || actual >= amount, Error::<T, I>::BalanceLow);
 
-		let conseq = Self::can_decrease(id, target, actual, f.keep_alive);
+		let conseq =
+			Self::can_decrease_inner(id, target, actual, f.keep_alive, f.respect_freezer);
 		let actual = match conseq.into_result() {
 			Ok(dust) => actual.saturating_add(dust), //< guaranteed by reducible_balance
 			Err(e) => {
@@ -239,7 +713,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// will always be `None` as long as `burn_dust` is `false` or `debit` is no greater than
 	/// `amount`.
 	///
-	/// If no valid credit can be made then return an `Err`.
+	/// If no valid credit can be made then return an `Err`. This includes the case that `dest` has
+	/// a frozen balance which its new balance would not cover together with the minimum balance.
 	pub(super) fn prep_credit(
 		id: T::AssetId,
 		dest: &T::AccountId,
@@ -251,7 +726,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			(true, Some(dust)) => (amount, Some(dust)),
 			_ => (debit, None),
 		};
-		Self::can_increase(id, &dest, credit).into_result()?;
+		Self::deposit_result(Self::can_increase(id, &dest, credit))?;
+		if let Some(frozen) = Self::frozen_balance(id, dest) {
+			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			let required = frozen
+				.checked_add(&details.min_balance)
+				.ok_or(ArithmeticError::Overflow)?;
+			let new_balance = Account::<T, I>::get(id, dest).balance.saturating_add(credit);
+			ensure!(new_balance >= required, Error::<T, I>::WouldKillDestination);
+		}
 		Ok((credit, maybe_burn))
 	}
 
@@ -273,114 +756,541 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					Error::<T, I>::NoPermission
 				);
 			}
+			ensure!(details.can_mint(), Error::<T, I>::MintingDisabled);
+			ensure!(details.status != AssetStatus::Retiring, Error::<T, I>::AssetRetired);
 			debug_assert!(T::Balance::max_value() - details.supply >= amount, "checked in prep; qed");
-			details.supply = details.supply.saturating_add(amount);
+			let new_supply = details.supply.saturating_add(amount);
+			if let Some(max_supply) = details.max_supply {
+				ensure!(new_supply <= max_supply, Error::<T, I>::SupplyCapExceeded);
+			}
+			details.supply = new_supply;
 			Ok(())
 		})?;
 		Self::deposit_event(Event::Issued(id, beneficiary.clone(), amount));
+		T::OnTransfer::on_mint(id, beneficiary, amount);
 		Ok(())
 	}
 
-	/// Increases the asset `id` balance of `beneficiary` by `amount`.
-	///
-	/// LOW-LEVEL: Does not alter the supply of asset or emit an event. Use `do_mint` if you need
-	/// that. This is not intended to be used alone.
-	///
-	/// Will return an error or will increase the amount by exactly `amount`.
-	pub(super) fn increase_balance(
+	/// Mint asset `id` into each of `targets` as `issuer`, doing nothing unless all succeed.
+	#[transactional]
+	pub(super) fn do_mint_to_many(
 		id: T::AssetId,
-		beneficiary: &T::AccountId,
-		amount: T::Balance,
-		check: impl FnOnce(
-			&mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
-		) -> DispatchResult,
+		issuer: T::AccountId,
+		targets: Vec<(T::AccountId, T::Balance)>,
 	) -> DispatchResult {
-		if amount.is_zero() { return Ok(()) }
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.issuer == issuer, Error::<T, I>::NoPermission);
+		let total = targets.iter()
+			.try_fold(details.supply, |acc, (_, amount)| acc.checked_add(amount))
+			.ok_or(ArithmeticError::Overflow)?;
+		if let Some(max_supply) = details.max_supply {
+			ensure!(total <= max_supply, Error::<T, I>::SupplyCapExceeded);
+		}
 
-		Self::can_increase(id, beneficiary, amount).into_result()?;
-		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+		for (beneficiary, amount) in targets {
+			Self::do_mint(id, &beneficiary, amount, None)?;
+		}
+		Ok(())
+	}
+
+	/// Make each of `transfers` in turn on behalf of `origin`, doing nothing unless all succeed.
+	///
+	/// A transfer from an account other than `origin` uses the approval it has given to `origin`.
+	#[transactional]
+	pub(super) fn do_multi_asset_transfer(
+		origin: &T::AccountId,
+		transfers: Vec<MultiAssetTransfer<T::AssetId, T::AccountId, T::Balance>>,
+	) -> DispatchResult {
+		let f = TransferFlags {
+			keep_alive: false,
+			best_effort: false,
+			burn_dust: false,
+			respect_freezer: true,
+		};
+		for t in transfers {
+			if &t.source == origin {
+				Self::do_transfer(t.asset, origin, &t.dest, t.amount, None, f)?;
+			} else {
+				Self::do_transfer_approved(t.asset, &t.source, origin, &t.dest, t.amount, false)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Change those members of the team of asset `id` which are given, on behalf of its owner
+	/// `origin`, emitting `TeamChanged` with the whole resulting team.
+	pub(super) fn do_set_team(
+		id: T::AssetId,
+		origin: T::AccountId,
+		issuer: Option<T::AccountId>,
+		admin: Option<T::AccountId>,
+		freezer: Option<T::AccountId>,
+	) -> DispatchResult {
+		Asset::<T, I>::try_mutate(id, |maybe_details| {
 			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(&origin == &details.owner, Error::<T, I>::NoPermission);
 
-			check(details)?;
+			if let Some(issuer) = issuer {
+				details.issuer = issuer;
+			}
+			if let Some(admin) = admin {
+				details.admin = admin;
+			}
+			if let Some(freezer) = freezer {
+				details.freezer = freezer;
+			}
 
-			Account::<T, I>::try_mutate(id, beneficiary, |t| -> DispatchResult {
-				let new_balance = t.balance.saturating_add(amount);
-				ensure!(new_balance >= details.min_balance, TokenError::BelowMinimum);
-				if t.balance.is_zero() {
-					t.sufficient = Self::new_account(beneficiary, details)?;
-				}
-				t.balance = new_balance;
-				Ok(())
-			})?;
+			Self::deposit_event(Event::TeamChanged(
+				id,
+				details.issuer.clone(),
+				details.admin.clone(),
+				details.freezer.clone(),
+			));
 			Ok(())
-		})?;
-		Ok(())
+		})
 	}
 
-	/// Reduces asset `id` balance of `target` by `amount`. Flags `f` can be given to alter whether
-	/// it attempts a `best_effort` or makes sure to `keep_alive` the account.
-	///
-	/// This alters the registered supply of the asset and emits an event.
+	/// Set the maximum supply of asset `id` to `max_supply`.
 	///
-	/// Will return an error and do nothing or will decrease the amount and return the amount
-	/// reduced by.
-	pub(super) fn do_burn(
+	/// If `maybe_check_owner` is `Some`, then it must be the owner of the asset and the new cap
+	/// must be no less than the current supply of the asset. Otherwise the cap may be set below
+	/// the supply, which may then be brought under it with `do_burn_excess_above_cap`.
+	pub(super) fn do_set_supply_cap(
 		id: T::AssetId,
-		target: &T::AccountId,
-		amount: T::Balance,
-		maybe_check_admin: Option<T::AccountId>,
-		f: DebitFlags,
-	) -> Result<T::Balance, DispatchError> {
-		let actual = Self::decrease_balance(id, target, amount, f, |actual, details| {
-			// Check admin rights.
-			if let Some(check_admin) = maybe_check_admin {
-				ensure!(&check_admin == &details.admin, Error::<T, I>::NoPermission);
+		max_supply: Option<T::Balance>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		Asset::<T, I>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(&check_owner == &details.owner, Error::<T, I>::NoPermission);
+				if let Some(cap) = max_supply {
+					ensure!(cap >= details.supply, Error::<T, I>::SupplyCapExceeded);
+				}
 			}
 
-			debug_assert!(details.supply >= actual, "checked in prep; qed");
-			details.supply = details.supply.saturating_sub(actual);
+			details.max_supply = max_supply;
 
+			Self::deposit_event(Event::CapChanged(id, max_supply));
 			Ok(())
-		})?;
-		Self::deposit_event(Event::Burned(id, target.clone(), actual));
-		Ok(actual)
+		})
 	}
 
-	/// Reduces asset `id` balance of `target` by `amount`. Flags `f` can be given to alter whether
-	/// it attempts a `best_effort` or makes sure to `keep_alive` the account.
-	///
-	/// LOW-LEVEL: Does not alter the supply of asset or emit an event. Use `do_burn` if you need
-	/// that. This is not intended to be used alone.
-	///
-	/// Will return an error and do nothing or will decrease the amount and return the amount
-	/// reduced by.
-	pub(super) fn decrease_balance(
+	/// Create the asset `id` owned by `owner`, with `admin` as each member of its team, reserving
+	/// the asset deposit from `owner`.
+	pub(super) fn do_create(
 		id: T::AssetId,
-		target: &T::AccountId,
-		amount: T::Balance,
-		f: DebitFlags,
-		check: impl FnOnce(
-			T::Balance,
-			&mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
-		) -> DispatchResult,
-	) -> Result<T::Balance, DispatchError> {
-		if amount.is_zero() { return Ok(amount) }
+		owner: T::AccountId,
+		admin: T::AccountId,
+		min_balance: T::Balance,
+		max_supply: Option<T::Balance>,
+	) -> DispatchResult {
+		ensure!(!Asset::<T, I>::contains_key(id), Error::<T, I>::InUse);
+		ensure!(!min_balance.is_zero(), Error::<T, I>::MinBalanceZero);
 
-		let actual = Self::prep_debit(id, target, amount, f)?;
+		let deposit = T::AssetDeposit::get();
+		T::Currency::reserve(&owner, deposit)?;
 
-		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
-			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+		let details = AssetDetailsBuilder::new(owner.clone())
+			.issuer(admin.clone())
+			.admin(admin.clone())
+			.freezer(admin.clone())
+			.max_supply(max_supply)
+			.min_balance(min_balance)
+			.deposit(deposit)
+			.build();
+		Asset::<T, I>::insert(id, details);
+		Self::deposit_event(Event::Created(id, owner, admin));
+		Ok(())
+	}
 
-			check(actual, details)?;
+	/// The first identifier from `NextAssetId` onwards which is not in use by an asset, trying at
+	/// most `MAX_ASSET_ID_PROBES` identifiers.
+	pub(super) fn next_free_asset_id() -> Result<T::AssetId, DispatchError> {
+		let mut id = NextAssetId::<T, I>::get();
+		for _ in 0..MAX_ASSET_ID_PROBES {
+			if !Asset::<T, I>::contains_key(id) {
+				return Ok(id)
+			}
+			id = id.increment();
+		}
+		Err(Error::<T, I>::InUse.into())
+	}
 
-			Account::<T, I>::try_mutate_exists(id, target, |maybe_account| -> DispatchResult {
-				let mut account = maybe_account.take().unwrap_or_default();
-				debug_assert!(account.balance >= actual, "checked in prep; qed");
+	/// Create asset `id` with the given `details` and `metadata`, reserving both of their deposits
+	/// from the owner and emitting `event` followed by `MetadataSet`.
+	pub(super) fn do_create_with_metadata(
+		id: T::AssetId,
+		details: AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		metadata: MetadataOf<T, I>,
+		event: Event<T, I>,
+	) -> DispatchResult {
+		ensure!(!Asset::<T, I>::contains_key(id), Error::<T, I>::InUse);
 
-				// Make the debit.
-				account.balance = account.balance.saturating_sub(actual);
-				*maybe_account = if account.balance < details.min_balance {
-					debug_assert!(account.balance.is_zero(), "checked in prep; qed");
+		let deposit = details.deposit.saturating_add(metadata.deposit);
+		T::Currency::reserve(&details.depositor, deposit)?;
+
+		Self::deposit_event(event);
+		Self::deposit_event(Event::MetadataSet(
+			id,
+			metadata.name.to_vec(),
+			metadata.symbol.to_vec(),
+			metadata.decimals,
+			metadata.is_frozen,
+			metadata.image_hash,
+			metadata.uri.as_ref().map(|uri| uri.to_vec()),
+		));
+		Asset::<T, I>::insert(id, details);
+		Metadata::<T, I>::insert(id, metadata);
+		Ok(())
+	}
+
+	/// Burn as much as possible up to `max_amount` of asset `id` from `target` on behalf of the
+	/// admin `check_admin`, leaving `target` with at least the minimum balance and any frozen
+	/// balance.
+	///
+	/// Returns the amount burned, which is zero if nothing could be burned.
+	pub(super) fn do_soft_burn(
+		id: T::AssetId,
+		target: &T::AccountId,
+		max_amount: T::Balance,
+		check_admin: T::AccountId,
+	) -> Result<T::Balance, DispatchError> {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(&check_admin == &details.admin, Error::<T, I>::NoPermission);
+
+		let amount = Self::reducible_balance(id, target, true)?.min(max_amount);
+		let f = DebitFlags { keep_alive: true, best_effort: false, respect_freezer: true };
+		Self::do_burn(id, target, amount, None, f)
+	}
+
+	/// Burn the supply of asset `id` in excess of its maximum supply from the account of its
+	/// admin `origin`, returning the amount burned.
+	///
+	/// If `best_effort` is `false` and the admin holds less than the excess, this fails with
+	/// `BalanceLow`; otherwise as much as the admin holds is burned.
+	pub(super) fn do_burn_excess_above_cap(
+		id: T::AssetId,
+		origin: T::AccountId,
+		best_effort: bool,
+	) -> Result<T::Balance, DispatchError> {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(origin == details.admin, Error::<T, I>::NoPermission);
+		let excess = details.max_supply
+			.map_or_else(Zero::zero, |cap| details.supply.saturating_sub(cap));
+		ensure!(!excess.is_zero(), Error::<T, I>::NoExcessSupply);
+		ensure!(best_effort || Self::balance(id, &origin) >= excess, Error::<T, I>::BalanceLow);
+
+		let f = DebitFlags { keep_alive: false, best_effort, respect_freezer: true };
+		let burned = Self::do_burn(id, &origin, excess, Some(origin.clone()), f)?;
+		Self::deposit_event(Event::SupplyCapEnforced(id, burned));
+		Ok(burned)
+	}
+
+	/// Freeze or thaw the asset class `id`, noting why if `reason` is given.
+	///
+	/// `origin` must be the Freezer of the asset to freeze it, or its Admin to thaw it.
+	pub(super) fn do_set_asset_class_frozen(
+		id: T::AssetId,
+		origin: T::AccountId,
+		frozen: bool,
+		reason: Option<Vec<u8>>,
+	) -> DispatchResult {
+		if let Some(ref reason) = reason {
+			ensure!(reason.len() <= T::StringLimit::get() as usize, Error::<T, I>::BadReason);
+		}
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+			let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			let role = if frozen { &d.freezer } else { &d.admin };
+			ensure!(&origin == role, Error::<T, I>::NoPermission);
+			d.is_frozen = frozen;
+			Ok(())
+		})?;
+
+		if frozen {
+			Self::deposit_event(Event::AssetFrozen(id, reason));
+		} else {
+			Self::deposit_event(Event::AssetThawed(id, reason));
+		}
+		Ok(())
+	}
+
+	/// Set the metadata of asset `id` on behalf of its owner `origin`, adjusting the reserved
+	/// metadata deposit to the new lengths and emitting `MetadataSet`.
+	#[transactional]
+	pub(super) fn do_set_metadata(
+		id: T::AssetId,
+		origin: T::AccountId,
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+		decimals: u8,
+		image_hash: Option<[u8; 32]>,
+	) -> DispatchResult {
+		let bounded_name: BoundedVec<u8, T::StringLimit> = name.clone().try_into()
+			.map_err(|_| Error::<T, I>::BadMetadata)?;
+		let bounded_symbol: BoundedVec<u8, T::StringLimit> = symbol.clone().try_into()
+			.map_err(|_| Error::<T, I>::BadMetadata)?;
+
+		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(&origin == &d.owner, Error::<T, I>::NoPermission);
+		Self::take_over_deposits(id, d)?;
+
+		Metadata::<T, I>::try_mutate_exists(id, |metadata| {
+			ensure!(
+				metadata.as_ref().map_or(true, |m| !m.is_frozen),
+				Error::<T, I>::NoPermission
+			);
+
+			let (old_deposit, uri) = metadata.take()
+				.map_or((Zero::zero(), None), |m| (m.deposit, m.uri));
+			let uri_len = uri.as_ref().map_or(0, |uri| uri.len());
+			let new_deposit = T::MetadataDepositPerByte::get()
+				.saturating_mul(((name.len() + symbol.len() + uri_len) as u32).into())
+				.saturating_add(T::MetadataDepositBase::get());
+
+			if new_deposit > old_deposit {
+				T::Currency::reserve(&origin, new_deposit - old_deposit)?;
+			} else {
+				T::Currency::unreserve(&origin, old_deposit - new_deposit);
+			}
+
+			*metadata = Some(AssetMetadata {
+				deposit: new_deposit,
+				name: bounded_name,
+				symbol: bounded_symbol,
+				decimals,
+				is_frozen: false,
+				image_hash,
+				uri: uri.clone(),
+			});
+
+			let uri = uri.map(|uri| uri.into_inner());
+			let event = Event::MetadataSet(id, name, symbol, decimals, false, image_hash, uri);
+			Self::deposit_event(event);
+			Ok(())
+		})
+	}
+
+	/// Set or clear the metadata URI of asset `id` on behalf of its owner `origin`, adjusting the
+	/// reserved metadata deposit to the new length and emitting `MetadataSet`.
+	#[transactional]
+	pub(super) fn do_set_metadata_url(
+		id: T::AssetId,
+		origin: T::AccountId,
+		uri: Option<Vec<u8>>,
+	) -> DispatchResult {
+		let uri: Option<BoundedVec<u8, T::UriLimit>> = uri
+			.map(|uri| uri.try_into().map_err(|_| Error::<T, I>::BadMetadata))
+			.transpose()?;
+
+		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(&origin == &d.owner, Error::<T, I>::NoPermission);
+		Self::take_over_deposits(id, d)?;
+
+		Metadata::<T, I>::try_mutate_exists(id, |maybe_metadata| {
+			let metadata = maybe_metadata.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(!metadata.is_frozen, Error::<T, I>::NoPermission);
+
+			let uri_deposit = |uri: &Option<BoundedVec<u8, T::UriLimit>>| {
+				let len = uri.as_ref().map_or(0, |uri| uri.len() as u32);
+				T::MetadataDepositPerByte::get().saturating_mul(len.into())
+			};
+			let old_deposit = metadata.deposit;
+			let new_deposit = old_deposit
+				.saturating_sub(uri_deposit(&metadata.uri))
+				.saturating_add(uri_deposit(&uri));
+
+			if new_deposit > old_deposit {
+				T::Currency::reserve(&origin, new_deposit - old_deposit)?;
+			} else {
+				T::Currency::unreserve(&origin, old_deposit - new_deposit);
+			}
+			metadata.deposit = new_deposit;
+			metadata.uri = uri;
+
+			Self::deposit_event(Event::MetadataSet(
+				id,
+				metadata.name.to_vec(),
+				metadata.symbol.to_vec(),
+				metadata.decimals,
+				metadata.is_frozen,
+				metadata.image_hash,
+				metadata.uri.as_ref().map(|uri| uri.to_vec()),
+			));
+			Ok(())
+		})
+	}
+
+	/// Set the frozen flag of each of the `targets` accounts of asset `id` to `freeze`, emitting
+	/// `Frozen` or `Thawed` for each. Targets without an account are skipped.
+	///
+	/// Returns the number of accounts updated and the number skipped.
+	pub(super) fn do_set_accounts_frozen(
+		id: T::AssetId,
+		targets: Vec<T::AccountId>,
+		freeze: bool,
+	) -> (u32, u32) {
+		let (mut done, mut skipped) = (0u32, 0u32);
+		for who in targets {
+			if !Account::<T, I>::contains_key(id, &who) {
+				skipped = skipped.saturating_add(1);
+				continue
+			}
+			Account::<T, I>::mutate(id, &who, |a| a.is_frozen = freeze);
+			done = done.saturating_add(1);
+			Self::deposit_event(if freeze {
+				Event::<T, I>::Frozen(id, who)
+			} else {
+				Event::<T, I>::Thawed(id, who)
+			});
+		}
+		(done, skipped)
+	}
+
+	/// Change the minimum balance of asset `id` to `min_balance`.
+	///
+	/// Raising the minimum balance requires every account holding a non-zero balance to already
+	/// hold at least `min_balance`, which makes it `O(A)` in the number of accounts.
+	///
+	/// Returns the number of accounts inspected, which are all those of the asset when raising
+	/// its minimum balance and none otherwise.
+	pub(super) fn do_set_min_balance(
+		id: T::AssetId,
+		min_balance: T::Balance,
+		accounts_witness: u32,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> Result<u32, DispatchError> {
+		ensure!(!min_balance.is_zero(), Error::<T, I>::MinBalanceZero);
+		Asset::<T, I>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(&check_owner == &details.owner, Error::<T, I>::NoPermission);
+			}
+			ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+
+			let old_min_balance = details.min_balance;
+			let mut inspected = 0;
+			if min_balance > old_min_balance {
+				ensure!(details.accounts <= accounts_witness, Error::<T, I>::BadWitness);
+				let too_low = Account::<T, I>::iter_prefix_values(id)
+					.any(|a| !a.balance.is_zero() && a.balance < min_balance);
+				ensure!(!too_low, Error::<T, I>::MinBalanceTooHigh);
+				inspected = details.accounts;
+			}
+
+			details.min_balance = min_balance;
+
+			Self::deposit_event(Event::MinBalanceChanged(id, old_min_balance, min_balance));
+			Ok(inspected)
+		})
+	}
+
+	/// Increases the asset `id` balance of `beneficiary` by `amount`.
+	///
+	/// LOW-LEVEL: Does not alter the supply of asset or emit an event. Use `do_mint` if you need
+	/// that. This is not intended to be used alone.
+	///
+	/// Will return an error or will increase the amount by exactly `amount`.
+	pub(super) fn increase_balance(
+		id: T::AssetId,
+		beneficiary: &T::AccountId,
+		amount: T::Balance,
+		check: impl FnOnce(
+			&mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		) -> DispatchResult,
+	) -> DispatchResult {
+		if amount.is_zero() { return Ok(()) }
+
+		Self::deposit_result(Self::can_increase(id, beneficiary, amount))?;
+		let (old_balance, new_balance) = Asset::<T, I>::try_mutate(id, |maybe_details| -> Result<_, DispatchError> {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+
+			check(details)?;
+
+			Account::<T, I>::try_mutate(id, beneficiary, |t| -> Result<_, DispatchError> {
+				let old_balance = t.balance;
+				let new_balance = t.balance.saturating_add(amount);
+				ensure!(new_balance >= details.min_balance, TokenError::BelowMinimum);
+				if t.balance.is_zero() && t.deposit.is_none() {
+					t.sufficient = Self::new_account(id, beneficiary, details)?;
+				}
+				t.balance = new_balance;
+				Ok((old_balance, new_balance))
+			})
+		})?;
+		Self::balance_changed(id, beneficiary, old_balance, new_balance);
+		Ok(())
+	}
+
+	/// Reduces asset `id` balance of `target` by `amount`. Flags `f` can be given to alter whether
+	/// it attempts a `best_effort` or makes sure to `keep_alive` the account.
+	///
+	/// This alters the registered supply of the asset and emits an event.
+	///
+	/// Will return an error and do nothing or will decrease the amount and return the amount
+	/// reduced by.
+	pub(super) fn do_burn(
+		id: T::AssetId,
+		target: &T::AccountId,
+		amount: T::Balance,
+		maybe_check_admin: Option<T::AccountId>,
+		f: DebitFlags,
+	) -> Result<T::Balance, DispatchError> {
+		let actual = Self::decrease_balance(id, target, amount, f, |actual, details| {
+			// Check admin rights.
+			if let Some(check_admin) = maybe_check_admin {
+				ensure!(&check_admin == &details.admin, Error::<T, I>::NoPermission);
+			}
+
+			debug_assert!(details.supply >= actual, "checked in prep; qed");
+			details.supply = details.supply.saturating_sub(actual);
+
+			Ok(())
+		})?;
+		Self::deposit_event(Event::Burned(id, target.clone(), actual));
+		T::OnTransfer::on_burn(id, target, actual);
+		Ok(actual)
+	}
+
+	/// Reduces asset `id` balance of `target` by `amount`. Flags `f` can be given to alter whether
+	/// it attempts a `best_effort` or makes sure to `keep_alive` the account.
+	///
+	/// LOW-LEVEL: Does not alter the supply of asset or emit an event. Use `do_burn` if you need
+	/// that. This is not intended to be used alone.
+	///
+	/// Will return an error and do nothing or will decrease the amount and return the amount
+	/// reduced by.
+	pub(super) fn decrease_balance(
+		id: T::AssetId,
+		target: &T::AccountId,
+		amount: T::Balance,
+		f: DebitFlags,
+		check: impl FnOnce(
+			T::Balance,
+			&mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		) -> DispatchResult,
+	) -> Result<T::Balance, DispatchError> {
+		if amount.is_zero() { return Ok(amount) }
+
+		let actual = Self::prep_debit(id, target, amount, f)?;
+		let mut old_balance = Zero::zero();
+		let mut new_balance = Zero::zero();
+
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+
+			check(actual, details)?;
+
+			Account::<T, I>::try_mutate_exists(id, target, |maybe_account| -> DispatchResult {
+				let mut account = maybe_account.take().unwrap_or_default();
+				debug_assert!(account.balance >= actual, "checked in prep; qed");
+
+				// Make the debit.
+				old_balance = account.balance;
+				account.balance = account.balance.saturating_sub(actual);
+				new_balance = account.balance;
+				*maybe_account = if account.balance < details.min_balance && account.deposit.is_none() {
+					debug_assert!(account.balance.is_zero(), "checked in prep; qed");
 					Self::dead_account(id, target, details, account.sufficient);
 					None
 				} else {
@@ -392,6 +1302,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
+		Self::balance_changed(id, target, old_balance, new_balance);
 		Ok(actual)
 	}
 
@@ -399,7 +1310,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// `dest` by (similar) amount.
 	///
 	/// Returns the actual amount placed into `dest`. Exact semantics are determined by the flags
-	/// `f`.
+	/// `f`. If the asset has a transfer fee, then that proportion of the amount is placed into the
+	/// fee beneficiary instead.
 	///
 	/// Will fail if the amount transferred is so small that it cannot create the destination due
 	/// to minimum balance requirements.
@@ -411,17 +1323,37 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		maybe_need_admin: Option<T::AccountId>,
 		f: TransferFlags,
 	) -> Result<T::Balance, DispatchError> {
-		// Early exist if no-op.
-		if amount.is_zero() {
-			Self::deposit_event(Event::Transferred(id, source.clone(), dest.clone(), amount));
-			return Ok(amount)
+		// Transfers forced by the Admin are not subject to the guard.
+		if maybe_need_admin.is_none() {
+			T::TransferGuard::allow_transfer(id, source, dest, amount)?;
+		}
+
+		// Early exist if no-op, which a best-effort transfer is when nothing can be debited.
+		let debit = if amount.is_zero() {
+			amount
+		} else {
+			Self::prep_debit(id, &source, amount, f.into())?
+		};
+		if debit.is_zero() {
+			Self::deposit_event(Event::Transferred(id, source.clone(), dest.clone(), debit));
+			T::OnTransfer::on_transfer(id, source, dest, debit);
+			return Ok(debit)
 		}
 
-		// Figure out the debit and credit, together with side-effects.
-		let debit = Self::prep_debit(id, &source, amount, f.into())?;
+		// Figure out the credit, together with side-effects.
 		let (credit, maybe_burn) = Self::prep_credit(id, &dest, amount, debit, f.burn_dust)?;
+		let maybe_fee = Self::prep_transfer_fee(id, &source, &dest, debit, credit)?;
+		let credit = maybe_fee.as_ref().map_or(credit, |(_, fee)| credit.saturating_sub(*fee));
+		let maybe_volume = if source != dest {
+			Self::prep_transfer_volume(id, amount)?
+		} else {
+			None
+		};
 
 		let mut source_account = Account::<T, I>::get(id, &source);
+		let source_balance = source_account.balance;
+		let mut dest_balances = (Zero::zero(), Zero::zero());
+		let mut fee_balances = (Zero::zero(), Zero::zero());
 
 		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
 			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
@@ -447,22 +1379,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			debug_assert!(source_account.balance >= debit, "checked in prep; qed");
 			source_account.balance = source_account.balance.saturating_sub(debit);
 
-			Account::<T, I>::try_mutate(id, &dest, |a| -> DispatchResult {
-				// Calculate new balance; this will not saturate since it's already checked in prep.
-				debug_assert!(a.balance.checked_add(&credit).is_some(), "checked in prep; qed");
-				let new_balance = a.balance.saturating_add(credit);
-
-				// Create a new account if there wasn't one already.
-				if a.balance.is_zero() {
-					a.sufficient = Self::new_account(&dest, details)?;
-				}
-
-				a.balance = new_balance;
-				Ok(())
-			})?;
+			dest_balances = Self::credit_account(id, &dest, credit, details)?;
+			if let Some((beneficiary, fee)) = maybe_fee.as_ref() {
+				fee_balances = Self::credit_account(id, beneficiary, *fee, details)?;
+			}
 
-			// Remove source account if it's now dead.
-			if source_account.balance < details.min_balance {
+			// Remove source account if it's now dead. Accounts created with `touch` are kept.
+			if source_account.balance < details.min_balance && source_account.deposit.is_none() {
 				debug_assert!(source_account.balance.is_zero(), "checked in prep; qed");
 				Self::dead_account(id, &source, details, source_account.sufficient);
 				Account::<T, I>::remove(id, &source);
@@ -473,7 +1396,821 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
+		if let Some(volume) = maybe_volume {
+			TransferVolume::<T, I>::insert(id, volume);
+		}
+		if source != dest {
+			Self::balance_changed(id, &source, source_balance, source_account.balance);
+			Self::balance_changed(id, &dest, dest_balances.0, dest_balances.1);
+		}
 		Self::deposit_event(Event::Transferred(id, source.clone(), dest.clone(), credit));
+		T::OnTransfer::on_transfer(id, source, dest, credit);
+		if let Some((beneficiary, fee)) = maybe_fee {
+			Self::balance_changed(id, &beneficiary, fee_balances.0, fee_balances.1);
+			let event = Event::TransferFeePaid(id, source.clone(), beneficiary.clone(), fee);
+			Self::deposit_event(event);
+			T::OnTransfer::on_transfer(id, source, &beneficiary, fee);
+		}
 		Ok(credit)
 	}
+
+	/// Figure out the fee due on a transfer of asset `id` from `source` to `dest`, which debits
+	/// `debit` from `source` and credits `credit` to `dest`, together with the account it is paid
+	/// to.
+	///
+	/// The fee is the asset's rate of `debit`, and is taken out of `credit`. There is no fee if the
+	/// asset has none configured, if it would be zero, if the fee beneficiary is either side of the
+	/// transfer or if the beneficiary cannot receive it, such as when it is below the minimum
+	/// balance and the beneficiary has no account. Fails with `FeeTooLarge` if the remainder
+	/// cannot be credited to `dest` due to minimum balance requirements.
+	pub(super) fn prep_transfer_fee(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		debit: T::Balance,
+		credit: T::Balance,
+	) -> Result<Option<(T::AccountId, T::Balance)>, DispatchError> {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		let (rate, beneficiary) = match details.transfer_fee {
+			Some((rate, beneficiary)) => (rate, beneficiary),
+			None => return Ok(None),
+		};
+		if source == dest || &beneficiary == source || &beneficiary == dest {
+			return Ok(None)
+		}
+		let fee = rate.mul_floor(debit).min(credit);
+		if fee.is_zero() {
+			return Ok(None)
+		}
+
+		match Self::can_increase(id, dest, credit.saturating_sub(fee)) {
+			DepositConsequence::BelowMinimum => Err(Error::<T, I>::FeeTooLarge)?,
+			c => Self::deposit_result(c)?,
+		}
+		// Waive a fee which the beneficiary cannot receive rather than blocking every transfer.
+		if Self::can_increase(id, &beneficiary, fee).into_result().is_err() {
+			return Ok(None)
+		}
+		Ok(Some((beneficiary, fee)))
+	}
+
+	/// Figure out the start of the current transfer rate limit window of asset `id`, together
+	/// with the volume transferred within it once another `amount` is.
+	///
+	/// There is nothing to record if the asset has no rate limit. A new window starts now if the
+	/// last one has ended. Fails with `TransferLimitExceeded` if the volume would exceed the limit.
+	pub(super) fn prep_transfer_volume(
+		id: T::AssetId,
+		amount: T::Balance,
+	) -> Result<Option<(T::BlockNumber, T::Balance)>, DispatchError> {
+		let (max_volume, window_blocks) = match TransferRateLimitConfig::<T, I>::get(id) {
+			Some(limit) => limit,
+			None => return Ok(None),
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		let (start, volume) = TransferVolume::<T, I>::get(id)
+			.filter(|(start, _)| now.saturating_sub(*start) < window_blocks)
+			.unwrap_or((now, Zero::zero()));
+		let volume = volume.checked_add(&amount)
+			.filter(|volume| volume <= &max_volume)
+			.ok_or(Error::<T, I>::TransferLimitExceeded)?;
+		Ok(Some((start, volume)))
+	}
+
+	/// Increase the asset `id` balance of `who` by `amount`, creating the account if needed, and
+	/// return the old and new balance.
+	///
+	/// LOW-LEVEL: Does not alter the supply of asset, emit an event or check that the credit is
+	/// possible. This is not intended to be used alone.
+	fn credit_account(
+		id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		details: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+	) -> Result<(T::Balance, T::Balance), DispatchError> {
+		Account::<T, I>::try_mutate(id, who, |a| {
+			// Calculate new balance; this will not saturate since it's already checked in prep.
+			debug_assert!(a.balance.checked_add(&amount).is_some(), "checked in prep; qed");
+			let new_balance = a.balance.saturating_add(amount);
+
+			// Create a new account if there wasn't one already.
+			if a.balance.is_zero() && a.deposit.is_none() {
+				a.sufficient = Self::new_account(id, who, details)?;
+			}
+
+			let old_balance = a.balance;
+			a.balance = new_balance;
+			Ok((old_balance, new_balance))
+		})
+	}
+
+	/// Set the transfer fee of asset `id` to `rate` of each transfer, paid to `beneficiary`. A
+	/// zero `rate` removes the fee.
+	pub(super) fn do_set_transfer_fee(
+		id: T::AssetId,
+		rate: Perbill,
+		beneficiary: T::AccountId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		Asset::<T, I>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(&check_owner == &details.owner, Error::<T, I>::NoPermission);
+			}
+
+			details.transfer_fee = if rate.is_zero() {
+				None
+			} else {
+				Some((rate, beneficiary.clone()))
+			};
+
+			Self::deposit_event(Event::TransferFeeChanged(id, rate, beneficiary));
+			Ok(())
+		})
+	}
+
+	/// Transfer from `source` to each of `targets` its respective amount.
+	///
+	/// Either all of the transfers succeed or none of them are made. The source account is read
+	/// and written, and the asset details mutated, only once. Any transfer fee of the asset is
+	/// taken from each of the transfers. If the source is left with less than the minimum balance,
+	/// then it is removed and the remainder credited to the last of the targets, as `do_transfer`
+	/// credits it to its destination.
+	pub(super) fn do_transfer_to_many(
+		id: T::AssetId,
+		source: &T::AccountId,
+		targets: Vec<(T::AccountId, T::Balance)>,
+	) -> DispatchResult {
+		let mut targets = targets.into_iter()
+			.filter(|(dest, amount)| dest != source && !amount.is_zero())
+			.collect::<Vec<_>>();
+		let total = targets.iter()
+			.try_fold(T::Balance::zero(), |acc, (_, amount)| acc.checked_add(amount))
+			.ok_or(ArithmeticError::Overflow)?;
+		if total.is_zero() {
+			return Ok(())
+		}
+
+		// Figure out the debit and check every credit before making any changes.
+		let f = DebitFlags { keep_alive: false, best_effort: false, respect_freezer: true };
+		let debit = Self::prep_debit(id, source, total, f)?;
+		let maybe_volume = Self::prep_transfer_volume(id, total)?;
+		if let Some((_, amount)) = targets.last_mut() {
+			*amount = amount.saturating_add(debit.saturating_sub(total));
+		}
+		let mut fees = Vec::with_capacity(targets.len());
+		for (dest, amount) in targets.iter() {
+			Self::prep_credit(id, dest, *amount, *amount, false)?;
+			fees.push(Self::prep_transfer_fee(id, source, dest, *amount, *amount)?);
+		}
+		for (dest, amount) in targets.iter() {
+			T::TransferGuard::allow_transfer(id, source, dest, *amount)?;
+		}
+
+		let mut source_account = Account::<T, I>::get(id, source);
+		let source_balance = source_account.balance;
+		let mut dest_balances = Vec::with_capacity(targets.len());
+		let mut fee_balances = Vec::with_capacity(targets.len());
+
+		// The same account may be credited more than once, so every credit is checked again.
+		let credit = |who: &T::AccountId, amount: T::Balance, details: &mut AssetDetails<_, _, _>| {
+			Account::<T, I>::try_mutate(id, who, |a| {
+				let new_balance = a.balance.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+
+				// Create a new account if there wasn't one already.
+				if a.balance.is_zero() && a.deposit.is_none() {
+					a.sufficient = Self::new_account(id, who, details)?;
+				}
+
+				let old_balance = a.balance;
+				a.balance = new_balance;
+				Ok::<_, DispatchError>((old_balance, new_balance))
+			})
+		};
+
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+
+			// Debit balance from source; this will not saturate since it's already checked in prep.
+			debug_assert!(source_account.balance >= debit, "checked in prep; qed");
+			source_account.balance = source_account.balance.saturating_sub(debit);
+
+			for ((dest, amount), maybe_fee) in targets.iter().zip(fees.iter()) {
+				let fee = maybe_fee.as_ref().map_or_else(Zero::zero, |(_, fee)| *fee);
+				dest_balances.push(credit(dest, amount.saturating_sub(fee), details)?);
+				if let Some((beneficiary, fee)) = maybe_fee {
+					fee_balances.push(credit(beneficiary, *fee, details)?);
+				}
+			}
+
+			// Remove source account if it's now dead. Accounts created with `touch` are kept.
+			if source_account.balance < details.min_balance && source_account.deposit.is_none() {
+				debug_assert!(source_account.balance.is_zero(), "checked in prep; qed");
+				Self::dead_account(id, source, details, source_account.sufficient);
+				Account::<T, I>::remove(id, source);
+			} else {
+				Account::<T, I>::insert(id, source, &source_account)
+			}
+
+			Ok(())
+		})?;
+
+		if let Some(volume) = maybe_volume {
+			TransferVolume::<T, I>::insert(id, volume);
+		}
+		Self::balance_changed(id, source, source_balance, source_account.balance);
+		for ((dest, _), (old, new)) in targets.iter().zip(dest_balances) {
+			Self::balance_changed(id, dest, old, new);
+		}
+		let mut fee_balances = fee_balances.into_iter();
+		for ((dest, amount), maybe_fee) in targets.into_iter().zip(fees) {
+			let fee = maybe_fee.as_ref().map_or_else(Zero::zero, |(_, fee)| *fee);
+			let credit = amount.saturating_sub(fee);
+			Self::deposit_event(Event::Transferred(id, source.clone(), dest.clone(), credit));
+			T::OnTransfer::on_transfer(id, source, &dest, credit);
+			if let Some(((beneficiary, fee), (old, new))) = maybe_fee.zip(fee_balances.next()) {
+				Self::balance_changed(id, &beneficiary, old, new);
+				let event = Event::TransferFeePaid(id, source.clone(), beneficiary.clone(), fee);
+				Self::deposit_event(event);
+				T::OnTransfer::on_transfer(id, source, &beneficiary, fee);
+			}
+		}
+		Ok(())
+	}
+
+	/// Create an asset account for `who`, reserving `AccountDeposit` from them and giving the
+	/// account a self-sufficient reference.
+	pub(super) fn do_touch(id: T::AssetId, who: T::AccountId) -> DispatchResult {
+		ensure!(!Account::<T, I>::contains_key(id, &who), Error::<T, I>::AlreadyExists);
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status != AssetStatus::Retiring, Error::<T, I>::AssetRetired);
+			ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+			ensure!(
+				details.accounts < T::MaxAccountsPerAsset::get(),
+				Error::<T, I>::TooManyAccounts,
+			);
+			let accounts = details.accounts.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			let sufficients = details.sufficients.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+			let deposit = T::AccountDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+
+			frame_system::Pallet::<T>::inc_sufficients(&who);
+			AccountAssets::<T, I>::insert(&who, id, ());
+			details.accounts = accounts;
+			details.sufficients = sufficients;
+			Account::<T, I>::insert(id, &who, AssetBalance {
+				balance: Zero::zero(),
+				reserved: Zero::zero(),
+				is_frozen: false,
+				sufficient: true,
+				deposit: Some(deposit),
+				extra: Default::default(),
+			});
+			#[cfg(feature = "emit-account-events")]
+			Self::deposit_event(Event::AccountCreated(id, who.clone()));
+			Ok(())
+		})?;
+		Self::deposit_event(Event::Touched(id, who));
+		Ok(())
+	}
+
+	/// Remove the asset account of `who` created with `touch`, returning the deposit to them.
+	///
+	/// If `allow_death` is `true`, then any remaining balance is burned like any other burn from
+	/// the account, so it fails if the asset or account is frozen or if some of the balance is
+	/// frozen or held. Otherwise the balance must already be zero.
+	pub(super) fn do_refund(id: T::AssetId, who: T::AccountId, allow_death: bool) -> DispatchResult {
+		let account = Account::<T, I>::get(id, &who);
+		let deposit = account.deposit.ok_or(Error::<T, I>::NoDeposit)?;
+		if !account.balance.is_zero() {
+			ensure!(allow_death, Error::<T, I>::BalanceZero);
+			let f = DebitFlags { keep_alive: false, best_effort: false, respect_freezer: true };
+			// The deposit keeps the account around once its balance is burned.
+			Self::do_burn(id, &who, account.balance, None, f)?;
+		}
+
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			T::Currency::unreserve(&who, deposit);
+			Self::dead_account(id, &who, details, account.sufficient);
+			Account::<T, I>::remove(id, &who);
+			Ok(())
+		})?;
+		Self::deposit_event(Event::Refunded(id, who));
+		Ok(())
+	}
+
+	/// Transfer `amount` of asset `id` from `owner` to `destination` under the approval that
+	/// `owner` has previously given to `delegate`, reducing the approved amount accordingly.
+	///
+	/// If `keep_alive` is `true`, then the transfer fails with `WouldDie` rather than taking the
+	/// balance of `owner` below the minimum balance.
+	pub(super) fn do_transfer_approved(
+		id: T::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		destination: &T::AccountId,
+		amount: T::Balance,
+		keep_alive: bool,
+	) -> DispatchResult {
+		Approvals::<T, I>::try_mutate_exists((id, owner, delegate), |maybe_approved| -> DispatchResult {
+			let mut approved = maybe_approved.take().ok_or(Error::<T, I>::Unapproved)?;
+			ensure!(amount <= approved.amount, Error::<T, I>::Unapproved);
+			let amount = if keep_alive {
+				let amount = amount.min(Self::reducible_balance(id, owner, true)?);
+				ensure!(!amount.is_zero(), Error::<T, I>::BalanceLow);
+				amount
+			} else {
+				amount
+			};
+			let remaining = approved.amount.saturating_sub(amount);
+
+			let f = TransferFlags {
+				keep_alive,
+				best_effort: false,
+				burn_dust: false,
+				respect_freezer: true,
+			};
+			Self::do_transfer(id, owner, destination, amount, None, f)?;
+
+			if remaining.is_zero() {
+				T::Currency::unreserve(owner, approved.deposit);
+				Self::clear_approval_expiry(id, owner, delegate);
+				Self::dec_approvals(id);
+			} else {
+				approved.amount = remaining;
+				*maybe_approved = Some(approved);
+			}
+			Ok(())
+		})
+	}
+
+	/// Mark the asset `id` as being destroyed, such that it can no longer be used.
+	///
+	/// If `maybe_check_owner` is `Some`, then it must be the owner of the asset.
+	pub(super) fn do_start_destroy(
+		id: T::AssetId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
+			}
+			ensure!(details.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+			ensure!(OpenReferences::<T, I>::get(id) == 0, Error::<T, I>::InUse);
+			details.status = AssetStatus::Destroying;
+			Ok(())
+		})?;
+		Self::deposit_event(Event::DestructionStarted(id));
+		Ok(())
+	}
+
+	/// Remove up to `max_items` accounts of the asset `id`, which must be being destroyed,
+	/// returning any deposits placed with `touch`.
+	pub(super) fn do_destroy_accounts(id: T::AssetId, max_items: u32) -> DispatchResult {
+		let mut removed = 0u32;
+		let remaining = Asset::<T, I>::try_mutate(id, |maybe_details| -> Result<u32, DispatchError> {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+			ensure!(OpenReferences::<T, I>::get(id) == 0, Error::<T, I>::InUse);
+
+			let accounts = Account::<T, I>::iter_prefix(id)
+				.take(max_items as usize)
+				.collect::<Vec<_>>();
+			for (who, v) in accounts {
+				if let Some(deposit) = v.deposit {
+					T::Currency::unreserve(&who, deposit);
+				}
+				details.supply = details.supply.saturating_sub(v.balance);
+				Self::dead_account(id, &who, details, v.sufficient);
+				Account::<T, I>::remove(id, &who);
+				removed += 1;
+			}
+			Ok(details.accounts)
+		})?;
+		Self::deposit_event(Event::AccountsDestroyed(id, removed, remaining));
+		Ok(())
+	}
+
+	/// Remove up to `max_items` approvals of the asset `id`, which must be being destroyed,
+	/// returning their deposits.
+	pub(super) fn do_destroy_approvals(id: T::AssetId, max_items: u32) -> DispatchResult {
+		let mut removed = 0u32;
+		Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+
+			let approvals = Approvals::<T, I>::iter_prefix((id,))
+				.take(max_items as usize)
+				.collect::<Vec<_>>();
+			for ((owner, delegate), approval) in approvals {
+				T::Currency::unreserve(&owner, approval.deposit);
+				Approvals::<T, I>::remove((id, &owner, &delegate));
+				Self::clear_approval_expiry(id, &owner, &delegate);
+				details.approvals = details.approvals.saturating_sub(1);
+				removed += 1;
+			}
+			Ok(())
+		})?;
+		Self::deposit_event(Event::ApprovalsDestroyed(id, removed));
+		Ok(())
+	}
+
+	/// Remove all approvals of asset `id`, returning their deposits, and return the number removed.
+	pub(super) fn drain_approvals(id: T::AssetId) -> u32 {
+		let approvals = Approvals::<T, I>::iter_prefix((id,)).collect::<Vec<_>>();
+		for ((owner, delegate), approval) in approvals.iter() {
+			T::Currency::unreserve(owner, approval.deposit);
+			Self::clear_approval_expiry(id, owner, delegate);
+		}
+		Approvals::<T, I>::remove_prefix((id,));
+		approvals.len() as u32
+	}
+
+	/// Remove up to `max_items` approvals of asset `id` granted by `owner`, returning their
+	/// deposits, and return the number removed.
+	pub(super) fn do_revoke_all_approvals(
+		id: T::AssetId,
+		owner: T::AccountId,
+		max_items: u32,
+	) -> u32 {
+		let approvals = Approvals::<T, I>::iter_prefix((id, &owner))
+			.take(max_items as usize)
+			.collect::<Vec<_>>();
+		let revoked = approvals.len() as u32;
+		for (delegate, approval) in approvals {
+			T::Currency::unreserve(&owner, approval.deposit);
+			Approvals::<T, I>::remove((id, &owner, &delegate));
+			Self::clear_approval_expiry(id, &owner, &delegate);
+		}
+		Asset::<T, I>::mutate(id, |maybe_details| if let Some(details) = maybe_details {
+			details.approvals = details.approvals.saturating_sub(revoked);
+		});
+
+		Self::deposit_event(Event::ApprovalsRevoked(id, owner, revoked));
+		revoked
+	}
+
+	/// Remove the asset `id`, which must be being destroyed and have no remaining accounts or
+	/// approvals, returning its deposits to the depositor.
+	pub(super) fn do_finish_destroy(id: T::AssetId) -> DispatchResult {
+		let supply = Asset::<T, I>::try_mutate_exists(id, |maybe_details| {
+			let details = maybe_details.take().ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
+			ensure!(details.accounts == 0, Error::<T, I>::InUse);
+			ensure!(Approvals::<T, I>::iter_prefix((id,)).next().is_none(), Error::<T, I>::InUse);
+
+			let metadata = Metadata::<T, I>::take(&id);
+			T::Currency::unreserve(
+				&details.depositor,
+				details.deposit.saturating_add(metadata.deposit),
+			);
+			PendingOwner::<T, I>::remove(id);
+			TransferGuardAccount::<T, I>::remove(id);
+			TransferRateLimitConfig::<T, I>::remove(id);
+			TransferVolume::<T, I>::remove(id);
+			Ok::<_, DispatchError>(details.supply)
+		})?;
+		Self::deposit_event(Event::Destroyed(id, 0, 0, supply));
+		Ok(())
+	}
+
+	/// Make `owner` the Owner of the asset `id`, moving the deposits of the asset and its metadata
+	/// to them and clearing any pending ownership proposal.
+	pub(super) fn do_set_owner(
+		id: T::AssetId,
+		details: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		owner: T::AccountId,
+	) -> DispatchResult {
+		let metadata_deposit = Metadata::<T, I>::get(id).deposit;
+		let deposit = details.deposit + metadata_deposit;
+
+		// Move the deposit to the new owner.
+		T::Currency::repatriate_reserved(&details.depositor, &owner, deposit, Reserved)?;
+
+		details.depositor = owner.clone();
+		details.owner = owner;
+		PendingOwner::<T, I>::remove(id);
+		Ok(())
+	}
+
+	/// Move the deposits of the asset `id` and its metadata from their depositor to the Owner, if
+	/// these differ, so that the Owner may adjust them.
+	pub(super) fn take_over_deposits(
+		id: T::AssetId,
+		mut details: AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+	) -> DispatchResult {
+		if details.depositor == details.owner {
+			return Ok(())
+		}
+		let deposit = details.deposit.saturating_add(Metadata::<T, I>::get(id).deposit);
+		T::Currency::repatriate_reserved(&details.depositor, &details.owner, deposit, Reserved)?;
+		details.depositor = details.owner.clone();
+		Asset::<T, I>::insert(id, details);
+		Ok(())
+	}
+
+	/// Set whether the metadata of asset `id` is frozen, leaving all of its other fields as they
+	/// are.
+	pub(super) fn set_metadata_frozen(id: T::AssetId, is_frozen: bool) -> DispatchResult {
+		ensure!(Metadata::<T, I>::contains_key(id), Error::<T, I>::Unknown);
+		Metadata::<T, I>::mutate(id, |metadata| metadata.is_frozen = is_frozen);
+		Ok(())
+	}
+
+	/// Move the asset `old_id` with all of its accounts, approvals and metadata to `new_id`.
+	///
+	/// On success, returns the number of accounts and approvals moved.
+	pub(super) fn do_migrate_asset_id(
+		old_id: T::AssetId,
+		new_id: T::AssetId,
+	) -> Result<(u32, u32), DispatchError> {
+		ensure!(!Asset::<T, I>::contains_key(new_id), Error::<T, I>::InUse);
+		ensure!(OpenReferences::<T, I>::get(old_id) == 0, Error::<T, I>::InUse);
+		let details = Asset::<T, I>::take(old_id).ok_or(Error::<T, I>::Unknown)?;
+		Asset::<T, I>::insert(new_id, details);
+
+		let mut accounts = 0u32;
+		let moved = Account::<T, I>::drain_prefix(old_id).collect::<Vec<_>>();
+		for (who, account) in moved {
+			if AccountAssets::<T, I>::take(&who, old_id).is_some() {
+				AccountAssets::<T, I>::insert(&who, new_id, ());
+			}
+			Account::<T, I>::insert(new_id, who, account);
+			accounts = accounts.saturating_add(1);
+		}
+
+		let mut approvals = 0u32;
+		let moved = Approvals::<T, I>::iter_prefix((old_id,)).collect::<Vec<_>>();
+		Approvals::<T, I>::remove_prefix((old_id,));
+		for ((owner, delegate), approval) in moved {
+			if let Some(expiry) = ApprovalExpiresAt::<T, I>::take((old_id, &owner, &delegate)) {
+				let key = (new_id, owner.clone(), delegate.clone());
+				ApprovalExpiresAt::<T, I>::insert((new_id, &owner, &delegate), expiry);
+				ApprovalExpiryQueue::<T, I>::insert(expiry, key, ());
+			}
+			Approvals::<T, I>::insert((new_id, owner, delegate), approval);
+			approvals = approvals.saturating_add(1);
+		}
+
+		if Metadata::<T, I>::contains_key(old_id) {
+			Metadata::<T, I>::insert(new_id, Metadata::<T, I>::take(old_id));
+		}
+		if let Some(proposed_owner) = PendingOwner::<T, I>::take(old_id) {
+			PendingOwner::<T, I>::insert(new_id, proposed_owner);
+		}
+		if let Some(guard) = TransferGuardAccount::<T, I>::take(old_id) {
+			TransferGuardAccount::<T, I>::insert(new_id, guard);
+		}
+		if let Some(limit) = TransferRateLimitConfig::<T, I>::take(old_id) {
+			TransferRateLimitConfig::<T, I>::insert(new_id, limit);
+		}
+		if let Some(volume) = TransferVolume::<T, I>::take(old_id) {
+			TransferVolume::<T, I>::insert(new_id, volume);
+		}
+
+		Self::deposit_event(Event::AssetIdMigrated(old_id, new_id));
+		Ok((accounts, approvals))
+	}
+
+	/// Record a new swap `proposal`, holding its offered funds in the proposer's account.
+	pub(super) fn do_propose_swap(
+		proposal: SwapProposal<T::AssetId, T::AccountId, T::Balance, T::BlockNumber>,
+	) -> DispatchResult {
+		ensure!(!proposal.offer_amount.is_zero(), Error::<T, I>::BalanceZero);
+		ensure!(!proposal.want_amount.is_zero(), Error::<T, I>::BalanceZero);
+		let want = Asset::<T, I>::get(proposal.want_asset).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(want.status != AssetStatus::Destroying, Error::<T, I>::AssetNotLive);
+		let (id, who, amount) = (proposal.offer_asset, &proposal.proposer, proposal.offer_amount);
+
+		let swap_id = NextSwapId::<T, I>::get();
+		let next_id = swap_id.checked_add(1).ok_or(Error::<T, I>::Overflow)?;
+		Self::hold(id, who, amount)?;
+		Self::add_reference(id)?;
+		Self::add_reference(proposal.want_asset)?;
+		NextSwapId::<T, I>::put(next_id);
+		Self::deposit_event(Event::SwapProposed(
+			swap_id,
+			who.clone(),
+			id,
+			amount,
+			proposal.want_asset,
+			proposal.want_amount,
+		));
+		Swaps::<T, I>::insert(swap_id, proposal);
+		Ok(())
+	}
+
+	/// Accept the swap proposal `swap_id` on behalf of `acceptor`, making both of its transfers or
+	/// neither.
+	#[transactional]
+	pub(super) fn do_accept_swap(swap_id: SwapId, acceptor: T::AccountId) -> DispatchResult {
+		let proposal = Swaps::<T, I>::take(swap_id).ok_or(Error::<T, I>::UnknownSwap)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now <= proposal.expiry, Error::<T, I>::SwapExpired);
+		Self::release(proposal.offer_asset, &proposal.proposer, proposal.offer_amount);
+		Self::remove_reference(proposal.offer_asset);
+		Self::remove_reference(proposal.want_asset);
+
+		let f = TransferFlags {
+			keep_alive: false,
+			best_effort: false,
+			burn_dust: false,
+			respect_freezer: true,
+		};
+		Self::do_transfer(
+			proposal.want_asset,
+			&acceptor,
+			&proposal.dest,
+			proposal.want_amount,
+			None,
+			f,
+		)?;
+		Self::do_transfer(
+			proposal.offer_asset,
+			&proposal.proposer,
+			&acceptor,
+			proposal.offer_amount,
+			None,
+			f,
+		)?;
+		Self::deposit_event(Event::SwapAccepted(swap_id, acceptor));
+		Ok(())
+	}
+
+	/// Cancel the swap proposal `swap_id` on behalf of `who`, releasing its offered funds.
+	pub(super) fn do_cancel_swap(swap_id: SwapId, who: T::AccountId) -> DispatchResult {
+		let proposal = Swaps::<T, I>::get(swap_id).ok_or(Error::<T, I>::UnknownSwap)?;
+		if who != proposal.proposer {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now > proposal.expiry, Error::<T, I>::SwapNotExpired);
+		}
+
+		Swaps::<T, I>::remove(swap_id);
+		Self::release(proposal.offer_asset, &proposal.proposer, proposal.offer_amount);
+		Self::remove_reference(proposal.offer_asset);
+		Self::remove_reference(proposal.want_asset);
+		Self::deposit_event(Event::SwapCancelled(swap_id));
+		Ok(())
+	}
+
+	/// Schedule the transfer `record` for block `execute_at`, holding its amount in the source
+	/// account until then.
+	pub(super) fn do_schedule_transfer(
+		record: ScheduledTransferRecord<T::AssetId, T::AccountId, T::Balance>,
+		execute_at: T::BlockNumber,
+	) -> DispatchResult {
+		ensure!(!record.amount.is_zero(), Error::<T, I>::BalanceZero);
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(execute_at > now, Error::<T, I>::ScheduleInPast);
+		let scheduled = ScheduledTransfers::<T, I>::iter_prefix_values(execute_at).count();
+		ensure!(
+			scheduled < T::MaxScheduledPerBlock::get() as usize,
+			Error::<T, I>::TooManyScheduled,
+		);
+
+		let schedule_id = NextScheduleId::<T, I>::get();
+		let next_id = schedule_id.checked_add(1).ok_or(Error::<T, I>::Overflow)?;
+		Self::hold(record.id, &record.source, record.amount)?;
+		Self::add_reference(record.id)?;
+		NextScheduleId::<T, I>::put(next_id);
+		ScheduleLookup::<T, I>::insert(schedule_id, execute_at);
+		Self::deposit_event(Event::TransferScheduled(
+			schedule_id,
+			record.id,
+			record.source.clone(),
+			record.dest.clone(),
+			record.amount,
+			execute_at,
+		));
+		ScheduledTransfers::<T, I>::insert(execute_at, schedule_id, record);
+		Ok(())
+	}
+
+	/// Cancel the scheduled transfer `schedule_id` on behalf of `who`, releasing its amount.
+	pub(super) fn do_cancel_scheduled_transfer(
+		schedule_id: ScheduleId,
+		who: T::AccountId,
+	) -> DispatchResult {
+		let execute_at = ScheduleLookup::<T, I>::get(schedule_id)
+			.ok_or(Error::<T, I>::UnknownSchedule)?;
+		let record = ScheduledTransfers::<T, I>::get(execute_at, schedule_id)
+			.ok_or(Error::<T, I>::UnknownSchedule)?;
+		ensure!(who == record.source, Error::<T, I>::NoPermission);
+
+		ScheduleLookup::<T, I>::remove(schedule_id);
+		ScheduledTransfers::<T, I>::remove(execute_at, schedule_id);
+		Self::release(record.id, &record.source, record.amount);
+		Self::remove_reference(record.id);
+		Self::deposit_event(Event::ScheduledTransferCancelled(schedule_id));
+		Ok(())
+	}
+
+	/// Execute all transfers scheduled for block `now`, releasing the amount held for any which
+	/// fail back to their source.
+	pub(super) fn execute_scheduled_transfers(now: T::BlockNumber) -> Weight {
+		let mut executed = 0u32;
+		for (schedule_id, record) in ScheduledTransfers::<T, I>::drain_prefix(now) {
+			ScheduleLookup::<T, I>::remove(schedule_id);
+			Self::release(record.id, &record.source, record.amount);
+			Self::remove_reference(record.id);
+			let event = match Self::execute_scheduled_transfer(&record) {
+				Ok(_) => Event::ScheduledTransferExecuted(schedule_id),
+				Err(_) => Event::ScheduledTransferFailed(schedule_id),
+			};
+			Self::deposit_event(event);
+			executed = executed.saturating_add(1);
+		}
+		T::WeightInfo::execute_scheduled_transfers(executed)
+	}
+
+	/// Transfer asset `id` from `source` to each of `targets` as `admin`, skipping any transfers
+	/// which fail. Freezes on `source` or on the asset class do not prevent the transfers.
+	pub(super) fn do_force_batch_transfer(
+		id: T::AssetId,
+		admin: T::AccountId,
+		source: &T::AccountId,
+		targets: Vec<(T::AccountId, T::Balance)>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.admin == admin, Error::<T, I>::NoPermission);
+
+		for (dest, amount) in targets {
+			if Self::force_transfer_one(id, &admin, source, &dest, amount).is_err() {
+				let event = Event::BatchTransferFailed(id, source.clone(), dest, amount);
+				Self::deposit_event(event);
+			}
+		}
+		Ok(())
+	}
+
+	/// Make a single transfer of a `force_batch_transfer`, leaving no changes if it fails.
+	#[transactional]
+	fn force_transfer_one(
+		id: T::AssetId,
+		admin: &T::AccountId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		let f = TransferFlags {
+			keep_alive: false,
+			best_effort: false,
+			burn_dust: false,
+			respect_freezer: false,
+		};
+		Self::do_transfer(id, source, dest, amount, Some(admin.clone()), f)?;
+		Ok(())
+	}
+
+	#[transactional]
+	fn execute_scheduled_transfer(
+		record: &ScheduledTransferRecord<T::AssetId, T::AccountId, T::Balance>,
+	) -> DispatchResult {
+		let f = TransferFlags {
+			keep_alive: false,
+			best_effort: false,
+			burn_dust: false,
+			respect_freezer: true,
+		};
+		Self::do_transfer(record.id, &record.source, &record.dest, record.amount, None, f)?;
+		Ok(())
+	}
+
+	/// Check that the counters kept in the details of every asset agree with its accounts and
+	/// approvals in storage, and that only accounts created with `touch` are stored empty.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn check_invariants() -> Result<(), &'static str> {
+		for (id, details) in Asset::<T, I>::iter() {
+			let mut accounts = 0u32;
+			let mut sufficients = 0u32;
+			let mut supply = T::Balance::zero();
+			for (_, account) in Account::<T, I>::iter_prefix(id) {
+				// Only accounts created with `touch` may be kept with no balance; any other would
+				// never be passed to `dead_account`, leaving the counters permanently too high.
+				ensure!(
+					!account.balance.is_zero() || account.deposit.is_some(),
+					"Asset has a stored account with neither a balance nor a deposit",
+				);
+				accounts = accounts.saturating_add(1);
+				if account.sufficient {
+					sufficients = sufficients.saturating_add(1);
+				}
+				supply = supply.checked_add(&account.balance).ok_or("Asset supply overflows")?;
+			}
+			let approvals = Approvals::<T, I>::iter_prefix((id,)).count() as u32;
+
+			ensure!(details.accounts == accounts, "Asset accounts do not match the stored accounts");
+			ensure!(
+				details.sufficients == sufficients,
+				"Asset sufficients do not match the stored sufficient accounts",
+			);
+			ensure!(details.approvals == approvals, "Asset approvals do not match the stored approvals");
+			ensure!(details.supply == supply, "Asset supply does not match the stored balances");
+		}
+		Ok(())
+	}
 }
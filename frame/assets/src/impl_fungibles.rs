@@ -18,6 +18,7 @@
 //! Implementations for fungibles trait.
 
 use super::*;
+use frame_support::storage::PrefixIterator;
 
 impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId> for Pallet<T, I> {
 	type AssetId = T::AssetId;
@@ -62,6 +63,14 @@ impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId
 	) -> WithdrawConsequence<Self::Balance> {
 		Pallet::<T, I>::can_decrease(asset, who, amount, false)
 	}
+
+	fn asset_exists(asset: Self::AssetId) -> bool {
+		Asset::<T, I>::contains_key(asset)
+	}
+
+	fn is_account_frozen(asset: Self::AssetId, who: &<T as SystemConfig>::AccountId) -> bool {
+		Pallet::<T, I>::frozen_kind(asset, who).is_some()
+	}
 }
 
 impl<T: Config<I>, I: 'static> fungibles::Mutate<<T as SystemConfig>::AccountId> for Pallet<T, I> {
@@ -81,6 +90,7 @@ impl<T: Config<I>, I: 'static> fungibles::Mutate<<T as SystemConfig>::AccountId>
 		let f = DebitFlags {
 			keep_alive: false,
 			best_effort: false,
+			respect_freezer: true,
 		};
 		Self::do_burn(asset, who, amount, None, f)
 	}
@@ -93,6 +103,7 @@ impl<T: Config<I>, I: 'static> fungibles::Mutate<<T as SystemConfig>::AccountId>
 		let f = DebitFlags {
 			keep_alive: false,
 			best_effort: true,
+			respect_freezer: true,
 		};
 		Self::do_burn(asset, who, amount, None, f)
 	}
@@ -109,12 +120,66 @@ impl<T: Config<I>, I: 'static> fungibles::Transfer<T::AccountId> for Pallet<T, I
 		let f = TransferFlags {
 			keep_alive,
 			best_effort: false,
-			burn_dust: false
+			burn_dust: false,
+			respect_freezer: true,
 		};
 		Self::do_transfer(asset, source, dest, amount, None, f)
 	}
 }
 
+impl<T: Config<I>, I: 'static> fungibles::InspectHold<T::AccountId> for Pallet<T, I> {
+	fn balance_on_hold(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		Account::<T, I>::get(asset, who).reserved
+	}
+
+	fn can_hold(asset: Self::AssetId, who: &T::AccountId, amount: Self::Balance) -> bool {
+		Pallet::<T, I>::reducible_balance(asset, who, true).map_or(false, |free| amount <= free)
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::MutateHold<T::AccountId> for Pallet<T, I> {
+	fn hold(asset: Self::AssetId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		Pallet::<T, I>::hold(asset, who, amount)
+	}
+
+	fn release(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		best_effort: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		let held = Account::<T, I>::get(asset, who).reserved;
+		let actual = if best_effort { amount.min(held) } else { amount };
+		ensure!(actual <= held, Error::<T, I>::BalanceLow);
+		Pallet::<T, I>::release(asset, who, actual);
+		Ok(actual)
+	}
+
+	#[transactional]
+	fn transfer_held(
+		asset: Self::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+		best_effort: bool,
+		on_hold: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		let actual =
+			<Self as fungibles::MutateHold<_>>::release(asset, source, amount, best_effort)?;
+		let f = TransferFlags {
+			keep_alive: false,
+			best_effort: false,
+			burn_dust: false,
+			respect_freezer: true,
+		};
+		let transferred = Self::do_transfer(asset, source, dest, actual, None, f)?;
+		if on_hold {
+			Pallet::<T, I>::hold(asset, dest, transferred)?;
+		}
+		Ok(transferred)
+	}
+}
+
 impl<T: Config<I>, I: 'static> fungibles::Unbalanced<T::AccountId> for Pallet<T, I> {
 	fn set_balance(_: Self::AssetId, _: &T::AccountId, _: Self::Balance) -> DispatchResult {
 		unreachable!("set_balance is not used if other functions are impl'd");
@@ -129,13 +194,13 @@ impl<T: Config<I>, I: 'static> fungibles::Unbalanced<T::AccountId> for Pallet<T,
 	fn decrease_balance(asset: T::AssetId, who: &T::AccountId, amount: Self::Balance)
 						-> Result<Self::Balance, DispatchError>
 	{
-		let f = DebitFlags { keep_alive: false, best_effort: false };
+		let f = DebitFlags { keep_alive: false, best_effort: false, respect_freezer: true };
 		Self::decrease_balance(asset, who, amount, f, |_, _| Ok(()))
 	}
 	fn decrease_balance_at_most(asset: T::AssetId, who: &T::AccountId, amount: Self::Balance)
 								-> Self::Balance
 	{
-		let f = DebitFlags { keep_alive: false, best_effort: true };
+		let f = DebitFlags { keep_alive: false, best_effort: true, respect_freezer: true };
 		Self::decrease_balance(asset, who, amount, f, |_, _| Ok(()))
 			.unwrap_or(Zero::zero())
 	}
@@ -148,9 +213,66 @@ impl<T: Config<I>, I: 'static> fungibles::Unbalanced<T::AccountId> for Pallet<T,
 	fn increase_balance_at_most(asset: T::AssetId, who: &T::AccountId, amount: Self::Balance)
 								-> Self::Balance
 	{
+		<Self as fungibles::Unbalanced<_>>::increase_balance_best_effort(asset, who, amount).0
+	}
+	/// The account is credited with either all of `amount` or none of it. The only failure found
+	/// while crediting, after `can_increase` succeeded, is a new account which cannot be created,
+	/// such as of a retiring asset, which is reported as `DepositConsequence::CannotCreate`.
+	fn increase_balance_best_effort(asset: T::AssetId, who: &T::AccountId, amount: Self::Balance)
+									-> (Self::Balance, DepositConsequence)
+	{
+		if amount.is_zero() {
+			return (amount, DepositConsequence::Success)
+		}
+		match Self::can_increase(asset, who, amount) {
+			DepositConsequence::Success => (),
+			conseq => return (Zero::zero(), conseq),
+		}
 		match Self::increase_balance(asset, who, amount, |_| Ok(())) {
-			Ok(()) => amount,
-			Err(_) => Zero::zero(),
+			Ok(()) => (amount, DepositConsequence::Success),
+			Err(DispatchError::Arithmetic(_)) => (Zero::zero(), DepositConsequence::Overflow),
+			Err(e) if e == Error::<T, I>::TooManyAccounts.into() =>
+				(Zero::zero(), DepositConsequence::Overflow),
+			Err(_) => (Zero::zero(), DepositConsequence::CannotCreate),
 		}
 	}
 }
+
+impl<T: Config<I>, I: 'static> fungibles::InspectEnumerable<T::AccountId> for Pallet<T, I> {
+	type AssetsIterator = sp_std::iter::Map<
+		PrefixIterator<(T::AssetId, AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>)>,
+		fn((T::AssetId, AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>)) -> T::AssetId,
+	>;
+	type AccountBalancesIterator = sp_std::iter::Map<
+		PrefixIterator<(T::AccountId, AssetBalance<T::Balance, DepositBalanceOf<T, I>, T::Extra>)>,
+		fn((T::AccountId, AssetBalance<T::Balance, DepositBalanceOf<T, I>, T::Extra>))
+			-> (T::AccountId, T::Balance),
+	>;
+
+	fn asset_ids() -> Self::AssetsIterator {
+		Asset::<T, I>::iter().map((|(id, _)| id) as fn(_) -> _)
+	}
+
+	fn account_balances(asset: T::AssetId) -> Self::AccountBalancesIterator {
+		Account::<T, I>::iter_prefix(asset)
+			.map((|(who, account): (_, AssetBalance<_, _, _>)| (who, account.balance)) as fn(_) -> _)
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::Roles<T::AccountId> for Pallet<T, I> {
+	fn owner(asset: T::AssetId) -> Option<T::AccountId> {
+		Asset::<T, I>::get(asset).map(|x| x.owner)
+	}
+
+	fn issuer(asset: T::AssetId) -> Option<T::AccountId> {
+		Asset::<T, I>::get(asset).map(|x| x.issuer)
+	}
+
+	fn admin(asset: T::AssetId) -> Option<T::AccountId> {
+		Asset::<T, I>::get(asset).map(|x| x.admin)
+	}
+
+	fn freezer(asset: T::AssetId) -> Option<T::AccountId> {
+		Asset::<T, I>::get(asset).map(|x| x.freezer)
+	}
+}
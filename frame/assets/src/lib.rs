@@ -77,32 +77,118 @@
 //! ### Permissionless Functions
 //!
 //! * `create`: Creates a new asset class, taking the required deposit.
+//! * `create_auto`: Creates a new asset class under the next free identifier, taking the required
+//!   deposit.
+//! * `create_with_metadata`: Creates a new asset class together with its metadata, taking the
+//!   required deposits.
 //! * `transfer`: Transfer sender's assets to another account.
 //! * `transfer_keep_alive`: Transfer sender's assets to another account, keeping the sender alive.
+//! * `transfer_with_memo`: Transfer sender's assets to another account together with a memo for
+//!   the recipient.
+//! * `transfer_best_effort`: Transfer as much as possible, up to some amount, of sender's assets
+//!   to another account.
+//! * `transfer_to_many`: Transfer sender's assets to several other accounts at once.
+//! * `multi_asset_transfer`: Make several transfers of possibly different assets atomically, from
+//!   the sender or from accounts which have approved it.
 //! * `set_metadata`: Set the metadata of an asset class.
+//! * `set_metadata_extended`: Set the metadata of an asset class, including an image hash.
+//! * `set_metadata_url`: Set or clear the URI of richer off-chain metadata of an asset class.
 //! * `clear_metadata`: Remove the metadata of an asset class.
 //! * `approve_transfer`: Create or increase an delegated transfer.
+//! * `approve_transfer_exact`: Set the amount third-party may transfer, replacing any previous
+//!   approval.
+//! * `revoke_all_approvals`: Rescind all of the sender's approvals of an asset, a batch at a
+//!   time.
 //! * `cancel_approval`: Rescind a previous approval.
 //! * `transfer_approved`: Transfer third-party's assets to another account.
+//! * `transfer_approved_keep_alive`: Transfer third-party's assets to another account, keeping the
+//!   third-party account alive.
+//! * `touch`: Create an asset account for the sender, taking a deposit.
+//! * `refund`: Remove the sender's asset account, returning the deposit.
+//! * `propose_swap`: Offer some of one asset for some of another, holding the offered amount.
+//! * `accept_swap`: Atomically make both transfers of a swap proposal.
+//! * `cancel_swap`: Cancel a swap proposal, releasing the offered amount.
+//! * `schedule_transfer`: Transfer sender's assets to another account in a future block, holding
+//!   the amount until then.
+//! * `cancel_scheduled_transfer`: Cancel a scheduled transfer, releasing the held amount.
+//! * `destroy_accounts`: Remove accounts of an asset class being destroyed.
+//! * `destroy_approvals`: Remove approvals of an asset class being destroyed.
+//! * `finish_destroy`: Remove an asset class being destroyed once it has no accounts or approvals.
 //!
 //! ### Permissioned Functions
 //!
 //! * `force_create`: Creates a new asset class without taking any deposit.
+//! * `force_create_with_metadata`: Creates a new asset class together with its metadata without
+//!   taking any deposit.
 //! * `force_set_metadata`: Set the metadata of an asset class.
 //! * `force_clear_metadata`: Remove the metadata of an asset class.
+//! * `thaw_metadata`: Allow the metadata of an asset class to be changed by its Owner again.
 //! * `force_asset_status`: Alter an asset class's attributes.
+//! * `force_set_supply_cap`: Change the maximum supply of an asset class.
+//! * `force_transfer_ownership`: Change the Owner of an asset class without moving its deposits.
+//! * `force_set_owner_deposit`: Correct the deposit recorded for an asset class without reserving
+//!   or unreserving anything.
+//! * `transfer_ownership_by_operator`: Change the Owner of an asset class as authorised by an
+//!   off-chain signature of its Owner.
+//! * `migrate_asset_id`: Move an asset class and all of its state to a new identifier.
 //! * `force_cancel_approval`: Rescind a previous approval.
 //!
 //! ### Privileged Functions
 //! * `destroy`: Destroys an entire asset class; called by the asset class's Owner.
+//! * `start_destroy`: Starts the destruction of an asset class over several blocks; called by the
+//!   asset class's Owner.
 //! * `mint`: Increases the asset balance of an account; called by the asset class's Issuer.
+//! * `mint_to_many`: Increases the asset balance of several accounts at once; called by the
+//!   asset class's Issuer.
+//! * `pause_minting`: Suspends minting of an asset class without affecting transfers; called by
+//!   the asset class's Issuer.
+//! * `resume_minting`: Allows minting of an asset class again; called by the asset class's Issuer.
+//! * `retire_asset`: Winds down an asset class, allowing only transfers and burns of existing
+//!   balances; called by the asset class's Owner.
+//! * `unretire_asset`: Makes a retiring asset class live again; called by the asset class's Owner.
+//! * `set_asset_sufficient`: Stops new accounts of an asset class being self-sufficient; called
+//!   by the asset class's Owner.
 //! * `burn`: Decreases the asset balance of an account; called by the asset class's Admin.
+//! * `soft_burn`: Decreases the asset balance of an account without taking it below the minimum
+//!   balance; called by the asset class's Admin.
+//! * `burn_excess_above_cap`: Burns the supply of an asset class above its maximum supply from
+//!   the sender's account; called by the asset class's Admin.
 //! * `force_transfer`: Transfers between arbitrary accounts; called by the asset class's Admin.
+//! * `force_batch_transfer`: Transfers from an arbitrary account to several others, skipping any
+//!   which fail; called by the asset class's Admin.
 //! * `freeze`: Disallows further `transfer`s from an account; called by the asset class's Freezer.
 //! * `thaw`: Allows further `transfer`s from an account; called by the asset class's Admin.
+//! * `bulk_freeze_accounts`: Prevents transfers from several accounts at once; called by the
+//!   asset class's Freezer.
+//! * `bulk_thaw_accounts`: Allows further transfers from several accounts at once; called by the
+//!   asset class's Admin.
+//! * `freeze_asset_with_reason`: Disallows further `transfer`s of an asset class, noting why;
+//!   called by the asset class's Freezer.
+//! * `thaw_asset_with_reason`: Allows further `transfer`s of an asset class, noting why; called by
+//!   the asset class's Admin.
 //! * `transfer_ownership`: Changes an asset class's Owner; called by the asset class's Owner.
+//! * `propose_new_owner`: Proposes a new Owner for an asset class, to take effect once accepted;
+//!   called by the asset class's Owner.
+//! * `accept_ownership`: Becomes an asset class's Owner; called by its proposed Owner.
+//! * `cancel_proposed_owner`: Rescinds the proposal of a new Owner; called by the asset class's
+//!   Owner.
+//! * `renounce_ownership`: Permanently gives up an asset class's Owner role, returning its
+//!   deposits; called by the asset class's Owner.
+//! * `freeze_metadata`: Prevents further changes to an asset class's metadata other than by the
+//!   force origin; called by the asset class's Owner or the force origin.
 //! * `set_team`: Changes an asset class's Admin, Freezer and Issuer; called by the asset class's
 //!   Owner.
+//! * `set_issuer`, `set_admin`, `set_freezer`: Change one of an asset class's Issuer, Admin or
+//!   Freezer, leaving the others unchanged; called by the asset class's Owner.
+//! * `set_supply_cap`: Changes an asset class's maximum supply; called by the asset class's Owner.
+//! * `set_min_balance`: Changes an asset class's minimum balance; called by the asset class's
+//!   Owner.
+//! * `set_transfer_fee`: Changes the proportion of each transfer an asset class takes as a fee;
+//!   called by the asset class's Owner.
+//! * `set_transfer_rate_limit`: Changes the volume of an asset class which may be transferred
+//!   within a window of blocks; called by the asset class's Owner.
+//! * `set_transfer_guard`: Changes the account consulted by the runtime's transfer guard for an
+//!   asset class; called by the asset class's Owner.
 //!
 //! Please refer to the [`Call`](./enum.Call.html) enum and its associated variants for documentation on each function.
 //!
@@ -111,6 +197,15 @@
 //!
 //! * `balance` - Get the asset `id` balance of `who`.
 //! * `total_supply` - Get the total supply of an asset `id`.
+//! * `asset_details` - Get the details of an asset `id`, such as its owner and team.
+//! * `metadata` - Get the metadata of an asset `id`, if any is set.
+//! * `approval_allowance` - Get the amount of asset `id` a delegate may still transfer for an owner.
+//! * `can_increase` - Get the consequence of depositing some amount of asset `id` into an account.
+//! * `can_decrease` - Get the consequence of withdrawing some amount of asset `id` from an account.
+//! * `frozen_kind` - Get whether withdrawals of asset `id` from an account are suspended because
+//!   the account or the whole asset class is frozen.
+//! * `can_transfer` - Check whether some amount of asset `id` could be transferred between
+//!   accounts.
 //!
 //! Please refer to the [`Module`](./struct.Module.html) struct for details on publicly available functions.
 //!
@@ -121,6 +216,7 @@
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "runtime-benchmarks", recursion_limit = "256")]
 
 pub mod weights;
 #[cfg(feature = "runtime-benchmarks")]
@@ -135,25 +231,33 @@ pub use extra_mutator::*;
 mod impl_stored_map;
 mod impl_fungibles;
 mod functions;
+pub mod migration;
 mod types;
 pub use types::*;
 
-use sp_std::{prelude::*, borrow::Borrow};
+use sp_std::{prelude::*, borrow::Borrow, convert::TryInto};
 use sp_runtime::{
-	RuntimeDebug, TokenError, ArithmeticError, traits::{
-		AtLeast32BitUnsigned, Zero, StaticLookup, Saturating, CheckedSub, CheckedAdd, Bounded,
-		StoredMapError,
+	RuntimeDebug, TokenError, ArithmeticError, Perbill, traits::{
+		AtLeast32BitUnsigned, Zero, One, StaticLookup, Saturating, CheckedSub, CheckedAdd, Bounded,
+		StoredMapError, Verify, IdentifyAccount,
 	}
 };
 use codec::{Encode, Decode, HasCompact};
-use frame_support::{ensure, dispatch::{DispatchError, DispatchResult}};
-use frame_support::traits::{Currency, ReservableCurrency, BalanceStatus::Reserved, StoredMap};
+use frame_support::{ensure, transactional, dispatch::{DispatchError, DispatchResult}, BoundedVec};
+use frame_support::weights::Weight;
+use frame_support::traits::{Currency, ReservableCurrency, BalanceStatus::Reserved, StoredMap, Get};
 use frame_support::traits::tokens::{WithdrawConsequence, DepositConsequence, fungibles};
 use frame_system::Config as SystemConfig;
 
 pub use weights::WeightInfo;
 pub use pallet::*;
 
+/// The tag with which the payload signed for `transfer_ownership_by_operator` begins.
+pub const TRANSFER_OWNERSHIP_TAG: &[u8] = b"assets:transfer_ownership";
+
+/// The number of identifiers from `NextAssetId` onwards which `create_auto` tries.
+pub const MAX_ASSET_ID_PROBES: u32 = 16;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
@@ -174,10 +278,16 @@ pub mod pallet {
 		type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
 
 		/// The units in which we record balances.
-		type Balance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy;
+		type Balance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy + MaybeSerializeDeserialize;
 
 		/// Identifier for the class of asset.
-		type AssetId: Member + Parameter + Default + Copy + HasCompact;
+		type AssetId: Member
+			+ Parameter
+			+ Default
+			+ Copy
+			+ HasCompact
+			+ MaybeSerializeDeserialize
+			+ Incrementable;
 
 		/// The currency mechanism.
 		type Currency: ReservableCurrency<Self::AccountId>;
@@ -199,16 +309,78 @@ pub mod pallet {
 		/// The amount of funds that must be reserved when creating a new approval.
 		type ApprovalDeposit: Get<DepositBalanceOf<Self, I>>;
 
+		/// The amount of funds that must be reserved when explicitly creating an asset account
+		/// with `touch`.
+		type AccountDeposit: Get<DepositBalanceOf<Self, I>>;
+
 		/// The maximum length of a name or symbol stored on-chain.
 		type StringLimit: Get<u32>;
 
+		/// The maximum length of a metadata URI stored on-chain.
+		type UriLimit: Get<u32>;
+
+		/// The maximum length of the memo of a `transfer_with_memo`.
+		type MemoLimit: Get<u32>;
+
+		/// Which memos are accepted by `transfer_with_memo`.
+		type MemoEncoding: Get<MemoEncoding>;
+
+		/// The maximum number of destinations of a single `transfer_to_many`.
+		type MaxFanOut: Get<u32>;
+
+		/// The maximum number of beneficiaries of a single `mint_to_many`.
+		type MaxMintTargets: Get<u32>;
+
+		/// The maximum number of transfers made together by a single `multi_asset_transfer`.
+		type MaxMultiTransfer: Get<u32>;
+
+		/// The maximum number of accounts frozen or thawed by a single `bulk_freeze_accounts` or
+		/// `bulk_thaw_accounts`.
+		type MaxFreezeBatch: Get<u32>;
+
+		/// The maximum number of approvals cancelled by a single `revoke_all_approvals`.
+		type MaxRevocationBatch: Get<u32>;
+
+		/// The maximum number of transfers which may be scheduled for execution in a single block.
+		type MaxScheduledPerBlock: Get<u32>;
+
+		/// The maximum number of accounts which may hold a balance of any single asset.
+		type MaxAccountsPerAsset: Get<u32>;
+
+		/// The maximum number of approvals which may exist for any single asset.
+		type MaxApprovalsPerAsset: Get<u32>;
+
+		/// The number of blocks after which an approval expires, if any. Expired approvals are
+		/// removed in `on_idle` and their deposits returned. Each new approval, or top-up of an
+		/// existing one, restarts the period.
+		type ApprovalExpiry: Get<Option<Self::BlockNumber>>;
+
 		/// A hook to allow a per-asset, per-account minimum balance to be enforced. This must be
 		/// respected in all permissionless operations.
 		type Freezer: FrozenBalance<Self::AssetId, Self::AccountId, Self::Balance>;
 
+		/// A hook called after every successful transfer, mint and burn of an asset.
+		///
+		/// Its cost is only covered by the weights of these calls once they are benchmarked with
+		/// the runtime's own implementation.
+		type OnTransfer: OnTransfer<Self::AssetId, Self::AccountId, Self::Balance>;
+
+		/// A hook which must allow every transfer of an asset other than those forced by its
+		/// Admin.
+		///
+		/// Its cost is only covered by the weights of the transfers once they are benchmarked with
+		/// the runtime's own implementation.
+		type TransferGuard: AssetTransferGuard<Self::AssetId, Self::AccountId, Self::Balance>;
+
 		/// Additional data to be stored with an account's asset balance.
 		type Extra: Member + Parameter + Default;
 
+		/// The signature with which an Owner authorises `transfer_ownership_by_operator`.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// The public key from which the Owner's account is derived for `OffchainSignature`.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -222,6 +394,52 @@ pub mod pallet {
 		AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
 	>;
 
+	#[pallet::storage]
+	/// The identifier tried first for the next asset created with `create_auto`.
+	pub(super) type NextAssetId<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		T::AssetId,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The account proposed by the Owner of an asset to become its new Owner, if any.
+	pub(super) type PendingOwner<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		T::AccountId,
+	>;
+
+	#[pallet::storage]
+	/// The account set by the Owner of an asset for its `TransferGuard` to consult, if any.
+	pub(super) type TransferGuardAccount<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		T::AccountId,
+	>;
+
+	#[pallet::storage]
+	/// The transfer rate limit of an asset, if any: the maximum volume which may be transferred
+	/// within a window of a number of blocks.
+	pub(super) type TransferRateLimitConfig<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		(T::Balance, T::BlockNumber),
+	>;
+
+	#[pallet::storage]
+	/// The block at which the current transfer rate limit window of an asset started, together
+	/// with the volume of it transferred since.
+	pub(super) type TransferVolume<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		(T::BlockNumber, T::Balance),
+	>;
+
 	#[pallet::storage]
 	/// The number of units of assets held by any given account.
 	pub(super) type Account<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -230,7 +448,7 @@ pub mod pallet {
 		T::AssetId,
 		Blake2_128Concat,
 		T::AccountId,
-		AssetBalance<T::Balance, T::Extra>,
+		AssetBalance<T::Balance, DepositBalanceOf<T, I>, T::Extra>,
 		ValueQuery,
 	>;
 
@@ -249,22 +467,197 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// The block in which an approval expires, keyed as `Approvals`. An entry may outlive its
+	/// approval; it is removed once its expiry is swept.
+	pub(super) type ApprovalExpiresAt<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AssetId>,
+			NMapKey<Blake2_128Concat, T::AccountId>, // owner
+			NMapKey<Blake2_128Concat, T::AccountId>, // delegate
+		),
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Approvals to be checked for expiry, keyed by the block in which they expire. Entries for
+	/// approvals which have since been cancelled or renewed are skipped.
+	pub(super) type ApprovalExpiryQueue<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Blake2_128Concat,
+		(T::AssetId, T::AccountId, T::AccountId),
+		(),
+	>;
+
+	#[pallet::storage]
+	/// The earliest block of `ApprovalExpiryQueue` which has not yet been fully swept, if any
+	/// approval expiry was ever queued.
+	pub(super) type ApprovalExpiryCursor<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	/// Metadata of an asset.
 	pub(super) type Metadata<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
 		Blake2_128Concat,
 		T::AssetId,
-		AssetMetadata<DepositBalanceOf<T, I>>,
+		MetadataOf<T, I>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The assets of which an account holds a balance.
+	pub(super) type AccountAssets<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AssetId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The identifier of the next swap proposal.
+	pub(super) type NextSwapId<T: Config<I>, I: 'static = ()> = StorageValue<_, SwapId, ValueQuery>;
+
+	#[pallet::storage]
+	/// Open swap proposals.
+	pub(super) type Swaps<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		SwapId,
+		SwapProposal<T::AssetId, T::AccountId, T::Balance, T::BlockNumber>,
+	>;
+
+	#[pallet::storage]
+	/// The identifier of the next scheduled transfer.
+	pub(super) type NextScheduleId<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		ScheduleId,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Pending scheduled transfers, keyed by the block in which they are to be executed.
+	pub(super) type ScheduledTransfers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Twox64Concat,
+		ScheduleId,
+		ScheduledTransferRecord<T::AssetId, T::AccountId, T::Balance>,
+	>;
+
+	#[pallet::storage]
+	/// The block in which a pending scheduled transfer is to be executed.
+	pub(super) type ScheduleLookup<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		ScheduleId,
+		T::BlockNumber,
+	>;
+
+	#[pallet::storage]
+	/// The number of open swap proposals and scheduled transfers which refer to each asset, which
+	/// may not be destroyed or migrated while there are any.
+	pub(super) type OpenReferences<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Storage version of the pallet.
+	///
+	/// This is set to `V2` for new networks.
+	pub(super) type StorageVersion<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		Releases,
 		ValueQuery,
 	>;
 
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		/// Genesis assets: id, owner, is_sufficient, min_balance
+		pub assets: Vec<(T::AssetId, T::AccountId, bool, T::Balance)>,
+		/// Genesis metadata: id, name, symbol, decimals
+		pub metadata: Vec<(T::AssetId, Vec<u8>, Vec<u8>, u8)>,
+		/// Genesis accounts: id, account_id, balance
+		pub accounts: Vec<(T::AssetId, T::AccountId, T::Balance)>,
+		/// Genesis next asset id: the identifier tried first by `create_auto`
+		pub next_asset_id: T::AssetId,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
+		fn default() -> Self {
+			Self {
+				assets: Default::default(),
+				metadata: Default::default(),
+				accounts: Default::default(),
+				next_asset_id: Default::default(),
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
+		fn build(&self) {
+			<StorageVersion<T, I>>::put(Releases::V2);
+			NextAssetId::<T, I>::put(self.next_asset_id);
+
+			for (id, owner, is_sufficient, min_balance) in &self.assets {
+				assert!(!Asset::<T, I>::contains_key(id), "Asset id already in use");
+				let details = AssetDetailsBuilder::new(owner.clone())
+					.min_balance(*min_balance)
+					.is_sufficient(*is_sufficient)
+					.build();
+				Asset::<T, I>::insert(id, details);
+			}
+
+			for (id, name, symbol, decimals) in &self.metadata {
+				assert!(Asset::<T, I>::contains_key(id), "Asset does not exist");
+
+				let bounded_name: BoundedVec<u8, T::StringLimit> = name.clone().try_into()
+					.expect("asset name is too long");
+				let bounded_symbol: BoundedVec<u8, T::StringLimit> = symbol.clone().try_into()
+					.expect("asset symbol is too long");
+
+				Metadata::<T, I>::insert(id, AssetMetadata {
+					deposit: Zero::zero(),
+					name: bounded_name,
+					symbol: bounded_symbol,
+					decimals: *decimals,
+					is_frozen: false,
+					image_hash: None,
+					uri: None,
+				});
+			}
+
+			for (id, account_id, amount) in &self.accounts {
+				let result = Pallet::<T, I>::do_mint(*id, account_id, *amount, None);
+				assert!(result.is_ok(), "Asset account could not be funded");
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	#[pallet::metadata(
 		T::AccountId = "AccountId",
 		T::Balance = "Balance",
-		T::AssetId = "AssetId"
+		T::AssetId = "AssetId",
+		T::BlockNumber = "BlockNumber"
 	)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// Some asset class was created. \[asset_id, creator, owner\]
@@ -283,18 +676,30 @@ pub mod pallet {
 		Frozen(T::AssetId, T::AccountId),
 		/// Some account `who` was thawed. \[asset_id, who\]
 		Thawed(T::AssetId, T::AccountId),
-		/// Some asset `asset_id` was frozen. \[asset_id\]
-		AssetFrozen(T::AssetId),
-		/// Some asset `asset_id` was thawed. \[asset_id\]
-		AssetThawed(T::AssetId),
-		/// An asset class was destroyed.
-		Destroyed(T::AssetId),
+		/// Some asset `asset_id` was frozen, for the given reason if any. \[asset_id, reason\]
+		AssetFrozen(T::AssetId, Option<Vec<u8>>),
+		/// Some asset `asset_id` was thawed, for the given reason if any. \[asset_id, reason\]
+		AssetThawed(T::AssetId, Option<Vec<u8>>),
+		/// An asset class was destroyed, together with the given number of accounts and approvals
+		/// and the supply held by those accounts. These are zero for `finish_destroy`, since
+		/// `destroy_accounts` and `destroy_approvals` already removed them.
+		/// \[asset_id, accounts, approvals, supply\]
+		Destroyed(T::AssetId, u32, u32, T::Balance),
+		/// The destruction of an asset class was started. \[asset_id\]
+		DestructionStarted(T::AssetId),
+		/// Accounts of an asset class being destroyed were removed.
+		/// \[asset_id, accounts_destroyed, accounts_remaining\]
+		AccountsDestroyed(T::AssetId, u32, u32),
+		/// Approvals of an asset class being destroyed were removed.
+		/// \[asset_id, approvals_destroyed\]
+		ApprovalsDestroyed(T::AssetId, u32),
 		/// Some asset class was force-created. \[asset_id, owner\]
 		ForceCreated(T::AssetId, T::AccountId),
-		/// New metadata has been set for an asset. \[asset_id, name, symbol, decimals, is_frozen\]
-		MetadataSet(T::AssetId, Vec<u8>, Vec<u8>, u8, bool),
-		/// Metadata has been cleared for an asset. \[asset_id\]
-		MetadataCleared(T::AssetId),
+		/// New metadata has been set for an asset.
+		/// \[asset_id, name, symbol, decimals, is_frozen, image_hash, uri\]
+		MetadataSet(T::AssetId, Vec<u8>, Vec<u8>, u8, bool, Option<[u8; 32]>, Option<Vec<u8>>),
+		/// Metadata has been cleared for an asset. \[asset_id, name\]
+		MetadataCleared(T::AssetId, Vec<u8>),
 		/// (Additional) funds have been approved for transfer to a destination account.
 		/// \[asset_id, source, delegate, amount\]
 		ApprovedTransfer(T::AssetId, T::AccountId, T::AccountId, T::Balance),
@@ -308,6 +713,100 @@ pub mod pallet {
 		/// An asset has had its attributes changed by the `Force` origin.
 		/// \[id\]
 		AssetStatusChanged(T::AssetId),
+		/// The maximum supply of an asset has been changed. \[asset_id, max_supply\]
+		CapChanged(T::AssetId, Option<T::Balance>),
+		/// The minimum balance of an asset has been changed.
+		/// \[asset_id, old_min_balance, new_min_balance\]
+		MinBalanceChanged(T::AssetId, T::Balance, T::Balance),
+		/// The transfer fee of an asset has been changed. A zero rate means no fee.
+		/// \[asset_id, rate, beneficiary\]
+		TransferFeeChanged(T::AssetId, Perbill, T::AccountId),
+		/// A transfer fee was paid by `from` to the fee beneficiary `to`.
+		/// \[asset_id, from, to, amount\]
+		TransferFeePaid(T::AssetId, T::AccountId, T::AccountId, T::Balance),
+		/// An asset account was explicitly created with a deposit. \[asset_id, who\]
+		Touched(T::AssetId, T::AccountId),
+		/// An asset account was removed and its deposit returned. \[asset_id, who\]
+		Refunded(T::AssetId, T::AccountId),
+		/// An asset account was created. \[asset_id, who\]
+		#[cfg(feature = "emit-account-events")]
+		AccountCreated(T::AssetId, T::AccountId),
+		/// An asset account was removed. \[asset_id, who\]
+		#[cfg(feature = "emit-account-events")]
+		AccountDied(T::AssetId, T::AccountId),
+		/// The balance of an asset account has changed.
+		/// \[asset_id, who, old_balance, new_balance\]
+		#[cfg(feature = "emit-balance-changed-events")]
+		BalanceChanged(T::AssetId, T::AccountId, T::Balance, T::Balance),
+		/// A swap of some of one asset for some of another was proposed.
+		/// \[swap_id, proposer, offer_asset, offer_amount, want_asset, want_amount\]
+		SwapProposed(SwapId, T::AccountId, T::AssetId, T::Balance, T::AssetId, T::Balance),
+		/// A swap proposal was accepted and both of its transfers made. \[swap_id, acceptor\]
+		SwapAccepted(SwapId, T::AccountId),
+		/// A swap proposal was cancelled and its offered funds released. \[swap_id\]
+		SwapCancelled(SwapId),
+		/// An asset class and all of its state was moved to a new identifier.
+		/// \[old_asset_id, new_asset_id\]
+		AssetIdMigrated(T::AssetId, T::AssetId),
+		/// A transfer was scheduled and its amount held until then.
+		/// \[schedule_id, asset_id, source, dest, amount, execute_at\]
+		TransferScheduled(
+			ScheduleId,
+			T::AssetId,
+			T::AccountId,
+			T::AccountId,
+			T::Balance,
+			T::BlockNumber,
+		),
+		/// A scheduled transfer was executed. \[schedule_id\]
+		ScheduledTransferExecuted(ScheduleId),
+		/// A scheduled transfer could not be executed and its amount was released back to the
+		/// source. \[schedule_id\]
+		ScheduledTransferFailed(ScheduleId),
+		/// A scheduled transfer was cancelled and its amount released. \[schedule_id\]
+		ScheduledTransferCancelled(ScheduleId),
+		/// The account proposed to become the new owner of an asset changed, or was rescinded if
+		/// `None`. \[asset_id, proposed_owner\]
+		PendingOwnerChanged(T::AssetId, Option<T::AccountId>),
+		/// The proposed owner of an asset accepted and became its owner. \[asset_id, owner\]
+		OwnershipAccepted(T::AssetId, T::AccountId),
+		/// The owner of an asset gave up the role for good. \[asset_id\]
+		OwnershipRenounced(T::AssetId),
+		/// The metadata of an asset was frozen against changes. \[asset_id\]
+		MetadataFrozen(T::AssetId),
+		/// The metadata of an asset was thawed, allowing changes again. \[asset_id\]
+		MetadataThawed(T::AssetId),
+		/// A transfer of a `force_batch_transfer` failed and was skipped.
+		/// \[asset_id, source, dest, amount\]
+		BatchTransferFailed(T::AssetId, T::AccountId, T::AccountId, T::Balance),
+		/// Minting of an asset was paused. \[asset_id\]
+		MintingPaused(T::AssetId),
+		/// Minting of an asset was resumed. \[asset_id\]
+		MintingResumed(T::AssetId),
+		/// The transfer rate limit of an asset changed, or was removed if the window is zero.
+		/// \[asset_id, max_volume, window_blocks\]
+		TransferRateLimitChanged(T::AssetId, T::Balance, T::BlockNumber),
+		/// Some assets were transferred with a memo. \[asset_id, from, to, amount, memo\]
+		TransferredWithMemo(T::AssetId, T::AccountId, T::AccountId, T::Balance, Vec<u8>),
+		/// The transfer guard account of an asset changed, or was removed if `None`.
+		/// \[asset_id, guard\]
+		TransferGuardChanged(T::AssetId, Option<T::AccountId>),
+		/// Some approvals granted by an owner were cancelled at once. \[asset_id, owner, count\]
+		ApprovalsRevoked(T::AssetId, T::AccountId, u32),
+		/// An approval expired and was removed. \[asset_id, owner, delegate\]
+		ApprovalExpired(T::AssetId, T::AccountId, T::AccountId),
+		/// Whether new accounts of an asset class are self-sufficient has changed.
+		/// \[asset_id, is_sufficient\]
+		SufficiencyChanged(T::AssetId, bool),
+		/// The supply of an asset in excess of its maximum supply was burned.
+		/// \[asset_id, burned\]
+		SupplyCapEnforced(T::AssetId, T::Balance),
+		/// The deposit recorded for an asset was corrected. \[asset_id, old_deposit, new_deposit\]
+		DepositCorrected(T::AssetId, DepositBalanceOf<T, I>, DepositBalanceOf<T, I>),
+		/// An asset class began to be wound down. \[asset_id\]
+		AssetRetired(T::AssetId),
+		/// An asset class stopped being wound down. \[asset_id\]
+		AssetUnretired(T::AssetId),
 	}
 
 	#[pallet::error]
@@ -321,25 +820,102 @@ pub mod pallet {
 		/// The given asset ID is unknown.
 		Unknown,
 		/// The origin account is frozen.
-		Frozen,
+		AccountFrozen,
 		/// The asset ID is already taken.
 		InUse,
 		/// Invalid witness data given.
 		BadWitness,
 		/// Minimum balance should be non-zero.
 		MinBalanceZero,
-		/// No provider reference exists to allow a non-zero balance of a non-self-sufficient asset.
-		NoProvider,
+		/// The account must hold some native currency as an existential deposit to receive this
+		/// asset, unless the asset is marked as sufficient.
+		InsufficientProviders,
 		/// Invalid metadata given.
 		BadMetadata,
 		/// No approval exists that would allow the transfer.
 		Unapproved,
 		/// The source account would not survive the transfer and it needs to stay alive.
 		WouldDie,
+		/// The operation would take the total supply of the asset above its maximum supply.
+		SupplyCapExceeded,
+		/// The asset account already exists.
+		AlreadyExists,
+		/// The asset account has no deposit to be refunded.
+		NoDeposit,
+		/// The asset is not live, and is likely being destroyed.
+		AssetNotLive,
+		/// The asset status is not the expected status.
+		IncorrectStatus,
+		/// The minimum balance would be higher than the balance of an existing account.
+		MinBalanceTooHigh,
+		/// The transfer fee would leave too little to credit the destination account.
+		FeeTooLarge,
+		/// The given swap ID is unknown.
+		UnknownSwap,
+		/// The swap proposal has expired and may no longer be accepted.
+		SwapExpired,
+		/// The swap proposal has not expired, so may only be cancelled by its proposer.
+		SwapNotExpired,
+		/// No further swaps can be proposed or transfers scheduled.
+		Overflow,
+		/// A transfer can only be scheduled for a future block.
+		ScheduleInPast,
+		/// The maximum number of transfers are already scheduled for the block.
+		TooManyScheduled,
+		/// The given schedule ID is unknown.
+		UnknownSchedule,
+		/// The asset class is frozen, suspending all transfers of it.
+		AssetClassFrozen,
+		/// The asset already has the maximum number of accounts.
+		TooManyAccounts,
+		/// The asset already has the maximum number of approvals.
+		TooManyApprovals,
+		/// Minting of the asset is paused.
+		MintingDisabled,
+		/// The transfer would exceed the volume of the asset which may be transferred within the
+		/// current window of its rate limit.
+		TransferLimitExceeded,
+		/// The memo is not valid in the memo encoding of the runtime.
+		BadMemo,
+		/// The destination account has a frozen balance which its balance would not cover, together
+		/// with the minimum balance, after the transfer.
+		WouldKillDestination,
+		/// The off-chain signature does not authorise this call.
+		BadSignature,
+		/// The off-chain authorisation has expired.
+		AuthorisationExpired,
+		/// The given reason is longer than `StringLimit`.
+		BadReason,
+		/// The supply of the asset does not exceed its maximum supply.
+		NoExcessSupply,
+		/// The account is already frozen.
+		AlreadyFrozen,
+		/// The account is not frozen.
+		NotFrozen,
+		/// The asset is retiring, so it may not be minted and no new accounts or approvals may be
+		/// created.
+		AssetRetired,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {}
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			Self::execute_scheduled_transfers(now)
+		}
+
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::sweep_approval_expiries(now, remaining_weight)
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migration::migrate_to_v2::<T, I>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::check_invariants()
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -358,6 +934,7 @@ pub mod pallet {
 		/// member of the asset class's admin team.
 		/// - `min_balance`: The minimum balance of this new asset that any single account must
 		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		/// - `max_supply`: The maximum total supply of this new asset, if any.
 		///
 		/// Emits `Created` event when successful.
 		///
@@ -368,34 +945,46 @@ pub mod pallet {
 			#[pallet::compact] id: T::AssetId,
 			admin: <T::Lookup as StaticLookup>::Source,
 			min_balance: T::Balance,
+			max_supply: Option<T::Balance>,
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
 			let admin = T::Lookup::lookup(admin)?;
+			Self::do_create(id, owner, admin, min_balance, max_supply)
+		}
 
-			ensure!(!Asset::<T, I>::contains_key(id), Error::<T, I>::InUse);
-			ensure!(!min_balance.is_zero(), Error::<T, I>::MinBalanceZero);
+		/// Issue a new class of fungible assets from a public origin, under the next free
+		/// identifier.
+		///
+		/// This is the same as `create`, except that the identifier is allocated by the chain from
+		/// `NextAssetId` rather than chosen by the caller, so the call cannot be front-run into
+		/// failing with `InUse`. Identifiers already in use are skipped, up to
+		/// `MAX_ASSET_ID_PROBES` identifiers in all, after which the call fails with `InUse`.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Funds of sender are reserved by `AssetDeposit`.
+		///
+		/// Parameters:
+		/// - `admin`: The admin of this class of assets. The admin is the initial address of each
+		/// member of the asset class's admin team.
+		/// - `min_balance`: The minimum balance of this new asset that any single account must
+		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		///
+		/// Emits `Created` event with the allocated identifier when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::create_auto())]
+		pub(super) fn create_auto(
+			origin: OriginFor<T>,
+			admin: <T::Lookup as StaticLookup>::Source,
+			min_balance: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let admin = T::Lookup::lookup(admin)?;
 
-			let deposit = T::AssetDeposit::get();
-			T::Currency::reserve(&owner, deposit)?;
-
-			Asset::<T, I>::insert(
-				id,
-				AssetDetails {
-					owner: owner.clone(),
-					issuer: admin.clone(),
-					admin: admin.clone(),
-					freezer: admin.clone(),
-					supply: Zero::zero(),
-					deposit,
-					min_balance,
-					is_sufficient: false,
-					accounts: 0,
-					sufficients: 0,
-					approvals: 0,
-					is_frozen: false,
-				},
-			);
-			Self::deposit_event(Event::Created(id, owner, admin));
+			let id = Self::next_free_asset_id()?;
+			Self::do_create(id, owner, admin, min_balance, None)?;
+			NextAssetId::<T, I>::put(id.increment());
 			Ok(())
 		}
 
@@ -416,6 +1005,7 @@ pub mod pallet {
 		/// have no existential deposit.
 		/// - `min_balance`: The minimum balance of this new asset that any single account must
 		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		/// - `max_supply`: The maximum total supply of this new asset, if any.
 		///
 		/// Emits `ForceCreated` event when successful.
 		///
@@ -427,6 +1017,7 @@ pub mod pallet {
 			owner: <T::Lookup as StaticLookup>::Source,
 			is_sufficient: bool,
 			#[pallet::compact] min_balance: T::Balance,
+			max_supply: Option<T::Balance>,
 		) -> DispatchResult {
 			T::ForceOrigin::ensure_origin(origin)?;
 			let owner = T::Lookup::lookup(owner)?;
@@ -434,34 +1025,152 @@ pub mod pallet {
 			ensure!(!Asset::<T, I>::contains_key(id), Error::<T, I>::InUse);
 			ensure!(!min_balance.is_zero(), Error::<T, I>::MinBalanceZero);
 
-			Asset::<T, I>::insert(
-				id,
-				AssetDetails {
-					owner: owner.clone(),
-					issuer: owner.clone(),
-					admin: owner.clone(),
-					freezer: owner.clone(),
-					supply: Zero::zero(),
-					deposit: Zero::zero(),
-					min_balance,
-					is_sufficient,
-					accounts: 0,
-					sufficients: 0,
-					approvals: 0,
-					is_frozen: false,
-				},
-			);
+			let details = AssetDetailsBuilder::new(owner.clone())
+				.max_supply(max_supply)
+				.min_balance(min_balance)
+				.is_sufficient(is_sufficient)
+				.build();
+			Asset::<T, I>::insert(id, details);
 			Self::deposit_event(Event::ForceCreated(id, owner));
 			Ok(())
 		}
 
+		/// Issue a new class of fungible assets from a public origin, together with its metadata.
+		///
+		/// This is equivalent to `create` followed by `set_metadata`, except that either both
+		/// succeed or nothing happens.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Funds of sender are reserved by `AssetDeposit` plus
+		/// `MetadataDepositBase + MetadataDepositPerByte * (name.len + symbol.len)`.
+		///
+		/// Parameters:
+		/// - `id`: The identifier of the new asset. This must not be currently in use to identify
+		/// an existing asset.
+		/// - `admin`: The admin of this class of assets. The admin is the initial address of each
+		/// member of the asset class's admin team.
+		/// - `min_balance`: The minimum balance of this new asset that any single account must
+		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		/// - `max_supply`: The maximum total supply of this new asset, if any.
+		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
+		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
+		/// - `decimals`: The number of decimals this asset uses to represent one unit.
+		///
+		/// Emits `Created` and `MetadataSet` events when successful.
+		///
+		/// Weight: `O(N + S)` where N and S are the length of the name and symbol respectively.
+		#[pallet::weight(T::WeightInfo::create()
+			.saturating_add(T::WeightInfo::set_metadata(name.len() as u32, symbol.len() as u32))
+		)]
+		pub(super) fn create_with_metadata(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			admin: <T::Lookup as StaticLookup>::Source,
+			min_balance: T::Balance,
+			max_supply: Option<T::Balance>,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let admin = T::Lookup::lookup(admin)?;
+
+			let deposit = T::MetadataDepositPerByte::get()
+				.saturating_mul(((name.len() + symbol.len()) as u32).into())
+				.saturating_add(T::MetadataDepositBase::get());
+			let metadata = AssetMetadata {
+				deposit,
+				name: name.try_into().map_err(|_| Error::<T, I>::BadMetadata)?,
+				symbol: symbol.try_into().map_err(|_| Error::<T, I>::BadMetadata)?,
+				decimals,
+				is_frozen: false,
+				image_hash: None,
+				uri: None,
+			};
+			ensure!(!min_balance.is_zero(), Error::<T, I>::MinBalanceZero);
+			let details = AssetDetailsBuilder::new(owner.clone())
+				.issuer(admin.clone())
+				.admin(admin.clone())
+				.freezer(admin.clone())
+				.max_supply(max_supply)
+				.min_balance(min_balance)
+				.deposit(T::AssetDeposit::get())
+				.build();
+			Self::do_create_with_metadata(id, details, metadata, Event::Created(id, owner, admin))
+		}
+
+		/// Issue a new class of fungible assets from a privileged origin, together with its
+		/// metadata.
+		///
+		/// This is equivalent to `force_create` followed by `force_set_metadata`, except that
+		/// either both succeed or nothing happens.
+		///
+		/// The origin must conform to `ForceOrigin`.
+		///
+		/// Unlike `create_with_metadata`, no funds are reserved.
+		///
+		/// - `id`: The identifier of the new asset. This must not be currently in use to identify
+		/// an existing asset.
+		/// - `owner`: The owner of this class of assets.
+		/// - `is_sufficient`: Whether a non-zero balance of this asset is deposit of sufficient
+		/// value to account for the state bloat associated with its balance storage.
+		/// - `min_balance`: The minimum balance of this new asset that any single account must
+		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		/// - `max_supply`: The maximum total supply of this new asset, if any.
+		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
+		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
+		/// - `decimals`: The number of decimals this asset uses to represent one unit.
+		/// - `is_frozen`: Whether this asset metadata should be locked in place.
+		///
+		/// Emits `ForceCreated` and `MetadataSet` events when successful.
+		///
+		/// Weight: `O(N + S)` where N and S are the length of the name and symbol respectively.
+		#[pallet::weight(T::WeightInfo::force_create()
+			.saturating_add(T::WeightInfo::force_set_metadata(name.len() as u32, symbol.len() as u32))
+		)]
+		pub(super) fn force_create_with_metadata(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			is_sufficient: bool,
+			#[pallet::compact] min_balance: T::Balance,
+			max_supply: Option<T::Balance>,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			is_frozen: bool,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			let metadata = AssetMetadata {
+				deposit: Zero::zero(),
+				name: name.try_into().map_err(|_| Error::<T, I>::BadMetadata)?,
+				symbol: symbol.try_into().map_err(|_| Error::<T, I>::BadMetadata)?,
+				decimals,
+				is_frozen,
+				image_hash: None,
+				uri: None,
+			};
+			ensure!(!min_balance.is_zero(), Error::<T, I>::MinBalanceZero);
+			let details = AssetDetailsBuilder::new(owner.clone())
+				.max_supply(max_supply)
+				.min_balance(min_balance)
+				.is_sufficient(is_sufficient)
+				.build();
+			Self::do_create_with_metadata(id, details, metadata, Event::ForceCreated(id, owner))
+		}
+
 		/// Destroy a class of fungible assets.
 		///
 		/// The origin must conform to `ForceOrigin` or must be Signed and the sender must be the
 		/// owner of the asset `id`.
 		///
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
-		/// asset.
+		/// asset which no open swap proposal or scheduled transfer refers to.
+		/// - `witness`: Upper bounds on the number of accounts, sufficients and approvals of the
+		/// asset, as given by `AssetDetails::destroy_witness`.
 		///
 		/// Emits `Destroyed` event when successful.
 		///
@@ -469,6 +1178,7 @@ pub mod pallet {
 		/// - `c = (witness.accounts - witness.sufficients)`
 		/// - `s = witness.sufficients`
 		/// - `a = witness.approvals`
+		/// Any weight of an over-estimated witness is refunded.
 		#[pallet::weight(T::WeightInfo::destroy(
 			witness.accounts.saturating_sub(witness.sufficients),
  			witness.sufficients,
@@ -478,21 +1188,35 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
 			witness: DestroyWitness,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
 				Ok(_) => None,
 				Err(origin) => Some(ensure_signed(origin)?),
 			};
-			Asset::<T, I>::try_mutate_exists(id, |maybe_details| {
+			let destroyed = Asset::<T, I>::try_mutate_exists(id, |maybe_details| {
 				let mut details = maybe_details.take().ok_or(Error::<T, I>::Unknown)?;
 				if let Some(check_owner) = maybe_check_owner {
 					ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
 				}
-				ensure!(details.accounts == witness.accounts, Error::<T, I>::BadWitness);
-				ensure!(details.sufficients == witness.sufficients, Error::<T, I>::BadWitness);
-				ensure!(details.approvals == witness.approvals, Error::<T, I>::BadWitness);
-
+				ensure!(details.accounts <= witness.accounts, Error::<T, I>::BadWitness);
+				ensure!(details.sufficients <= witness.sufficients, Error::<T, I>::BadWitness);
+				ensure!(details.approvals <= witness.approvals, Error::<T, I>::BadWitness);
+				ensure!(OpenReferences::<T, I>::get(id) == 0, Error::<T, I>::InUse);
+
+				let supply = details.supply;
+				// The approvals are drained before the accounts, since iterating storage past keys
+				// removed earlier in the block costs stack for each of them.
+				let approvals = Self::drain_approvals(id);
+				details.approvals = 0;
+				let mut destroyed = DestroyWitness { accounts: 0, sufficients: 0, approvals };
 				for (who, v) in Account::<T, I>::drain_prefix(id) {
+					if let Some(deposit) = v.deposit {
+						T::Currency::unreserve(&who, deposit);
+					}
+					destroyed.accounts = destroyed.accounts.saturating_add(1);
+					if v.sufficient {
+						destroyed.sufficients = destroyed.sufficients.saturating_add(1);
+					}
 					Self::dead_account(id, &who, &mut details, v.sufficient);
 				}
 				debug_assert_eq!(details.accounts, 0);
@@ -500,44 +1224,177 @@ pub mod pallet {
 
 				let metadata = Metadata::<T, I>::take(&id);
 				T::Currency::unreserve(
-					&details.owner,
+					&details.depositor,
 					details.deposit.saturating_add(metadata.deposit),
 				);
 
-				Approvals::<T, I>::remove_prefix((&id,));
-				Self::deposit_event(Event::Destroyed(id));
+				PendingOwner::<T, I>::remove(id);
+				TransferGuardAccount::<T, I>::remove(id);
+				TransferRateLimitConfig::<T, I>::remove(id);
+				TransferVolume::<T, I>::remove(id);
+				Self::deposit_event(
+					Event::Destroyed(id, destroyed.accounts, destroyed.approvals, supply)
+				);
+				Ok::<_, DispatchError>(destroyed)
+			})?;
 
-				// NOTE: could use postinfo to reflect the actual number of accounts/sufficient/approvals
-				Ok(())
-			})
+			Ok(Some(T::WeightInfo::destroy(
+				destroyed.accounts.saturating_sub(destroyed.sufficients),
+				destroyed.sufficients,
+				destroyed.approvals,
+			)).into())
 		}
 
-		/// Mint assets of a particular class.
+		/// Start the destruction of a class of fungible assets.
 		///
-		/// The origin must be Signed and the sender must be the Issuer of the asset `id`.
+		/// The origin must conform to `ForceOrigin` or must be Signed and the sender must be the
+		/// owner of the asset `id`.
 		///
-		/// - `id`: The identifier of the asset to have some amount minted.
-		/// - `beneficiary`: The account to be credited with the minted assets.
-		/// - `amount`: The amount of the asset to be minted.
+		/// Once started, the asset may no longer be minted, burned or transferred, and its
+		/// accounts and approvals may be removed by anyone with `destroy_accounts` and
+		/// `destroy_approvals`, after which `finish_destroy` removes the asset itself.
 		///
-		/// Emits `Destroyed` event when successful.
+		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
+		/// live or retiring asset which no open swap proposal or scheduled transfer refers to.
+		///
+		/// Emits `DestructionStarted` event when successful.
 		///
 		/// Weight: `O(1)`
-		/// Modes: Pre-existing balance of `beneficiary`; Account pre-existence of `beneficiary`.
-		#[pallet::weight(T::WeightInfo::mint())]
-		pub(super) fn mint(
+		#[pallet::weight(T::WeightInfo::start_destroy())]
+		pub(super) fn start_destroy(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
-			beneficiary: <T::Lookup as StaticLookup>::Source,
-			#[pallet::compact] amount: T::Balance
 		) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			Self::do_start_destroy(id, maybe_check_owner)
+		}
+
+		/// Remove up to `max_items` accounts of a class of fungible assets being destroyed.
+		///
+		/// Origin must be Signed.
+		///
+		/// Any deposits placed for the removed accounts with `touch` are returned. No accounts are
+		/// removed while an open swap proposal or scheduled transfer refers to the asset.
+		///
+		/// - `id`: The identifier of the asset being destroyed.
+		/// - `max_items`: The maximum number of accounts to remove.
+		///
+		/// Emits `AccountsDestroyed` event when successful.
+		///
+		/// Weight: `O(max_items)`
+		#[pallet::weight(T::WeightInfo::destroy_accounts(*max_items))]
+		pub(super) fn destroy_accounts(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			max_items: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_destroy_accounts(id, max_items)
+		}
+
+		/// Remove up to `max_items` approvals of a class of fungible assets being destroyed.
+		///
+		/// Origin must be Signed.
+		///
+		/// The deposits reserved for the removed approvals are returned.
+		///
+		/// - `id`: The identifier of the asset being destroyed.
+		/// - `max_items`: The maximum number of approvals to remove.
+		///
+		/// Emits `ApprovalsDestroyed` event when successful.
+		///
+		/// Weight: `O(max_items)`
+		#[pallet::weight(T::WeightInfo::destroy_approvals(*max_items))]
+		pub(super) fn destroy_approvals(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			max_items: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_destroy_approvals(id, max_items)
+		}
+
+		/// Complete the destruction of a class of fungible assets.
+		///
+		/// Origin must be Signed.
+		///
+		/// All accounts and approvals of the asset must already have been removed. The asset
+		/// and metadata deposits are returned to the account they were reserved from.
+		///
+		/// - `id`: The identifier of the asset being destroyed.
+		///
+		/// Emits `Destroyed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::finish_destroy())]
+		pub(super) fn finish_destroy(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_finish_destroy(id)
+		}
+
+		/// Mint assets of a particular class.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to have some amount minted.
+		/// - `beneficiary`: The account to be credited with the minted assets.
+		/// - `amount`: The amount of the asset to be minted.
+		///
+		/// Emits `Destroyed` event when successful.
+		///
+		/// Weight: `O(1)`
+		/// Modes: Pre-existing balance of `beneficiary`; Account pre-existence of `beneficiary`.
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub(super) fn mint(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
 			Self::do_mint(id, &beneficiary, amount, Some(origin))?;
 			Self::deposit_event(Event::Issued(id, beneficiary, amount));
 			Ok(())
 		}
 
+		/// Mint assets of a particular class into several accounts at once.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the asset `id`.
+		///
+		/// Either all of the mints succeed or none do. The total minted must not take the supply
+		/// above the maximum supply of the asset, if any.
+		///
+		/// - `id`: The identifier of the asset to have some amount minted.
+		/// - `targets`: The accounts to be credited with minted assets, each with the amount to be
+		/// minted into it. At most `MaxMintTargets` targets may be given.
+		///
+		/// Emits `Issued` for each target credited.
+		///
+		/// Weight: `O(N)` where N is the number of targets.
+		#[pallet::weight(T::WeightInfo::mint_to_many(targets.len() as u32))]
+		pub(super) fn mint_to_many(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			targets: BoundedVec<
+				(<T::Lookup as StaticLookup>::Source, T::Balance),
+				T::MaxMintTargets,
+			>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let targets = targets.into_iter()
+				.map(|(target, amount)| Ok((T::Lookup::lookup(target)?, amount)))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+
+			Self::do_mint_to_many(id, origin, targets)
+		}
+
 		/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`.
 		///
 		/// Origin must be Signed and the sender should be the Manager of the asset `id`.
@@ -563,12 +1420,39 @@ pub mod pallet {
 			let origin = ensure_signed(origin)?;
 			let who = T::Lookup::lookup(who)?;
 
-			let f = DebitFlags { keep_alive: false, best_effort: true };
+			let f = DebitFlags { keep_alive: false, best_effort: true, respect_freezer: true };
 			let burned = Self::do_burn(id, &who, amount, Some(origin), f)?;
 			Self::deposit_event(Event::Burned(id, who, burned));
 			Ok(())
 		}
 
+		/// Reduce the balance of `who` by as much as possible up to `max_amount` assets of `id`,
+		/// without reducing it below the minimum balance or any frozen balance.
+		///
+		/// Origin must be Signed and the sender should be the Manager of the asset `id`.
+		///
+		/// Burns nothing if the balance of `who` is already at or below the minimum balance.
+		///
+		/// - `id`: The identifier of the asset to have some amount burned.
+		/// - `who`: The account to be debited from.
+		/// - `max_amount`: The maximum amount by which `who`'s balance should be reduced.
+		///
+		/// Emits `Burned` with the actual amount burned.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::soft_burn())]
+		pub(super) fn soft_burn(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] max_amount: T::Balance
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			Self::do_soft_burn(id, &who, max_amount, origin).map(|_| ())
+		}
+
 		/// Move some assets from the sender account to another.
 		///
 		/// Origin must be Signed.
@@ -600,11 +1484,96 @@ pub mod pallet {
 			let f = TransferFlags {
 				keep_alive: false,
 				best_effort: false,
-				burn_dust: false
+				burn_dust: false,
+				respect_freezer: true,
 			};
 			Self::do_transfer(id, &origin, &dest, amount, None, f).map(|_| ())
 		}
 
+		/// Move as much as possible, up to `amount`, of some assets from the sender account to
+		/// another.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited.
+		/// - `amount`: The maximum amount by which the sender's balance of assets should be reduced
+		/// and `target`'s balance increased. If the sender cannot transfer this much, then all of
+		/// its reducible balance is transferred instead. This may be zero, as it is when the asset
+		/// class or the sender's account is frozen or the asset is being destroyed, in which case
+		/// nothing happens and `target` is not created.
+		///
+		/// Emits `Transferred` with the actual amount transferred.
+		///
+		/// Weight: `O(1)`. Most of it is refunded if nothing is transferred.
+		#[pallet::weight(T::WeightInfo::transfer_best_effort())]
+		pub(super) fn transfer_best_effort(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			target: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(target)?;
+
+			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			let can_send = details.status != AssetStatus::Destroying
+				&& !details.is_frozen
+				&& !Account::<T, I>::get(id, &origin).is_frozen;
+			let amount = if can_send { amount } else { Zero::zero() };
+			let f = TransferFlags {
+				keep_alive: false,
+				best_effort: true,
+				burn_dust: false,
+				respect_freezer: true,
+			};
+			let actual = Self::do_transfer(id, &origin, &dest, amount, None, f)?;
+			if actual.is_zero() {
+				return Ok(Some(T::DbWeight::get().reads(2)).into())
+			}
+			Ok(().into())
+		}
+
+		/// Move some assets from the sender account to another, together with a memo for the
+		/// recipient, such as a destination tag of an exchange.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited.
+		/// - `amount`: The amount by which the sender's balance of assets should be reduced and
+		/// `target`'s balance increased, as with `transfer`.
+		/// - `memo`: Arbitrary data for the recipient, limited in length by `MemoLimit` and which
+		/// must be valid in the `MemoEncoding` of the runtime. It is only included in the event and
+		/// not stored.
+		///
+		/// Emits `Transferred` and then `TransferredWithMemo` with the actual amount transferred.
+		///
+		/// Weight: `O(M)` where `M` is the length of the memo.
+		#[pallet::weight(T::WeightInfo::transfer_with_memo(memo.len() as u32))]
+		pub(super) fn transfer_with_memo(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			target: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+			memo: BoundedVec<u8, T::MemoLimit>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(target)?;
+			ensure!(T::MemoEncoding::get().validate(&memo), Error::<T, I>::BadMemo);
+
+			let f = TransferFlags {
+				keep_alive: false,
+				best_effort: false,
+				burn_dust: false,
+				respect_freezer: true,
+			};
+			let credit = Self::do_transfer(id, &origin, &dest, amount, None, f)?;
+			let memo = memo.into_inner();
+			Self::deposit_event(Event::TransferredWithMemo(id, origin, dest, credit, memo));
+			Ok(())
+		}
+
 		/// Move some assets from the sender account to another, keeping the sender account alive.
 		///
 		/// Origin must be Signed.
@@ -636,7 +1605,8 @@ pub mod pallet {
 			let f = TransferFlags {
 				keep_alive: true,
 				best_effort: false,
-				burn_dust: false
+				burn_dust: false,
+				respect_freezer: true,
 			};
 			Self::do_transfer(id, &source, &dest, amount, None, f).map(|_| ())
 		}
@@ -675,17 +1645,117 @@ pub mod pallet {
 			let f = TransferFlags {
 				keep_alive: false,
 				best_effort: false,
-				burn_dust: false
+				burn_dust: false,
+				respect_freezer: true,
 			};
 			Self::do_transfer(id, &source, &dest, amount, Some(origin), f).map(|_| ())
 		}
 
+		/// Move some assets from one account to several others.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// Each transfer is made in turn as with `force_transfer`. A transfer which fails, for
+		/// example because `source` has run out of balance, is skipped without affecting the
+		/// others. Unlike `force_transfer`, the transfers are made even if `source` or the asset
+		/// class is frozen.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `source`: The account to be debited.
+		/// - `targets`: The accounts to be credited, each with the amount by which it should be
+		/// credited. At most `MaxFanOut` targets may be given.
+		///
+		/// Emits `Transferred` for each target credited and `BatchTransferFailed` for each target
+		/// skipped.
+		///
+		/// Weight: `O(N)` where N is the number of targets.
+		#[pallet::weight(T::WeightInfo::force_batch_transfer(targets.len() as u32))]
+		pub(super) fn force_batch_transfer(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			source: <T::Lookup as StaticLookup>::Source,
+			targets: BoundedVec<(<T::Lookup as StaticLookup>::Source, T::Balance), T::MaxFanOut>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let source = T::Lookup::lookup(source)?;
+			let targets = targets.into_iter()
+				.map(|(target, amount)| Ok((T::Lookup::lookup(target)?, amount)))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+
+			Self::do_force_batch_transfer(id, origin, &source, targets)
+		}
+
+		/// Move some assets from the sender account to several other accounts.
+		///
+		/// Origin must be Signed.
+		///
+		/// The sender must have enough balance to cover all of the transfers, otherwise nothing is
+		/// transferred. If the sender is left with less than the minimum balance, then its account
+		/// is removed and the remainder is credited to the last of the targets.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `targets`: The accounts to be credited, each with the amount by which it should be
+		/// credited. At most `MaxFanOut` targets may be given. Entries for the sender or with a
+		/// zero amount are ignored.
+		///
+		/// Emits `Transferred` for each target credited.
+		///
+		/// Weight: `O(N)` where N is the number of targets.
+		#[pallet::weight(T::WeightInfo::transfer_to_many(targets.len() as u32))]
+		pub(super) fn transfer_to_many(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			targets: BoundedVec<(<T::Lookup as StaticLookup>::Source, T::Balance), T::MaxFanOut>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let targets = targets.into_iter()
+				.map(|(target, amount)| Ok((T::Lookup::lookup(target)?, amount)))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+
+			Self::do_transfer_to_many(id, &origin, targets)
+		}
+
+		/// Make several transfers, of possibly different assets, atomically.
+		///
+		/// Origin must be Signed.
+		///
+		/// Each transfer is either from the sender or from an account which has approved the sender
+		/// to transfer at least its amount, in which case the approval is used as with
+		/// `transfer_approved`. If any of the transfers fails, then none of them are made.
+		///
+		/// - `transfers`: The transfers to be made, in order. At most `MaxMultiTransfer` transfers
+		/// may be given.
+		///
+		/// Emits `Transferred` for each transfer made.
+		///
+		/// Weight: `O(N)` where N is the number of transfers.
+		#[pallet::weight(T::WeightInfo::multi_asset_transfer(transfers.len() as u32))]
+		pub(super) fn multi_asset_transfer(
+			origin: OriginFor<T>,
+			transfers: BoundedVec<
+				MultiAssetTransfer<T::AssetId, <T::Lookup as StaticLookup>::Source, T::Balance>,
+				T::MaxMultiTransfer,
+			>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let transfers = transfers.into_iter()
+				.map(|t| Ok(MultiAssetTransfer {
+					asset: t.asset,
+					source: T::Lookup::lookup(t.source)?,
+					dest: T::Lookup::lookup(t.dest)?,
+					amount: t.amount,
+				}))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+
+			Self::do_multi_asset_transfer(&origin, transfers)
+		}
+
 		/// Disallow further unprivileged transfers from an account.
 		///
 		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
 		///
 		/// - `id`: The identifier of the asset to be frozen.
-		/// - `who`: The account to be frozen.
+		/// - `who`: The account to be frozen. It must not already be frozen.
 		///
 		/// Emits `Frozen`.
 		///
@@ -705,6 +1775,7 @@ pub mod pallet {
 				Account::<T, I>::contains_key(id, &who),
 				Error::<T, I>::BalanceZero
 			);
+			ensure!(!Account::<T, I>::get(id, &who).is_frozen, Error::<T, I>::AlreadyFrozen);
 
 			Account::<T, I>::mutate(id, &who, |a| a.is_frozen = true);
 
@@ -717,7 +1788,7 @@ pub mod pallet {
 		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
 		///
 		/// - `id`: The identifier of the asset to be frozen.
-		/// - `who`: The account to be unfrozen.
+		/// - `who`: The account to be unfrozen. It must be frozen.
 		///
 		/// Emits `Thawed`.
 		///
@@ -738,6 +1809,7 @@ pub mod pallet {
 				Account::<T, I>::contains_key(id, &who),
 				Error::<T, I>::BalanceZero
 			);
+			ensure!(Account::<T, I>::get(id, &who).is_frozen, Error::<T, I>::NotFrozen);
 
 			Account::<T, I>::mutate(id, &who, |a| a.is_frozen = false);
 
@@ -745,13 +1817,75 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Disallow further unprivileged transfers from several accounts.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be frozen.
+		/// - `targets`: The accounts to be frozen. At most `MaxFreezeBatch` targets may be given.
+		/// Targets which hold no account for the asset are skipped.
+		///
+		/// Emits `Frozen` for each account frozen.
+		///
+		/// Weight: `O(N)` where N is the number of targets. Skipped targets are refunded.
+		#[pallet::weight(T::WeightInfo::bulk_freeze_accounts(targets.len() as u32))]
+		pub(super) fn bulk_freeze_accounts(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			targets: BoundedVec<<T::Lookup as StaticLookup>::Source, T::MaxFreezeBatch>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(&origin == &d.freezer, Error::<T, I>::NoPermission);
+			let targets = targets.into_iter()
+				.map(T::Lookup::lookup)
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let (done, skipped) = Self::do_set_accounts_frozen(id, targets, true);
+			let weight = T::WeightInfo::bulk_freeze_accounts(done)
+				.saturating_add(T::DbWeight::get().reads(skipped.into()));
+			Ok(Some(weight).into())
+		}
+
+		/// Allow unprivileged transfers from several accounts again.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be thawed.
+		/// - `targets`: The accounts to be unfrozen. At most `MaxFreezeBatch` targets may be given.
+		/// Targets which hold no account for the asset are skipped.
+		///
+		/// Emits `Thawed` for each account unfrozen.
+		///
+		/// Weight: `O(N)` where N is the number of targets. Skipped targets are refunded.
+		#[pallet::weight(T::WeightInfo::bulk_thaw_accounts(targets.len() as u32))]
+		pub(super) fn bulk_thaw_accounts(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			targets: BoundedVec<<T::Lookup as StaticLookup>::Source, T::MaxFreezeBatch>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(&origin == &details.admin, Error::<T, I>::NoPermission);
+			let targets = targets.into_iter()
+				.map(T::Lookup::lookup)
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let (done, skipped) = Self::do_set_accounts_frozen(id, targets, false);
+			let weight = T::WeightInfo::bulk_thaw_accounts(done)
+				.saturating_add(T::DbWeight::get().reads(skipped.into()));
+			Ok(Some(weight).into())
+		}
+
 		/// Disallow further unprivileged transfers for the asset class.
 		///
 		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
 		///
 		/// - `id`: The identifier of the asset to be frozen.
 		///
-		/// Emits `Frozen`.
+		/// Emits `AssetFrozen`.
 		///
 		/// Weight: `O(1)`
 		#[pallet::weight(T::WeightInfo::freeze_asset())]
@@ -760,25 +1894,36 @@ pub mod pallet {
 			#[pallet::compact] id: T::AssetId
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
+			Self::do_set_asset_class_frozen(id, origin, true, None)
+		}
 
-			Asset::<T, I>::try_mutate(id, |maybe_details| {
-				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
-				ensure!(&origin == &d.freezer, Error::<T, I>::NoPermission);
-
-				d.is_frozen = true;
-
-				Self::deposit_event(Event::<T, I>::AssetFrozen(id));
-				Ok(())
-			})
+		/// Disallow further unprivileged transfers for the asset class, noting why.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be frozen.
+		/// - `reason`: Why the asset is frozen. Limited in length by `StringLimit`.
+		///
+		/// Emits `AssetFrozen` with the reason.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::freeze_asset_with_reason())]
+		pub(super) fn freeze_asset_with_reason(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			reason: Vec<u8>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_asset_class_frozen(id, origin, true, Some(reason))
 		}
 
 		/// Allow unprivileged transfers for the asset again.
 		///
 		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
 		///
-		/// - `id`: The identifier of the asset to be frozen.
+		/// - `id`: The identifier of the asset to be thawed.
 		///
-		/// Emits `Thawed`.
+		/// Emits `AssetThawed`.
 		///
 		/// Weight: `O(1)`
 		#[pallet::weight(T::WeightInfo::thaw_asset())]
@@ -787,30 +1932,195 @@ pub mod pallet {
 			#[pallet::compact] id: T::AssetId
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
+			Self::do_set_asset_class_frozen(id, origin, false, None)
+		}
+
+		/// Allow unprivileged transfers for the asset again, noting why.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be thawed.
+		/// - `reason`: Why the asset is thawed. Limited in length by `StringLimit`.
+		///
+		/// Emits `AssetThawed` with the reason.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::thaw_asset_with_reason())]
+		pub(super) fn thaw_asset_with_reason(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			reason: Vec<u8>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_asset_class_frozen(id, origin, false, Some(reason))
+		}
+
+		/// Disallow further minting of the asset class, leaving transfers unaffected.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset whose minting is to be paused.
+		///
+		/// Emits `MintingPaused`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::pause_minting())]
+		pub(super) fn pause_minting(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
 
 			Asset::<T, I>::try_mutate(id, |maybe_details| {
 				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
-				ensure!(&origin == &d.admin, Error::<T, I>::NoPermission);
+				ensure!(&origin == &d.issuer, Error::<T, I>::NoPermission);
 
-				d.is_frozen = false;
+				d.disable_mint = true;
 
-				Self::deposit_event(Event::<T, I>::AssetThawed(id));
+				Self::deposit_event(Event::<T, I>::MintingPaused(id));
 				Ok(())
 			})
 		}
 
-		/// Change the Owner of an asset.
+		/// Allow minting of the asset class again.
 		///
-		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		/// Origin must be Signed and the sender should be the Issuer of the asset `id`.
 		///
-		/// - `id`: The identifier of the asset.
-		/// - `owner`: The new Owner of this asset.
+		/// - `id`: The identifier of the asset whose minting is to be resumed.
 		///
-		/// Emits `OwnerChanged`.
+		/// Emits `MintingResumed`.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::transfer_ownership())]
-		pub(super) fn transfer_ownership(
+		#[pallet::weight(T::WeightInfo::resume_minting())]
+		pub(super) fn resume_minting(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T, I>::try_mutate(id, |maybe_details| {
+				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(&origin == &d.issuer, Error::<T, I>::NoPermission);
+
+				d.disable_mint = false;
+
+				Self::deposit_event(Event::<T, I>::MintingResumed(id));
+				Ok(())
+			})
+		}
+
+		/// Begin winding down the asset class ahead of its destruction.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// While retiring, the asset may still be transferred between existing accounts and
+		/// burned, but it may not be minted and no new accounts or approvals may be created.
+		///
+		/// - `id`: The identifier of the asset to be retired. This must identify a live asset.
+		///
+		/// Emits `AssetRetired`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::retire_asset())]
+		pub(super) fn retire_asset(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T, I>::try_mutate(id, |maybe_details| {
+				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(&origin == &d.owner, Error::<T, I>::NoPermission);
+				ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+
+				d.status = AssetStatus::Retiring;
+
+				Self::deposit_event(Event::<T, I>::AssetRetired(id));
+				Ok(())
+			})
+		}
+
+		/// Return a retiring asset class to normal use.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be unretired. This must identify a retiring
+		/// asset.
+		///
+		/// Emits `AssetUnretired`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::unretire_asset())]
+		pub(super) fn unretire_asset(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T, I>::try_mutate(id, |maybe_details| {
+				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(&origin == &d.owner, Error::<T, I>::NoPermission);
+				ensure!(d.status == AssetStatus::Retiring, Error::<T, I>::IncorrectStatus);
+
+				d.status = AssetStatus::Live;
+
+				Self::deposit_event(Event::<T, I>::AssetUnretired(id));
+				Ok(())
+			})
+		}
+
+		/// Stop giving new accounts of the asset class a provider reference.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// The Owner may only make a sufficient asset insufficient: since sufficient accounts need
+		/// no existential deposit, only `ForceOrigin` may make an asset sufficient, with
+		/// `force_asset_status`. Only accounts created afterwards are affected: existing accounts
+		/// keep the reference they were created with until they are removed.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `is_sufficient`: Whether new accounts should be given a provider reference rather
+		/// than require a consumer reference.
+		///
+		/// Emits `SufficiencyChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_asset_sufficient())]
+		pub(super) fn set_asset_sufficient(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			is_sufficient: bool,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T, I>::try_mutate(id, |maybe_details| {
+				let d = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(&origin == &d.owner, Error::<T, I>::NoPermission);
+				ensure!(!is_sufficient || d.is_sufficient, Error::<T, I>::NoPermission);
+
+				d.is_sufficient = is_sufficient;
+
+				Self::deposit_event(Event::<T, I>::SufficiencyChanged(id, is_sufficient));
+				Ok(())
+			})
+		}
+
+		/// Change the Owner of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// This moves the deposit to the new Owner immediately. Prefer `propose_new_owner`, which
+		/// only moves it once the new Owner has accepted with `accept_ownership`. Any pending
+		/// proposal is cleared.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The new Owner of this asset.
+		///
+		/// Emits `OwnerChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub(super) fn transfer_ownership(
 			origin: OriginFor<T>,
 			#[pallet::compact] id: T::AssetId,
 			owner: <T::Lookup as StaticLookup>::Source,
@@ -825,19 +2135,251 @@ pub mod pallet {
 					return Ok(());
 				}
 
-				let metadata_deposit = Metadata::<T, I>::get(id).deposit;
-				let deposit = details.deposit + metadata_deposit;
+				Self::do_set_owner(id, details, owner.clone())?;
+
+				Self::deposit_event(Event::OwnerChanged(id, owner));
+				Ok(())
+			})
+		}
+
+		/// Change the Owner of an asset on behalf of its Owner, as authorised by an off-chain
+		/// signature.
+		///
+		/// Origin must be Signed; any account may submit the authorisation.
+		///
+		/// The Owner must have signed the payload given by `transfer_ownership_payload` for
+		/// `(id, owner, nonce, expiry)`, where `nonce` is the asset's current `owner_nonce`, which
+		/// is then incremented. Otherwise this behaves as `transfer_ownership`, moving the deposit
+		/// to the new Owner.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The new Owner of this asset.
+		/// - `expiry`: The last block in which the authorisation may be used.
+		/// - `signature`: The Owner's signature of the authorisation.
+		///
+		/// Emits `OwnerChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_ownership_by_operator())]
+		pub(super) fn transfer_ownership_by_operator(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			expiry: T::BlockNumber,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= expiry, Error::<T, I>::AuthorisationExpired);
+
+			Asset::<T, I>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				let nonce = details.owner_nonce;
+				let message = Self::transfer_ownership_payload(id, &owner, nonce, expiry);
+				ensure!(
+					signature.verify(&message[..], &details.owner),
+					Error::<T, I>::BadSignature,
+				);
+				details.owner_nonce = nonce.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+				if details.owner != owner {
+					Self::do_set_owner(id, details, owner.clone())?;
+				}
+
+				Self::deposit_event(Event::OwnerChanged(id, owner));
+				Ok(())
+			})
+		}
 
-				// Move the deposit to the new owner.
-				T::Currency::repatriate_reserved(&details.owner, &owner, deposit, Reserved)?;
+		/// Change the Owner of an asset, leaving its deposits reserved from the previous Owner.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// Unlike `transfer_ownership`, no deposit is moved, so the new Owner does not need any
+		/// free balance. The deposits are returned to the account they were reserved from when
+		/// the asset is destroyed or its metadata cleared, and move to the new Owner should they
+		/// change the metadata. Any pending proposal of a new Owner is cleared.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The new Owner of this asset.
+		///
+		/// Emits `OwnerChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_transfer_ownership())]
+		pub(super) fn force_transfer_ownership(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
 
+			Asset::<T, I>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
 				details.owner = owner.clone();
+				PendingOwner::<T, I>::remove(id);
 
 				Self::deposit_event(Event::OwnerChanged(id, owner));
 				Ok(())
 			})
 		}
 
+		/// Set the deposit recorded for an asset to some value, without reserving or unreserving
+		/// anything.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// This is for correcting deposit accounting, such as after a migration. The caller must
+		/// ensure that the new deposit is actually reserved from the Owner, or is deliberately zero.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `deposit`: The deposit to record for this asset.
+		///
+		/// Emits `DepositCorrected`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_set_owner_deposit())]
+		pub(super) fn force_set_owner_deposit(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			deposit: DepositBalanceOf<T, I>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Asset::<T, I>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				let old_deposit = sp_std::mem::replace(&mut details.deposit, deposit);
+
+				Self::deposit_event(Event::DepositCorrected(id, old_deposit, deposit));
+				Ok(())
+			})
+		}
+
+		/// Propose a new Owner for an asset, to take effect once they call `accept_ownership`.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// No deposit is moved until the proposal is accepted. Any previous proposal is replaced.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `proposed_owner`: The account proposed to become the new Owner of this asset.
+		///
+		/// Emits `PendingOwnerChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::propose_new_owner())]
+		pub(super) fn propose_new_owner(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			proposed_owner: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let proposed_owner = T::Lookup::lookup(proposed_owner)?;
+			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(&origin == &details.owner, Error::<T, I>::NoPermission);
+
+			PendingOwner::<T, I>::insert(id, &proposed_owner);
+			Self::deposit_event(Event::PendingOwnerChanged(id, Some(proposed_owner)));
+			Ok(())
+		}
+
+		/// Accept the ownership of an asset, as proposed by its Owner with `propose_new_owner`.
+		///
+		/// Origin must be Signed and the sender should be the proposed Owner of the asset `id`.
+		///
+		/// The deposit of the asset and its metadata is moved from the old Owner to the sender.
+		///
+		/// - `id`: The identifier of the asset.
+		///
+		/// Emits `OwnershipAccepted`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::accept_ownership())]
+		pub(super) fn accept_ownership(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let proposed_owner = PendingOwner::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(origin == proposed_owner, Error::<T, I>::NoPermission);
+
+			Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				Self::do_set_owner(id, details, origin.clone())
+			})?;
+
+			Self::deposit_event(Event::OwnershipAccepted(id, origin));
+			Ok(())
+		}
+
+		/// Rescind the proposal of a new Owner for an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		///
+		/// Emits `PendingOwnerChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_proposed_owner())]
+		pub(super) fn cancel_proposed_owner(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(&origin == &details.owner, Error::<T, I>::NoPermission);
+			ensure!(PendingOwner::<T, I>::take(id).is_some(), Error::<T, I>::Unknown);
+
+			Self::deposit_event(Event::PendingOwnerChanged(id, None));
+			Ok(())
+		}
+
+		/// Permanently give up the Owner role of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// The Owner is set to the default account, which is not expected to sign for anything, so
+		/// the asset can no longer be transferred to a new Owner, have its team changed or be
+		/// destroyed other than by `ForceOrigin`. The deposits of the asset and its metadata are
+		/// returned to the account they were reserved from, and any pending ownership proposal is
+		/// cleared.
+		///
+		/// - `id`: The identifier of the asset.
+		///
+		/// Emits `OwnershipRenounced`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::renounce_ownership())]
+		pub(super) fn renounce_ownership(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T, I>::try_mutate(id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
+				ensure!(&origin == &details.owner, Error::<T, I>::NoPermission);
+
+				let mut deposit = sp_std::mem::take(&mut details.deposit);
+				if Metadata::<T, I>::contains_key(id) {
+					Metadata::<T, I>::mutate(id, |metadata| {
+						deposit = deposit.saturating_add(sp_std::mem::take(&mut metadata.deposit));
+					});
+				}
+				T::Currency::unreserve(&details.depositor, deposit);
+
+				details.owner = Default::default();
+				PendingOwner::<T, I>::remove(id);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::OwnershipRenounced(id));
+			Ok(())
+		}
+
 		/// Change the Issuer, Admin and Freezer of an asset.
 		///
 		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
@@ -862,18 +2404,258 @@ pub mod pallet {
 			let issuer = T::Lookup::lookup(issuer)?;
 			let admin = T::Lookup::lookup(admin)?;
 			let freezer = T::Lookup::lookup(freezer)?;
+			Self::do_set_team(id, origin, Some(issuer), Some(admin), Some(freezer))
+		}
 
-			Asset::<T, I>::try_mutate(id, |maybe_details| {
-				let details = maybe_details.as_mut().ok_or(Error::<T, I>::Unknown)?;
-				ensure!(&origin == &details.owner, Error::<T, I>::NoPermission);
+		/// Change the Issuer of an asset, leaving its Admin and Freezer unchanged.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `issuer`: The new Issuer of this asset.
+		///
+		/// Emits `TeamChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_team())]
+		pub(super) fn set_issuer(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			issuer: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let issuer = T::Lookup::lookup(issuer)?;
+			Self::do_set_team(id, origin, Some(issuer), None, None)
+		}
+
+		/// Change the Admin of an asset, leaving its Issuer and Freezer unchanged.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `admin`: The new Admin of this asset.
+		///
+		/// Emits `TeamChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_team())]
+		pub(super) fn set_admin(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			admin: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let admin = T::Lookup::lookup(admin)?;
+			Self::do_set_team(id, origin, None, Some(admin), None)
+		}
+
+		/// Change the Freezer of an asset, leaving its Issuer and Admin unchanged.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `freezer`: The new Freezer of this asset.
+		///
+		/// Emits `TeamChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_team())]
+		pub(super) fn set_freezer(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			freezer: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let freezer = T::Lookup::lookup(freezer)?;
+			Self::do_set_team(id, origin, None, None, Some(freezer))
+		}
+
+		/// Change the maximum supply of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `max_supply`: The new maximum total supply of this asset, or `None` for no limit. This
+		/// must be no less than the current total supply.
+		///
+		/// Emits `CapChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_supply_cap())]
+		pub(super) fn set_supply_cap(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			max_supply: Option<T::Balance>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_supply_cap(id, max_supply, Some(origin))
+		}
+
+		/// Change the maximum supply of an asset.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `max_supply`: The new maximum total supply of this asset, or `None` for no limit. This
+		/// may be less than the current total supply, which the Admin may then burn down to it with
+		/// `burn_excess_above_cap`.
+		///
+		/// Emits `CapChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_set_supply_cap())]
+		pub(super) fn force_set_supply_cap(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			max_supply: Option<T::Balance>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_set_supply_cap(id, max_supply, None)
+		}
+
+		/// Burn the supply of an asset in excess of its maximum supply from the sender's account.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`, whose
+		/// supply must exceed its maximum supply, as may happen after `force_set_supply_cap`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `best_effort`: Whether to burn as much as the sender holds if that is less than the
+		/// excess, rather than failing with `BalanceLow`.
+		///
+		/// Emits `SupplyCapEnforced` with the amount burned.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::burn_excess_above_cap())]
+		pub(super) fn burn_excess_above_cap(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			best_effort: bool,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_burn_excess_above_cap(id, origin, best_effort).map(|_| ())
+		}
+
+		/// Change the minimum balance of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `min_balance`: The new minimum balance of this asset. This must be non-zero and, if
+		/// it is being raised, no greater than the balance of any account holding the asset.
+		/// - `accounts_witness`: An upper bound on the number of accounts of the asset, as given by
+		/// `AssetDetails::accounts`. It is only checked when raising the minimum balance, as every
+		/// account must then be inspected.
+		///
+		/// Emits `MinBalanceChanged`.
+		///
+		/// Weight: `O(A)` where `A = accounts_witness`. Only `O(1)` is charged when lowering the
+		/// minimum balance, and any weight of an over-estimated witness is refunded.
+		#[pallet::weight(T::WeightInfo::set_min_balance(*accounts_witness))]
+		pub(super) fn set_min_balance(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			#[pallet::compact] min_balance: T::Balance,
+			#[pallet::compact] accounts_witness: u32,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let inspected =
+				Self::do_set_min_balance(id, min_balance, accounts_witness, Some(origin))?;
+			Ok(Some(T::WeightInfo::set_min_balance(inspected)).into())
+		}
+
+		/// Change the transfer fee of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `rate`: The proportion of each transfer to be paid as a fee, in the same asset. A zero
+		/// rate removes the fee.
+		/// - `beneficiary`: The account which receives the fees. Fees are only paid when they can
+		/// be credited to this account, so it should already hold the asset.
+		///
+		/// Emits `TransferFeeChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_transfer_fee())]
+		pub(super) fn set_transfer_fee(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			rate: Perbill,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			Self::do_set_transfer_fee(id, rate, beneficiary, Some(origin))
+		}
+
+		/// Change the transfer rate limit of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// Once set, transfers of the asset fail while they would take the volume transferred
+		/// within the current window above `max_volume`. A window starts with the first transfer
+		/// after the previous one has ended. Changing the limit starts a new window.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `max_volume`: The maximum volume which may be transferred within a window.
+		/// - `window_blocks`: The length of a window in blocks. Zero removes the limit.
+		///
+		/// Emits `TransferRateLimitChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_transfer_rate_limit())]
+		pub(super) fn set_transfer_rate_limit(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			#[pallet::compact] max_volume: T::Balance,
+			window_blocks: T::BlockNumber,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(origin == details.owner, Error::<T, I>::NoPermission);
+
+			if window_blocks.is_zero() {
+				TransferRateLimitConfig::<T, I>::remove(id);
+			} else {
+				TransferRateLimitConfig::<T, I>::insert(id, (max_volume, window_blocks));
+			}
+			TransferVolume::<T, I>::remove(id);
+
+			Self::deposit_event(Event::TransferRateLimitChanged(id, max_volume, window_blocks));
+			Ok(())
+		}
+
+		/// Change the transfer guard account of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// The runtime's `TransferGuard` may use this account, for example to select the oracle
+		/// which approves transfers of the asset.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `guard`: The new guard account of the asset, or `None` to remove it.
+		///
+		/// Emits `TransferGuardChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_transfer_guard())]
+		pub(super) fn set_transfer_guard(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			guard: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let details = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(origin == details.owner, Error::<T, I>::NoPermission);
 
-				details.issuer = issuer.clone();
-				details.admin = admin.clone();
-				details.freezer = freezer.clone();
+			let guard = guard.map(T::Lookup::lookup).transpose()?;
+			match guard {
+				Some(ref guard) => TransferGuardAccount::<T, I>::insert(id, guard),
+				None => TransferGuardAccount::<T, I>::remove(id),
+			}
 
-				Self::deposit_event(Event::TeamChanged(id, issuer, admin, freezer));
-				Ok(())
-			})
+			Self::deposit_event(Event::TransferGuardChanged(id, guard));
+			Ok(())
 		}
 
 		/// Set the metadata for an asset.
@@ -881,8 +2663,8 @@ pub mod pallet {
 		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
 		///
 		/// Funds of sender are reserved according to the formula:
-		/// `MetadataDepositBase + MetadataDepositPerByte * (name.len + symbol.len)` taking into
-		/// account any already reserved funds.
+		/// `MetadataDepositBase + MetadataDepositPerByte * (name.len + symbol.len + uri.len)` taking
+		/// into account any already reserved funds. Any URI set with `set_metadata_url` is kept.
 		///
 		/// - `id`: The identifier of the asset to update.
 		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
@@ -901,41 +2683,64 @@ pub mod pallet {
 			decimals: u8,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
+			Self::do_set_metadata(id, origin, name, symbol, decimals, None)
+		}
 
-			ensure!(name.len() <= T::StringLimit::get() as usize, Error::<T, I>::BadMetadata);
-			ensure!(symbol.len() <= T::StringLimit::get() as usize, Error::<T, I>::BadMetadata);
-
-			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
-			ensure!(&origin == &d.owner, Error::<T, I>::NoPermission);
-
-			Metadata::<T, I>::try_mutate_exists(id, |metadata| {
-				ensure!(
-					metadata.as_ref().map_or(true, |m| !m.is_frozen),
-					Error::<T, I>::NoPermission
-				);
-
-				let old_deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
-				let new_deposit = T::MetadataDepositPerByte::get()
-					.saturating_mul(((name.len() + symbol.len()) as u32).into())
-					.saturating_add(T::MetadataDepositBase::get());
-
-				if new_deposit > old_deposit {
-					T::Currency::reserve(&origin, new_deposit - old_deposit)?;
-				} else {
-					T::Currency::unreserve(&origin, old_deposit - new_deposit);
-				}
-
-				*metadata = Some(AssetMetadata {
-					deposit: new_deposit,
-					name: name.clone(),
-					symbol: symbol.clone(),
-					decimals,
-					is_frozen: false,
-				});
+		/// Set the metadata for an asset, together with a hash identifying its icon.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// Funds of sender are reserved as for `set_metadata`.
+		///
+		/// - `id`: The identifier of the asset to update.
+		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
+		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
+		/// - `decimals`: The number of decimals this asset uses to represent one unit.
+		/// - `image_hash`: A hash identifying the icon of this asset off-chain, such as the SHA-256
+		/// of its IPFS CID. It is not validated.
+		///
+		/// Emits `MetadataSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_metadata_extended(name.len() as u32, symbol.len() as u32))]
+		pub(super) fn set_metadata_extended(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			image_hash: Option<[u8; 32]>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_metadata(id, origin, name, symbol, decimals, image_hash)
+		}
 
-				Self::deposit_event(Event::MetadataSet(id, name, symbol, decimals, false));
-				Ok(())
-			})
+		/// Set or clear the URI of richer metadata for an asset, leaving the rest of its metadata
+		/// unchanged.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`, which must
+		/// already have metadata that is not frozen.
+		///
+		/// Funds of sender are reserved for the URI at `MetadataDepositPerByte * uri.len`, taking
+		/// into account any already reserved for a previous URI. Clearing the URI frees them.
+		///
+		/// - `id`: The identifier of the asset to update.
+		/// - `uri`: The URI locating the asset's richer metadata off-chain, such as a JSON file with
+		/// its icon and description, or `None` to clear it. Limited in length by `UriLimit`.
+		///
+		/// Emits `MetadataSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(
+			T::WeightInfo::set_metadata_url(uri.as_ref().map_or(0, |uri| uri.len() as u32))
+		)]
+		pub(super) fn set_metadata_url(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			uri: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_metadata_url(id, origin, uri)
 		}
 
 		/// Clear the metadata for an asset.
@@ -960,9 +2765,9 @@ pub mod pallet {
 			ensure!(&origin == &d.owner, Error::<T, I>::NoPermission);
 
 			Metadata::<T, I>::try_mutate_exists(id, |metadata| {
-				let deposit = metadata.take().ok_or(Error::<T, I>::Unknown)?.deposit;
-				T::Currency::unreserve(&d.owner, deposit);
-				Self::deposit_event(Event::MetadataCleared(id));
+				let old = metadata.take().ok_or(Error::<T, I>::Unknown)?;
+				T::Currency::unreserve(&d.depositor, old.deposit);
+				Self::deposit_event(Event::MetadataCleared(id, old.name.into_inner()));
 				Ok(())
 			})
 		}
@@ -992,21 +2797,28 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::ForceOrigin::ensure_origin(origin)?;
 
-			ensure!(name.len() <= T::StringLimit::get() as usize, Error::<T, I>::BadMetadata);
-			ensure!(symbol.len() <= T::StringLimit::get() as usize, Error::<T, I>::BadMetadata);
+			let bounded_name: BoundedVec<u8, T::StringLimit> = name.clone().try_into()
+				.map_err(|_| Error::<T, I>::BadMetadata)?;
+			let bounded_symbol: BoundedVec<u8, T::StringLimit> = symbol.clone().try_into()
+				.map_err(|_| Error::<T, I>::BadMetadata)?;
 
 			ensure!(Asset::<T, I>::contains_key(id), Error::<T, I>::Unknown);
 			Metadata::<T, I>::try_mutate_exists(id, |metadata| {
-				let deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
+				let (deposit, uri) = metadata.take()
+					.map_or((Zero::zero(), None), |m| (m.deposit, m.uri));
 				*metadata = Some(AssetMetadata {
 					deposit,
-					name: name.clone(),
-					symbol: symbol.clone(),
+					name: bounded_name,
+					symbol: bounded_symbol,
 					decimals,
 					is_frozen,
+					image_hash: None,
+					uri: uri.clone(),
 				});
 
-				Self::deposit_event(Event::MetadataSet(id, name, symbol, decimals, is_frozen));
+				let uri = uri.map(|uri| uri.into_inner());
+				let event = Event::MetadataSet(id, name, symbol, decimals, is_frozen, None, uri);
+				Self::deposit_event(event);
 				Ok(())
 			})
 		}
@@ -1031,13 +2843,66 @@ pub mod pallet {
 
 			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
 			Metadata::<T, I>::try_mutate_exists(id, |metadata| {
-				let deposit = metadata.take().ok_or(Error::<T, I>::Unknown)?.deposit;
-				T::Currency::unreserve(&d.owner, deposit);
-				Self::deposit_event(Event::MetadataCleared(id));
+				let old = metadata.take().ok_or(Error::<T, I>::Unknown)?;
+				T::Currency::unreserve(&d.depositor, old.deposit);
+				Self::deposit_event(Event::MetadataCleared(id, old.name.into_inner()));
 				Ok(())
 			})
 		}
 
+		/// Freeze the metadata of an asset, so that it may only be changed by `ForceOrigin`.
+		///
+		/// Origin must be either `ForceOrigin` or Signed by the Owner of the asset `id`. Only the
+		/// `is_frozen` flag of the metadata is changed.
+		///
+		/// - `id`: The identifier of the asset whose metadata should be frozen.
+		///
+		/// Emits `MetadataFrozen`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::freeze_metadata())]
+		pub(super) fn freeze_metadata(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResult {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(d.owner == check_owner, Error::<T, I>::NoPermission);
+			}
+			Self::set_metadata_frozen(id, true)?;
+
+			Self::deposit_event(Event::MetadataFrozen(id));
+			Ok(())
+		}
+
+		/// Thaw the metadata of an asset, so that it may be changed by its Owner again.
+		///
+		/// Origin must be ForceOrigin. Only the `is_frozen` flag of the metadata is changed.
+		///
+		/// - `id`: The identifier of the asset whose metadata should be thawed.
+		///
+		/// Emits `MetadataThawed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::thaw_metadata())]
+		pub(super) fn thaw_metadata(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(Asset::<T, I>::contains_key(id), Error::<T, I>::Unknown);
+			Self::set_metadata_frozen(id, false)?;
+
+			Self::deposit_event(Event::MetadataThawed(id));
+			Ok(())
+		}
+
 		/// Alter the attributes of a given asset.
 		///
 		/// Origin must be `ForceOrigin`.
@@ -1056,6 +2921,8 @@ pub mod pallet {
 		/// growth).
 		/// - `is_frozen`: Whether this asset class is frozen except for permissioned/admin
 		/// instructions.
+		/// - `max_supply`: The maximum total supply of this asset, if any.
+		/// - `disable_mint`: Whether minting of this asset is paused, leaving transfers unaffected.
 		///
 		/// Emits `AssetStatusChanged` with the identity of the asset.
 		///
@@ -1071,6 +2938,8 @@ pub mod pallet {
 			#[pallet::compact] min_balance: T::Balance,
 			is_sufficient: bool,
 			is_frozen: bool,
+			max_supply: Option<T::Balance>,
+			disable_mint: bool,
 		) -> DispatchResult {
 			T::ForceOrigin::ensure_origin(origin)?;
 
@@ -1083,6 +2952,8 @@ pub mod pallet {
 				asset.min_balance = min_balance;
 				asset.is_sufficient = is_sufficient;
 				asset.is_frozen = is_frozen;
+				asset.max_supply = max_supply;
+				asset.disable_mint = disable_mint;
 				*maybe_asset = Some(asset);
 
 				Self::deposit_event(Event::AssetStatusChanged(id));
@@ -1090,6 +2961,34 @@ pub mod pallet {
 			})
 		}
 
+		/// Move an asset class and all of its state to a new identifier.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// The details, accounts, approvals and metadata of the asset are moved from `old_id` to
+		/// `new_id`, with all balances preserved. No open swap proposal or scheduled transfer may
+		/// refer to the asset. Any state kept under `old_id` by the `Freezer` or
+		/// `OnTransfer` hooks is not migrated.
+		///
+		/// - `old_id`: The current identifier of the asset.
+		/// - `new_id`: The new identifier of the asset. This must not be currently in use to
+		/// identify an existing asset.
+		///
+		/// Emits `AssetIdMigrated`.
+		///
+		/// Weight: `O(A + P)` where `A` is the number of accounts and `P` the number of approvals
+		/// of the asset. The whole block is reserved for the call, with the remainder refunded.
+		#[pallet::weight((T::BlockWeights::get().max_block, DispatchClass::Operational))]
+		pub(super) fn migrate_asset_id(
+			origin: OriginFor<T>,
+			#[pallet::compact] old_id: T::AssetId,
+			#[pallet::compact] new_id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let (accounts, approvals) = Self::do_migrate_asset_id(old_id, new_id)?;
+			Ok(Some(T::WeightInfo::migrate_asset_id(accounts, approvals)).into())
+		}
+
 		/// Approve an amount of asset for transfer by a delegated third-party account.
 		///
 		/// Origin must be Signed.
@@ -1119,8 +3018,16 @@ pub mod pallet {
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
 			let delegate = T::Lookup::lookup(delegate)?;
+			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(d.status != AssetStatus::Retiring, Error::<T, I>::AssetRetired);
+			ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 
 			Approvals::<T, I>::try_mutate((id, &owner, &delegate), |maybe_approved| -> DispatchResult {
+				let is_new = maybe_approved.is_none();
+				ensure!(
+					!is_new || d.approvals < T::MaxApprovalsPerAsset::get(),
+					Error::<T, I>::TooManyApprovals,
+				);
 				let mut approved = maybe_approved.take().unwrap_or_default();
 				let deposit_required = T::ApprovalDeposit::get();
 				if approved.deposit < deposit_required {
@@ -1129,8 +3036,77 @@ pub mod pallet {
 				}
 				approved.amount = approved.amount.saturating_add(amount);
 				*maybe_approved = Some(approved);
+				if is_new {
+					Self::inc_approvals(id);
+				}
+				Ok(())
+			})?;
+			Self::schedule_approval_expiry(id, &owner, &delegate);
+			Self::deposit_event(Event::ApprovedTransfer(id, owner, delegate, amount));
+
+			Ok(())
+		}
+
+		/// Set the amount of asset approved for transfer by a delegated third-party account.
+		///
+		/// Origin must be Signed.
+		///
+		/// Unlike `approve_transfer`, this replaces rather than adds to any amount already approved
+		/// from the signing account to `delegate`. Ensures that `ApprovalDeposit` worth of
+		/// `Currency` is reserved from signing account for the purpose of holding the approval.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account to delegate permission to transfer asset.
+		/// - `amount`: The amount of asset that may be transferred by `delegate`. If zero, then the
+		/// approval is cancelled, as with `cancel_approval`.
+		///
+		/// Emits `ApprovedTransfer` on success, or `ApprovalCancelled` if `amount` is zero.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::approve_transfer_exact())]
+		pub(super) fn approve_transfer_exact(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(d.status != AssetStatus::Retiring, Error::<T, I>::AssetRetired);
+			ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+
+			if amount.is_zero() {
+				let approval = Approvals::<T, I>::take((id, &owner, &delegate))
+					.ok_or(Error::<T, I>::Unknown)?;
+				T::Currency::unreserve(&owner, approval.deposit);
+				Self::clear_approval_expiry(id, &owner, &delegate);
+				Self::dec_approvals(id);
+
+				Self::deposit_event(Event::ApprovalCancelled(id, owner, delegate));
+				return Ok(())
+			}
+
+			Approvals::<T, I>::try_mutate((id, &owner, &delegate), |maybe_approved| -> DispatchResult {
+				let is_new = maybe_approved.is_none();
+				ensure!(
+					!is_new || d.approvals < T::MaxApprovalsPerAsset::get(),
+					Error::<T, I>::TooManyApprovals,
+				);
+				let mut approved = maybe_approved.take().unwrap_or_default();
+				let deposit_required = T::ApprovalDeposit::get();
+				if approved.deposit < deposit_required {
+					T::Currency::reserve(&owner, deposit_required - approved.deposit)?;
+					approved.deposit = deposit_required;
+				}
+				approved.amount = amount;
+				*maybe_approved = Some(approved);
+				if is_new {
+					Self::inc_approvals(id);
+				}
 				Ok(())
 			})?;
+			Self::schedule_approval_expiry(id, &owner, &delegate);
 			Self::deposit_event(Event::ApprovedTransfer(id, owner, delegate, amount));
 
 			Ok(())
@@ -1159,11 +3135,35 @@ pub mod pallet {
 			let delegate = T::Lookup::lookup(delegate)?;
 			let approval = Approvals::<T, I>::take((id, &owner, &delegate)).ok_or(Error::<T, I>::Unknown)?;
 			T::Currency::unreserve(&owner, approval.deposit);
+			Self::clear_approval_expiry(id, &owner, &delegate);
+			Self::dec_approvals(id);
 
 			Self::deposit_event(Event::ApprovalCancelled(id, owner, delegate));
 			Ok(())
 		}
 
+		/// Cancel the approvals of some asset which the sender has granted for delegated
+		/// transfers, up to `MaxRevocationBatch` of them.
+		///
+		/// Origin must be Signed. Call again while approvals remain.
+		///
+		/// Unreserves any deposits previously reserved by `approve_transfer` for the approvals.
+		///
+		/// - `id`: The identifier of the asset.
+		///
+		/// Emits `ApprovalsRevoked` with the number of approvals cancelled.
+		///
+		/// Weight: `O(K)` where `K` is the number of approvals cancelled.
+		#[pallet::weight(T::WeightInfo::revoke_all_approvals(T::MaxRevocationBatch::get()))]
+		pub(super) fn revoke_all_approvals(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let revoked = Self::do_revoke_all_approvals(id, owner, T::MaxRevocationBatch::get());
+			Ok(Some(T::WeightInfo::revoke_all_approvals(revoked)).into())
+		}
+
 		/// Cancel all of some asset approved for delegated transfer by a third-party account.
 		///
 		/// Origin must be either ForceOrigin or Signed origin with the signer being the Admin
@@ -1198,6 +3198,8 @@ pub mod pallet {
 
 			let approval = Approvals::<T, I>::take((id, &owner, &delegate)).ok_or(Error::<T, I>::Unknown)?;
 			T::Currency::unreserve(&owner, approval.deposit);
+			Self::clear_approval_expiry(id, &owner, &delegate);
+			Self::dec_approvals(id);
 
 			Self::deposit_event(Event::ApprovalCancelled(id, owner, delegate));
 			Ok(())
@@ -1233,29 +3235,217 @@ pub mod pallet {
 			let owner = T::Lookup::lookup(owner)?;
 			let destination = T::Lookup::lookup(destination)?;
 
-			Approvals::<T, I>::try_mutate_exists((id, &owner, delegate), |maybe_approved| -> DispatchResult {
-				let mut approved = maybe_approved.take().ok_or(Error::<T, I>::Unapproved)?;
-				let remaining = approved
-					.amount
-					.checked_sub(&amount)
-					.ok_or(Error::<T, I>::Unapproved)?;
-
-				let f = TransferFlags {
-					keep_alive: false,
-					best_effort: false,
-					burn_dust: false
-				};
-				Self::do_transfer(id, &owner, &destination, amount, None, f)?;
-
-				if remaining.is_zero() {
-					T::Currency::unreserve(&owner, approved.deposit);
-				} else {
-					approved.amount = remaining;
-					*maybe_approved = Some(approved);
-				}
-				Ok(())
-			})?;
-			Ok(())
+			Self::do_transfer_approved(id, &owner, &delegate, &destination, amount, false)
+		}
+
+		/// Transfer some asset balance from a previously delegated account to some third-party
+		/// account, keeping the delegating account alive.
+		///
+		/// Origin must be Signed and there must be an approval in place by the `owner` to the
+		/// signer.
+		///
+		/// If the entire amount approved for transfer is transferred, then any deposit previously
+		/// reserved by `approve_transfer` is unreserved.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which previously approved for a transfer of at least `amount` and
+		/// from which the asset balance will be withdrawn. Its balance remains at least the
+		/// minimum balance of the asset.
+		/// - `destination`: The account to which the asset balance will be transferred.
+		/// - `amount`: The most assets to transfer. Less is transferred, and deducted from the
+		/// approval, if the `owner` could not otherwise be kept alive; if nothing could be, the
+		/// call fails with `BalanceLow`.
+		///
+		/// Emits `Transferred` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_approved_keep_alive())]
+		pub(super) fn transfer_approved_keep_alive(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			destination: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+		) -> DispatchResult {
+			let delegate = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let destination = T::Lookup::lookup(destination)?;
+
+			Self::do_transfer_approved(id, &owner, &delegate, &destination, amount, true)
+		}
+
+		/// Propose to swap some of one asset for some of another.
+		///
+		/// Origin must be Signed and the sender must have at least `offer_amount` of
+		/// `offer_asset` transferable while staying alive. That amount is held in the sender's
+		/// account until the proposal is accepted or cancelled.
+		///
+		/// - `offer_asset`: The identifier of the asset offered.
+		/// - `offer_amount`: The amount of `offer_asset` offered.
+		/// - `want_asset`: The identifier of the asset wanted in return. This must not be being
+		/// destroyed.
+		/// - `want_amount`: The amount of `want_asset` wanted in return.
+		/// - `expiry`: The last block in which the proposal may be accepted.
+		/// - `dest`: The account which receives the wanted asset, defaulting to the sender.
+		///
+		/// Emits `SwapProposed` with the identifier of the new proposal.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::propose_swap())]
+		pub(super) fn propose_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] offer_asset: T::AssetId,
+			#[pallet::compact] offer_amount: T::Balance,
+			#[pallet::compact] want_asset: T::AssetId,
+			#[pallet::compact] want_amount: T::Balance,
+			expiry: T::BlockNumber,
+			dest: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			let dest = match dest {
+				Some(dest) => T::Lookup::lookup(dest)?,
+				None => proposer.clone(),
+			};
+			let proposal = SwapProposal {
+				proposer,
+				offer_asset,
+				offer_amount,
+				want_asset,
+				want_amount,
+				dest,
+				expiry,
+			};
+			Self::do_propose_swap(proposal)
+		}
+
+		/// Accept a swap proposal, transferring the wanted asset from the sender to the
+		/// proposal's destination and the offered asset from the proposer to the sender.
+		///
+		/// Origin must be Signed and the sender must have at least the wanted amount of the
+		/// wanted asset. Either both transfers are made or neither is.
+		///
+		/// - `swap_id`: The identifier of the proposal, which must not have expired.
+		///
+		/// Emits `SwapAccepted`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::accept_swap())]
+		pub(super) fn accept_swap(origin: OriginFor<T>, swap_id: SwapId) -> DispatchResult {
+			let acceptor = ensure_signed(origin)?;
+			Self::do_accept_swap(swap_id, acceptor)
+		}
+
+		/// Cancel a swap proposal, releasing its offered funds.
+		///
+		/// Origin must be Signed and the sender must be the proposer, unless the proposal has
+		/// expired, in which case any account may cancel it.
+		///
+		/// - `swap_id`: The identifier of the proposal.
+		///
+		/// Emits `SwapCancelled`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_swap())]
+		pub(super) fn cancel_swap(origin: OriginFor<T>, swap_id: SwapId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_swap(swap_id, origin)
+		}
+
+		/// Schedule a transfer of some asset balance to another account in a future block.
+		///
+		/// Origin must be Signed and the sender must have at least `amount` of the asset `id`
+		/// transferable while staying alive. That amount is held in the sender's account until
+		/// the transfer is executed or cancelled.
+		///
+		/// If the transfer cannot be made in block `execute_at`, for example because `dest` could
+		/// no longer receive it, then the held amount is released back to the sender.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `dest`: The account to be credited.
+		/// - `amount`: The amount by which the sender's balance of assets should be reduced and
+		/// `dest`'s balance increased.
+		/// - `execute_at`: The block in which the transfer is to be made. This must be in the
+		/// future and have fewer than `MaxScheduledPerBlock` transfers already scheduled.
+		///
+		/// Emits `TransferScheduled` with the identifier of the new schedule.
+		///
+		/// Weight: `O(S)` where `S` is `MaxScheduledPerBlock`.
+		#[pallet::weight(T::WeightInfo::schedule_transfer())]
+		pub(super) fn schedule_transfer(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			dest: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] amount: T::Balance,
+			execute_at: T::BlockNumber,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+
+			let record = ScheduledTransferRecord { id, source, dest, amount };
+			Self::do_schedule_transfer(record, execute_at)
+		}
+
+		/// Cancel a pending scheduled transfer, releasing its held amount.
+		///
+		/// Origin must be Signed and the sender must be the source of the scheduled transfer.
+		///
+		/// - `schedule_id`: The identifier of the scheduled transfer.
+		///
+		/// Emits `ScheduledTransferCancelled`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_scheduled_transfer())]
+		pub(super) fn cancel_scheduled_transfer(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_scheduled_transfer(schedule_id, origin)
+		}
+
+		/// Create an asset account for the sender, placing a deposit.
+		///
+		/// Origin must be Signed.
+		///
+		/// A deposit of `AccountDeposit` is reserved from the sender. The account is given a
+		/// self-sufficient reference and so needs no provider reference to hold the asset `id`,
+		/// even if the asset is not sufficient. It is not removed when its balance reaches zero.
+		///
+		/// - `id`: The identifier of the asset for which the account should be created.
+		///
+		/// Emits `Touched`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::touch())]
+		pub(super) fn touch(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_touch(id, who)
+		}
+
+		/// Remove the sender's asset account, returning the deposit placed by `touch`.
+		///
+		/// Origin must be Signed and there must be an account for the sender which was created
+		/// with `touch`.
+		///
+		/// - `id`: The identifier of the asset for which the account should be removed.
+		/// - `allow_death`: If `true`, then any remaining balance of the account is burned, which
+		/// fails if any of it is frozen or held. If `false`, then the call fails with
+		/// `BalanceZero` unless the balance is already zero.
+		///
+		/// Emits `Refunded`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::refund())]
+		pub(super) fn refund(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: T::AssetId,
+			allow_death: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_refund(id, who, allow_death)
 		}
 	}
 }
@@ -0,0 +1,243 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2017-2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the assets pallet.
+
+use super::*;
+use frame_support::{
+	storage::migration::{put_storage_value, storage_iter},
+	traits::PalletInfoAccess,
+	weights::Weight,
+};
+
+/// The details of an asset as stored before `sufficients` replaced `max_zombies`.
+#[derive(Decode)]
+struct OldAssetDetails<Balance, AccountId, DepositBalance> {
+	owner: AccountId,
+	issuer: AccountId,
+	admin: AccountId,
+	freezer: AccountId,
+	supply: Balance,
+	deposit: DepositBalance,
+	#[allow(dead_code)]
+	max_zombies: u32,
+	min_balance: Balance,
+	#[allow(dead_code)]
+	zombies: u32,
+	accounts: u32,
+	is_frozen: bool,
+}
+
+/// The details of an asset as stored in storage version `V1`.
+#[derive(Encode, Decode)]
+struct V1AssetDetails<Balance, AccountId, DepositBalance> {
+	owner: AccountId,
+	issuer: AccountId,
+	admin: AccountId,
+	freezer: AccountId,
+	supply: Balance,
+	deposit: DepositBalance,
+	min_balance: Balance,
+	is_sufficient: bool,
+	accounts: u32,
+	sufficients: u32,
+	approvals: u32,
+	is_frozen: bool,
+}
+
+/// The balance of an account as stored before `sufficients` replaced `max_zombies`.
+#[derive(Decode)]
+struct OldAssetBalance<Balance> {
+	balance: Balance,
+	is_frozen: bool,
+	#[allow(dead_code)]
+	is_zombie: bool,
+}
+
+/// The balance of an account as stored in storage version `V1`.
+#[derive(Encode, Decode)]
+struct V1AssetBalance<Balance, Extra> {
+	balance: Balance,
+	is_frozen: bool,
+	sufficient: bool,
+	extra: Extra,
+}
+
+/// The metadata of an asset as stored in storage version `V1`.
+#[derive(Decode)]
+struct V1AssetMetadata<DepositBalance> {
+	deposit: DepositBalance,
+	name: Vec<u8>,
+	symbol: Vec<u8>,
+	decimals: u8,
+	is_frozen: bool,
+}
+
+/// Truncate `s` to at most `StringLimit` bytes.
+fn bound_string<T: Config<I>, I: 'static>(
+	id: &T::AssetId,
+	mut s: Vec<u8>,
+) -> BoundedVec<u8, T::StringLimit> {
+	let limit = T::StringLimit::get() as usize;
+	if s.len() > limit {
+		log::warn!(
+			target: "runtime::assets",
+			"Truncating over-long metadata of asset {:?} to {} bytes.",
+			id,
+			limit,
+		);
+		s.truncate(limit);
+	}
+	s.try_into().unwrap_or_default()
+}
+
+/// Migrate the storage of the pallet from the layout of storage version `V1` to that of `V2`.
+///
+/// - Every `Asset` is given no `max_supply`, its Owner as `depositor`, a `Live` status, no
+///   `transfer_fee`, minting enabled and an `owner_nonce` of zero.
+/// - Every `Account` is given no `reserved` balance and no `deposit`, and is indexed in
+///   `AccountAssets`.
+/// - Every `Metadata` has its `name` and `symbol` bounded by `StringLimit`, truncating any
+///   which exceed it, and is given no `image_hash` or `uri`.
+///
+/// Does nothing unless the storage version is `V1`.
+pub fn migrate_to_v2<T: Config<I>, I: 'static>() -> Weight {
+	if StorageVersion::<T, I>::get() != Releases::V1 {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut assets: Weight = 0;
+	Asset::<T, I>::translate::<V1AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>, _>(
+		|_, old| {
+			assets = assets.saturating_add(1);
+			Some(AssetDetails {
+				owner: old.owner.clone(),
+				issuer: old.issuer,
+				admin: old.admin,
+				freezer: old.freezer,
+				supply: old.supply,
+				max_supply: None,
+				deposit: old.deposit,
+				depositor: old.owner,
+				min_balance: old.min_balance,
+				is_sufficient: old.is_sufficient,
+				accounts: old.accounts,
+				sufficients: old.sufficients,
+				approvals: old.approvals,
+				is_frozen: old.is_frozen,
+				status: AssetStatus::Live,
+				transfer_fee: None,
+				disable_mint: false,
+				owner_nonce: 0,
+			})
+		},
+	);
+
+	let mut accounts: Weight = 0;
+	Account::<T, I>::translate::<V1AssetBalance<T::Balance, T::Extra>, _>(|id, who, old| {
+		accounts = accounts.saturating_add(1);
+		AccountAssets::<T, I>::insert(&who, id, ());
+		Some(AssetBalance {
+			balance: old.balance,
+			reserved: Zero::zero(),
+			is_frozen: old.is_frozen,
+			sufficient: old.sufficient,
+			deposit: None,
+			extra: old.extra,
+		})
+	});
+
+	let mut metadata: Weight = 0;
+	Metadata::<T, I>::translate::<V1AssetMetadata<DepositBalanceOf<T, I>>, _>(|id, old| {
+		metadata = metadata.saturating_add(1);
+		Some(AssetMetadata {
+			deposit: old.deposit,
+			name: bound_string::<T, I>(&id, old.name),
+			symbol: bound_string::<T, I>(&id, old.symbol),
+			decimals: old.decimals,
+			is_frozen: old.is_frozen,
+			image_hash: None,
+			uri: None,
+		})
+	});
+	StorageVersion::<T, I>::put(Releases::V2);
+
+	let translated = assets.saturating_add(accounts).saturating_add(metadata);
+	T::DbWeight::get().reads_writes(
+		translated.saturating_add(1),
+		translated.saturating_add(accounts).saturating_add(1),
+	)
+}
+
+/// Migrate `Asset` and `Account` from the `max_zombies` layout to the `sufficients` layout.
+///
+/// The assets and accounts are migrated to the layout of storage version `V1`, so
+/// `migrate_to_v2`, as run by `on_runtime_upgrade`, must follow this one.
+///
+/// Every migrated asset is conservatively made non-sufficient, so `sufficients` is set to zero
+/// and no account is considered to hold a self-sufficient reference.
+///
+/// Chains can not be told apart from their storage version alone, since both layouts predate it;
+/// this must only be called, from the runtime's own upgrade, by chains which still carry the
+/// `max_zombies` layout. Afterwards the storage version is `V1`.
+pub fn migrate_from_max_zombies<T: Config<I>, I: 'static>() -> Weight {
+	let pallet = <Pallet<T, I> as PalletInfoAccess>::name().as_bytes();
+	let mut translated: Weight = 0;
+	let assets = storage_iter::<OldAssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>>(
+		pallet,
+		b"Asset",
+	);
+	for (key, old) in assets {
+		translated = translated.saturating_add(1);
+		put_storage_value(pallet, b"Asset", &key, V1AssetDetails {
+			owner: old.owner,
+			issuer: old.issuer,
+			admin: old.admin,
+			freezer: old.freezer,
+			supply: old.supply,
+			deposit: old.deposit,
+			min_balance: old.min_balance,
+			is_sufficient: false,
+			accounts: old.accounts,
+			sufficients: 0,
+			approvals: 0,
+			is_frozen: old.is_frozen,
+		});
+	}
+	for (key, old) in storage_iter::<OldAssetBalance<T::Balance>>(pallet, b"Account") {
+		translated = translated.saturating_add(1);
+		put_storage_value(pallet, b"Account", &key, V1AssetBalance {
+			balance: old.balance,
+			is_frozen: old.is_frozen,
+			sufficient: false,
+			extra: T::Extra::default(),
+		});
+	}
+	StorageVersion::<T, I>::put(Releases::V1);
+
+	T::DbWeight::get().reads_writes(translated, translated.saturating_add(1))
+}
+
+/// Check that the storage version was updated by `migrate_from_max_zombies`.
+///
+/// The accounts can only be checked against the assets once the migrations of
+/// `on_runtime_upgrade` have run, which `post_upgrade` does.
+#[cfg(feature = "try-runtime")]
+pub fn post_migrate_from_max_zombies<T: Config<I>, I: 'static>() -> Result<(), &'static str> {
+	ensure!(StorageVersion::<T, I>::get() == Releases::V1, "Storage version was not updated");
+	Ok(())
+}
@@ -21,7 +21,10 @@ use super::*;
 use crate as pallet_assets;
 
 use sp_core::H256;
-use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, testing::Header};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, TestSignature, UintAuthorityId},
+};
 use frame_support::{parameter_types, construct_runtime};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -35,7 +38,7 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Config<T>, Storage, Event<T>},
 	}
 );
 
@@ -85,11 +88,27 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub const AssetDeposit: u64 = 1;
 	pub const ApprovalDeposit: u64 = 1;
+	pub const AccountDeposit: u64 = 10;
 	pub const StringLimit: u32 = 50;
+	pub const UriLimit: u32 = 256;
+	pub const MemoLimit: u32 = 32;
+	pub const MaxFanOut: u32 = 5;
+	pub const MaxMintTargets: u32 = 5;
+	pub const MaxMultiTransfer: u32 = 5;
+	pub const MaxFreezeBatch: u32 = 5;
+	pub const MaxRevocationBatch: u32 = 2;
+	pub const MaxScheduledPerBlock: u32 = 2;
 	pub const MetadataDepositBase: u64 = 1;
 	pub const MetadataDepositPerByte: u64 = 1;
 }
 
+parameter_types! {
+	pub static MaxAccountsPerAsset: u32 = u32::max_value() / 2;
+	pub static MaxApprovalsPerAsset: u32 = u32::max_value() / 2;
+	pub static ApprovalExpiry: Option<u64> = None;
+	pub static AssetMemoEncoding: MemoEncoding = MemoEncoding::Utf8;
+}
+
 impl Config for Test {
 	type Event = Event;
 	type Balance = u64;
@@ -100,8 +119,25 @@ impl Config for Test {
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
+	type AccountDeposit = AccountDeposit;
 	type StringLimit = StringLimit;
+	type UriLimit = UriLimit;
+	type MemoLimit = MemoLimit;
+	type MemoEncoding = AssetMemoEncoding;
+	type MaxFanOut = MaxFanOut;
+	type MaxMintTargets = MaxMintTargets;
+	type MaxMultiTransfer = MaxMultiTransfer;
+	type MaxFreezeBatch = MaxFreezeBatch;
+	type MaxRevocationBatch = MaxRevocationBatch;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type MaxAccountsPerAsset = MaxAccountsPerAsset;
+	type MaxApprovalsPerAsset = MaxApprovalsPerAsset;
+	type ApprovalExpiry = ApprovalExpiry;
 	type Freezer = TestFreezer;
+	type OnTransfer = TestOnTransfer;
+	type TransferGuard = TestTransferGuard;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
 	type WeightInfo = ();
 	type Extra = ();
 }
@@ -116,6 +152,8 @@ pub(crate) enum Hook {
 thread_local! {
 	static FROZEN: RefCell<HashMap<(u32, u64), u64>> = RefCell::new(Default::default());
 	static HOOKS: RefCell<Vec<Hook>> = RefCell::new(Default::default());
+	static MOVEMENTS: RefCell<Vec<Movement>> = RefCell::new(Default::default());
+	static BLOCKED: RefCell<Vec<u64>> = RefCell::new(Default::default());
 }
 
 pub struct TestFreezer;
@@ -129,15 +167,54 @@ impl FrozenBalance<u32, u64, u64> for TestFreezer {
 	}
 }
 
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum Movement {
+	Transferred(u32, u64, u64, u64),
+	Minted(u32, u64, u64),
+	Burned(u32, u64, u64),
+}
+
+pub struct TestOnTransfer;
+impl OnTransfer<u32, u64, u64> for TestOnTransfer {
+	fn on_transfer(asset: u32, from: &u64, to: &u64, amount: u64) {
+		MOVEMENTS.with(|m| m.borrow_mut().push(Movement::Transferred(asset, *from, *to, amount)));
+	}
+
+	fn on_mint(asset: u32, who: &u64, amount: u64) {
+		MOVEMENTS.with(|m| m.borrow_mut().push(Movement::Minted(asset, *who, amount)));
+	}
+
+	fn on_burn(asset: u32, who: &u64, amount: u64) {
+		MOVEMENTS.with(|m| m.borrow_mut().push(Movement::Burned(asset, *who, amount)));
+	}
+}
+
+pub struct TestTransferGuard;
+impl AssetTransferGuard<u32, u64, u64> for TestTransferGuard {
+	fn allow_transfer(_: u32, from: &u64, to: &u64, _: u64) -> DispatchResult {
+		if BLOCKED.with(|b| b.borrow().iter().any(|who| who == from || who == to)) {
+			Err(DispatchError::Other("Blocked"))
+		} else {
+			Ok(())
+		}
+	}
+}
+
 pub(crate) fn set_frozen_balance(asset: u32, who: u64, amount: u64) {
 	FROZEN.with(|f| f.borrow_mut().insert((asset, who), amount));
 }
 pub(crate) fn clear_frozen_balance(asset: u32, who: u64) {
 	FROZEN.with(|f| f.borrow_mut().remove(&(asset, who)));
 }
+pub(crate) fn block_transfers(who: u64) {
+	BLOCKED.with(|b| b.borrow_mut().push(who));
+}
 pub(crate) fn hooks() -> Vec<Hook> {
 	HOOKS.with(|h| h.borrow().clone())
 }
+pub(crate) fn movements() -> Vec<Movement> {
+	MOVEMENTS.with(|m| m.borrow().clone())
+}
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
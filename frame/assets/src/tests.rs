@@ -20,13 +20,13 @@
 use super::*;
 use crate::{Error, mock::*};
 use sp_runtime::TokenError;
-use frame_support::{assert_ok, assert_noop, traits::Currency};
+use frame_support::{assert_ok, assert_noop, traits::{Currency, OnInitialize}};
 use pallet_balances::Error as BalancesError;
 
 #[test]
 fn basic_minting_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
@@ -34,10 +34,38 @@ fn basic_minting_should_work() {
 	});
 }
 
+#[test]
+fn mint_to_many_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, Some(150)));
+		let targets = |v: Vec<(u64, u64)>| -> BoundedVec<(u64, u64), MaxMintTargets> { v.try_into().unwrap() };
+
+		assert_noop!(
+			Assets::mint_to_many(Origin::signed(2), 0, targets(vec![(2, 50)])),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(
+			Assets::mint_to_many(Origin::signed(1), 0, targets(vec![(2, 100), (3, 100)])),
+			Error::<Test>::SupplyCapExceeded,
+		);
+		// A failing mint undoes those before it.
+		assert_noop!(
+			Assets::mint_to_many(Origin::signed(1), 0, targets(vec![(2, 50), (3, 5)])),
+			TokenError::BelowMinimum,
+		);
+
+		assert_ok!(Assets::mint_to_many(Origin::signed(1), 0, targets(vec![(2, 50), (3, 60), (2, 10)])));
+		assert_eq!(Assets::balance(0, 2), 60);
+		assert_eq!(Assets::balance(0, 3), 60);
+		assert_eq!(Assets::total_supply(0), 120);
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Issued(0, 2, 10)));
+	});
+}
+
 #[test]
 fn approval_lifecycle_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
@@ -50,10 +78,97 @@ fn approval_lifecycle_works() {
 	});
 }
 
+#[test]
+fn approve_transfer_exact_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 1);
+		assert_noop!(Assets::approve_transfer_exact(Origin::signed(1), 0, 2, 0), Error::<Test>::Unknown);
+
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer_exact(Origin::signed(1), 0, 2, 30));
+		assert_eq!(Approvals::<Test>::get((0, 1, 2)).map(|a| a.amount), Some(30));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_noop!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 31), Error::<Test>::Unapproved);
+
+		assert_ok!(Assets::approve_transfer_exact(Origin::signed(1), 0, 2, 0));
+		assert_eq!(Approvals::<Test>::get((0, 1, 2)), None);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+
+		assert_ok!(Assets::approve_transfer_exact(Origin::signed(1), 0, 2, 20));
+		assert_ok!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 20));
+		assert_eq!(Assets::balance(0, 3), 20);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn approval_allowance_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 1);
+		assert_eq!(Assets::approval_allowance(0, &1, &2), 0);
+
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::approval_allowance(0, &1, &2), 50);
+		assert_eq!(Assets::approval_allowance(0, &2, &1), 0);
+
+		assert_ok!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 40));
+		assert_eq!(Assets::approval_allowance(0, &1, &2), 10);
+		assert_ok!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 10));
+		assert_eq!(Assets::approval_allowance(0, &1, &2), 0);
+	});
+}
+
+#[test]
+fn paged_queries_should_work() {
+	new_test_ext().execute_with(|| {
+		assert!(Assets::asset_ids_page(None, 10).is_empty());
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, true, 1, None));
+		assert_ok!(Assets::force_create(Origin::root(), 2, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 20));
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 4, 10));
+		Balances::make_free_balance_be(&1, 10);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 30));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 40));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 1, 3, 40));
+
+		// Paging through everything yields each entry once, and only those of the asset.
+		let first = Assets::asset_ids_page(None, 2);
+		assert_eq!(first.len(), 2);
+		let rest = Assets::asset_ids_page(first.last().cloned(), 2);
+		let mut ids = [first, rest].concat();
+		ids.sort();
+		assert_eq!(ids, vec![0, 1, 2]);
+
+		let first = Assets::holders_page(0, None, 2);
+		assert_eq!(first.len(), 2);
+		let rest = Assets::holders_page(0, first.last().map(|(who, _)| *who), 2);
+		assert_eq!(rest.len(), 1);
+		let mut holders = [first, rest].concat();
+		holders.sort();
+		assert_eq!(holders, vec![(1, 100), (2, 50), (3, 20)]);
+		assert!(Assets::holders_page(2, None, 10).is_empty());
+
+		let first = Assets::approvals_page(0, None, 1);
+		assert_eq!(first.len(), 1);
+		let rest = Assets::approvals_page(0, first.last().map(|(o, d, _)| (*o, *d)), 10);
+		let mut approvals = [first, rest].concat();
+		approvals.sort();
+		assert_eq!(approvals, vec![(1, 2, 30), (1, 3, 40)]);
+		assert!(Assets::approvals_page(0, None, 0).is_empty());
+	});
+}
+
 #[test]
 fn approval_deposits_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		let e = BalancesError::<Test>::InsufficientBalance;
 		assert_noop!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50), e);
@@ -71,10 +186,50 @@ fn approval_deposits_work() {
 	});
 }
 
+#[test]
+fn max_approvals_per_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		MaxApprovalsPerAsset::set(2);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 10);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::approve_transfer_exact(Origin::signed(1), 0, 3, 10));
+		assert_noop!(Assets::approve_transfer(Origin::signed(1), 0, 4, 10), Error::<Test>::TooManyApprovals);
+		assert_noop!(
+			Assets::approve_transfer_exact(Origin::signed(1), 0, 4, 10),
+			Error::<Test>::TooManyApprovals,
+		);
+		// Existing approvals may still be changed.
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::cancel_approval(Origin::signed(1), 0, 3));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 4, 10));
+	});
+}
+
+#[test]
+fn max_accounts_per_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		MaxAccountsPerAsset::set(2);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+		assert!(matches!(Assets::can_increase(0, &3, 10), DepositConsequence::Overflow));
+		assert!(matches!(Assets::can_increase(0, &2, 10), DepositConsequence::Success));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 3, 10), ArithmeticError::Overflow);
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 3, 10), ArithmeticError::Overflow);
+		Balances::make_free_balance_be(&3, 100);
+		assert_noop!(Assets::touch(Origin::signed(3), 0), Error::<Test>::TooManyAccounts);
+		// Once an account is reaped, another may be created.
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 1, 10));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 3, 10));
+	});
+}
+
 #[test]
 fn cannot_transfer_more_than_approved() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
@@ -83,10 +238,37 @@ fn cannot_transfer_more_than_approved() {
 	});
 }
 
+#[test]
+fn transfer_approved_keep_alive_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 1);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 100));
+
+		assert_noop!(
+			Assets::transfer_approved_keep_alive(Origin::signed(2), 0, 1, 3, 101),
+			Error::<Test>::Unapproved,
+		);
+
+		// Only as much as keeps the owner alive is transferred, and deducted from the approval.
+		assert_ok!(Assets::transfer_approved_keep_alive(Origin::signed(2), 0, 1, 3, 95));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 3, 90)));
+		assert_eq!(Assets::balance(0, 1), 10);
+		assert_eq!(Assets::balance(0, 3), 90);
+		assert_eq!(Approvals::<Test>::get((0, 1, 2)).map(|a| a.amount), Some(10));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_noop!(
+			Assets::transfer_approved_keep_alive(Origin::signed(2), 0, 1, 3, 10),
+			Error::<Test>::BalanceLow,
+		);
+	});
+}
+
 #[test]
 fn cannot_transfer_more_than_exists() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 101));
@@ -98,7 +280,7 @@ fn cannot_transfer_more_than_exists() {
 #[test]
 fn cancel_approval_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
@@ -113,7 +295,7 @@ fn cancel_approval_works() {
 #[test]
 fn force_cancel_approval_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		Balances::make_free_balance_be(&1, 1);
 		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
@@ -131,7 +313,7 @@ fn force_cancel_approval_works() {
 fn lifecycle_should_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 100);
-		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
 		assert_eq!(Balances::reserved_balance(&1), 1);
 		assert!(Asset::<Test>::contains_key(0));
 
@@ -153,7 +335,7 @@ fn lifecycle_should_work() {
 		assert!(!Metadata::<Test>::contains_key(0));
 		assert_eq!(Account::<Test>::iter_prefix(0).count(), 0);
 
-		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
 		assert_eq!(Balances::reserved_balance(&1), 1);
 		assert!(Asset::<Test>::contains_key(0));
 
@@ -175,31 +357,135 @@ fn lifecycle_should_work() {
 	});
 }
 
+#[test]
+fn destroy_refunds_over_estimated_witness() {
+	use frame_support::weights::GetDispatchInfo;
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 10, 100));
+		let w = DestroyWitness { accounts: 5, sufficients: 5, approvals: 5 };
+		let call = crate::Call::<Test>::destroy(0, w);
+		let declared = call.get_dispatch_info().weight;
+
+		let post_info = Assets::destroy(Origin::root(), 0, w).unwrap();
+		let actual = <() as crate::WeightInfo>::destroy(0, 1, 0);
+		assert_eq!(post_info.actual_weight, Some(actual));
+		assert!(actual < declared);
+		assert!(!Asset::<Test>::contains_key(0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Destroyed(0, 1, 0, 100)));
+	});
+}
+
 #[test]
 fn destroy_with_bad_witness_should_not_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 100);
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 10, 100));
 		assert_noop!(Assets::destroy(Origin::signed(1), 0, w), Error::<Test>::BadWitness);
 	});
 }
 
+#[test]
+fn phased_destroy_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0], vec![0], 12));
+		Balances::make_free_balance_be(&10, 100);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 10, 100));
+		Balances::make_free_balance_be(&20, 100);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 20, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(Balances::reserved_balance(&1), 5);
+
+		assert_noop!(Assets::destroy_accounts(Origin::signed(3), 0, 10), Error::<Test>::IncorrectStatus);
+		assert_noop!(Assets::finish_destroy(Origin::signed(3), 0), Error::<Test>::IncorrectStatus);
+		assert_noop!(Assets::start_destroy(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::start_destroy(Origin::signed(1), 0));
+		assert_noop!(Assets::start_destroy(Origin::signed(1), 0), Error::<Test>::AssetNotLive);
+
+		// The asset can no longer be used.
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 10, 100), TokenError::UnknownAsset);
+		assert_noop!(Assets::transfer(Origin::signed(10), 0, 20, 50), Error::<Test>::AssetNotLive);
+		assert_noop!(Assets::burn(Origin::signed(1), 0, 10, 50), Error::<Test>::AssetNotLive);
+		assert_noop!(Assets::approve_transfer(Origin::signed(10), 0, 2, 50), Error::<Test>::AssetNotLive);
+		assert_noop!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 50), Error::<Test>::AssetNotLive);
+
+		// Anyone may remove the accounts and approvals, a few at a time.
+		assert_ok!(Assets::destroy_accounts(Origin::signed(3), 0, 2));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::AccountsDestroyed(0, 2, 1)));
+		assert_noop!(Assets::finish_destroy(Origin::signed(3), 0), Error::<Test>::InUse);
+		assert_ok!(Assets::destroy_accounts(Origin::signed(3), 0, 2));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::AccountsDestroyed(0, 1, 0)));
+		assert_eq!(Account::<Test>::iter_prefix(0).count(), 0);
+		assert_noop!(Assets::finish_destroy(Origin::signed(3), 0), Error::<Test>::InUse);
+
+		assert_ok!(Assets::destroy_approvals(Origin::signed(3), 0, 10));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::ApprovalsDestroyed(0, 1)));
+		assert_eq!(Balances::reserved_balance(&1), 4);
+
+		assert_ok!(Assets::finish_destroy(Origin::signed(3), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Destroyed(0, 0, 0, 0)));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert!(!Asset::<Test>::contains_key(0));
+		assert!(!Metadata::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn destroy_should_wait_for_swaps_and_scheduled_transfers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 2, 100));
+
+		// Neither the offered nor the wanted asset of an open swap may be destroyed.
+		assert_ok!(Assets::propose_swap(Origin::signed(1), 0, 60, 1, 50, 10, None));
+		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
+		assert_noop!(Assets::destroy(Origin::signed(1), 0, w), Error::<Test>::InUse);
+		assert_noop!(Assets::start_destroy(Origin::signed(1), 1), Error::<Test>::InUse);
+
+		assert_ok!(Assets::schedule_transfer(Origin::signed(2), 1, 3, 10, 5));
+		assert_ok!(Assets::cancel_swap(Origin::signed(1), 0));
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, w));
+		assert_noop!(Assets::start_destroy(Origin::signed(1), 1), Error::<Test>::InUse);
+
+		assert_ok!(Assets::cancel_scheduled_transfer(Origin::signed(2), 0));
+		assert!(!OpenReferences::<Test>::contains_key(1));
+		assert_ok!(Assets::start_destroy(Origin::signed(1), 1));
+		// No new swap may want an asset being destroyed.
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(
+			Assets::propose_swap(Origin::signed(1), 0, 60, 1, 50, 10, None),
+			Error::<Test>::AssetNotLive,
+		);
+
+		OpenReferences::<Test>::insert(1, 1);
+		assert_noop!(Assets::destroy_accounts(Origin::signed(3), 1, 10), Error::<Test>::InUse);
+		OpenReferences::<Test>::remove(1);
+		assert_ok!(Assets::destroy_accounts(Origin::signed(3), 1, 10));
+	});
+}
+
 #[test]
 fn non_providing_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, false, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, false, 1, None));
 
 		Balances::make_free_balance_be(&0, 100);
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 0, 100));
 
 		// Cannot mint into account 2 since it doesn't (yet) exist...
-		assert_noop!(Assets::mint(Origin::signed(1), 0, 1, 100), TokenError::CannotCreate);
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 1, 100), Error::<Test>::InsufficientProviders);
 		// ...or transfer...
-		assert_noop!(Assets::transfer(Origin::signed(0), 0, 1, 50), TokenError::CannotCreate);
+		assert_noop!(Assets::transfer(Origin::signed(0), 0, 1, 50), Error::<Test>::InsufficientProviders);
 		// ...or force-transfer
-		assert_noop!(Assets::force_transfer(Origin::signed(1), 0, 0, 1, 50), TokenError::CannotCreate);
+		assert_noop!(Assets::force_transfer(Origin::signed(1), 0, 0, 1, 50), Error::<Test>::InsufficientProviders);
 
 		Balances::make_free_balance_be(&1, 100);
 		Balances::make_free_balance_be(&2, 100);
@@ -211,7 +497,7 @@ fn non_providing_should_work() {
 #[test]
 fn min_balance_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 1);
 
@@ -240,7 +526,7 @@ fn min_balance_should_work() {
 #[test]
 fn querying_total_supply_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
@@ -258,7 +544,7 @@ fn querying_total_supply_should_work() {
 #[test]
 fn transferring_amount_below_available_balance_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
@@ -270,7 +556,7 @@ fn transferring_amount_below_available_balance_should_work() {
 #[test]
 fn transferring_enough_to_kill_source_when_keep_alive_should_fail() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 		assert_noop!(Assets::transfer_keep_alive(Origin::signed(1), 0, 2, 91), Error::<Test>::BalanceLow);
@@ -281,168 +567,1513 @@ fn transferring_enough_to_kill_source_when_keep_alive_should_fail() {
 }
 
 #[test]
-fn transferring_frozen_user_should_not_work() {
+fn transfer_best_effort_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
-		assert_eq!(Assets::balance(0, 1), 100);
-		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1));
-		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::Frozen);
-		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
-		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::transfer_best_effort(Origin::signed(1), 0, 2, 40));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 2, 40)));
+
+		// Only what remains is transferred, and the source is reaped.
+		assert_ok!(Assets::transfer_best_effort(Origin::signed(1), 0, 2, 100));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 2, 60)));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::balance(0, 2), 100);
+
+		// Nothing left to transfer: succeeds without creating the destination.
+		assert_ok!(Assets::transfer_best_effort(Origin::signed(1), 0, 3, 100));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 3, 0)));
+		assert!(!Account::<Test>::contains_key(0, 3));
+
+		// A frozen balance is left in place, together with the minimum balance keeping it alive.
+		set_frozen_balance(0, 2, 30);
+		assert_ok!(Assets::transfer_best_effort(Origin::signed(2), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 2), 40);
+		assert_eq!(Assets::balance(0, 1), 60);
+
+		// Nothing can be sent from a frozen account or asset class.
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2));
+		assert_ok!(Assets::transfer_best_effort(Origin::signed(2), 0, 1, 5));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 2, 1, 0)));
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 2));
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0));
+		assert_ok!(Assets::transfer_best_effort(Origin::signed(2), 0, 1, 5));
+		assert_eq!(Assets::balance(0, 2), 40);
+		assert_eq!(Assets::balance(0, 1), 60);
 	});
 }
 
 #[test]
-fn transferring_frozen_asset_should_not_work() {
+fn transfer_to_many_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
-		assert_eq!(Assets::balance(0, 1), 100);
-		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0));
-		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::Frozen);
-		assert_ok!(Assets::thaw_asset(Origin::signed(1), 0));
-		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+
+		let targets = |v: Vec<(u64, u64)>| -> BoundedVec<(u64, u64), MaxFanOut> { v.try_into().unwrap() };
+
+		// Not enough to cover everything; nothing happens.
+		assert_noop!(
+			Assets::transfer_to_many(Origin::signed(1), 0, targets(vec![(2, 60), (3, 41)])),
+			Error::<Test>::BalanceLow,
+		);
+		// One target would be left below the minimum balance; nothing happens.
+		assert_noop!(
+			Assets::transfer_to_many(Origin::signed(1), 0, targets(vec![(2, 60), (3, 5)])),
+			TokenError::BelowMinimum,
+		);
+
+		assert_ok!(Assets::transfer_to_many(Origin::signed(1), 0, targets(vec![(2, 50), (3, 30), (1, 5)])));
+		assert_eq!(Assets::balance(0, 1), 20);
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_eq!(Assets::balance(0, 3), 30);
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 3);
+
+		// The source is left with dust, which goes to the last target.
+		assert_ok!(Assets::transfer_to_many(Origin::signed(1), 0, targets(vec![(2, 5), (3, 10)])));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::balance(0, 2), 55);
+		assert_eq!(Assets::balance(0, 3), 45);
+		assert_eq!(Assets::total_supply(0), 100);
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 2);
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 3, 15)));
 	});
 }
 
 #[test]
-fn origin_guards_should_work() {
+fn multi_asset_transfer_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
-		assert_noop!(Assets::transfer_ownership(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
-		assert_noop!(Assets::set_team(Origin::signed(2), 0, 2, 2, 2), Error::<Test>::NoPermission);
-		assert_noop!(Assets::freeze(Origin::signed(2), 0, 1), Error::<Test>::NoPermission);
-		assert_noop!(Assets::thaw(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
-		assert_noop!(Assets::mint(Origin::signed(2), 0, 2, 100), Error::<Test>::NoPermission);
-		assert_noop!(Assets::burn(Origin::signed(2), 0, 1, 100), Error::<Test>::NoPermission);
-		assert_noop!(Assets::force_transfer(Origin::signed(2), 0, 1, 2, 100), Error::<Test>::NoPermission);
-		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
-		assert_noop!(Assets::destroy(Origin::signed(2), 0, w), Error::<Test>::NoPermission);
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 2, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 100));
+		// Both sides of the swap let the settlement account 3 move their assets.
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(2), 1, 3, 30));
+
+		let transfer = |asset, source, dest, amount| MultiAssetTransfer { asset, source, dest, amount };
+		let transfers = |v: Vec<MultiAssetTransfer<u32, u64, u64>>| -> BoundedVec<_, MaxMultiTransfer> {
+			v.try_into().unwrap()
+		};
+
+		// The second transfer exceeds its approval; nothing happens.
+		assert_noop!(
+			Assets::multi_asset_transfer(
+				Origin::signed(3),
+				transfers(vec![transfer(0, 1, 2, 50), transfer(1, 2, 1, 31)]),
+			),
+			Error::<Test>::Unapproved,
+		);
+		// Nor may the sender use approvals given to anyone else.
+		assert_noop!(
+			Assets::multi_asset_transfer(Origin::signed(4), transfers(vec![transfer(0, 1, 2, 50)])),
+			Error::<Test>::Unapproved,
+		);
+
+		assert_ok!(Assets::multi_asset_transfer(
+			Origin::signed(3),
+			transfers(vec![transfer(0, 1, 2, 50), transfer(1, 2, 1, 30), transfer(0, 3, 4, 10)]),
+		));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_eq!(Assets::balance(1, 1), 30);
+		assert_eq!(Assets::balance(1, 2), 70);
+		assert_eq!(Assets::balance(0, 3), 90);
+		assert_eq!(Assets::balance(0, 4), 10);
+		assert_eq!(Assets::approval_allowance(0, &1, &3), 0);
+		assert_eq!(Assets::approval_allowance(1, &2, &3), 0);
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 3, 4, 10)));
 	});
 }
 
 #[test]
-fn transfer_owner_should_work() {
+fn force_batch_transfer_should_work() {
 	new_test_ext().execute_with(|| {
-		Balances::make_free_balance_be(&1, 100);
-		Balances::make_free_balance_be(&2, 100);
-		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		let targets = |v: Vec<(u64, u64)>| -> BoundedVec<(u64, u64), MaxFanOut> { v.try_into().unwrap() };
 
-		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_noop!(
+			Assets::force_batch_transfer(Origin::signed(2), 0, 2, targets(vec![(3, 10)])),
+			Error::<Test>::NoPermission,
+		);
+		// The transfer to 4 is below the minimum balance and the one to 6 is more than is left.
+		assert_ok!(Assets::force_batch_transfer(
+			Origin::signed(1),
+			0,
+			2,
+			targets(vec![(3, 30), (4, 5), (5, 50), (6, 30)]),
+		));
+		assert_eq!(Assets::balance(0, 2), 20);
+		assert_eq!(Assets::balance(0, 3), 30);
+		assert_eq!(Assets::balance(0, 4), 0);
+		assert_eq!(Assets::balance(0, 5), 50);
+		assert_eq!(Assets::balance(0, 6), 0);
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 2, 5, 50)));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::BatchTransferFailed(0, 2, 4, 5)));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::BatchTransferFailed(0, 2, 6, 30)));
+	});
+}
 
-		assert_ok!(Assets::transfer_ownership(Origin::signed(1), 0, 2));
-		assert_eq!(Balances::reserved_balance(&2), 1);
-		assert_eq!(Balances::reserved_balance(&1), 0);
+#[test]
+fn force_batch_transfer_should_ignore_freezes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		let targets = |v: Vec<(u64, u64)>| -> BoundedVec<(u64, u64), MaxFanOut> { v.try_into().unwrap() };
 
-		assert_noop!(Assets::transfer_ownership(Origin::signed(1), 0, 1), Error::<Test>::NoPermission);
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2));
+		assert_noop!(Assets::force_transfer(Origin::signed(1), 0, 2, 3, 30), Error::<Test>::AccountFrozen);
+		assert_ok!(Assets::force_batch_transfer(Origin::signed(1), 0, 2, targets(vec![(3, 30)])));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 2, 3, 30)));
+		assert_eq!(Assets::balance(0, 2), 70);
+		assert_eq!(Assets::balance(0, 3), 30);
 
-		// Set metadata now and make sure that deposit gets transferred back.
-		assert_ok!(Assets::set_metadata(Origin::signed(2), 0, vec![0u8; 10], vec![0u8; 10], 12));
-		assert_ok!(Assets::transfer_ownership(Origin::signed(2), 0, 1));
-		assert_eq!(Balances::reserved_balance(&1), 22);
-		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0));
+		assert_noop!(Assets::force_transfer(Origin::signed(1), 0, 2, 4, 30), Error::<Test>::AssetClassFrozen);
+		assert_ok!(Assets::force_batch_transfer(Origin::signed(1), 0, 2, targets(vec![(4, 30)])));
+		assert_eq!(Assets::balance(0, 2), 40);
+		assert_eq!(Assets::balance(0, 4), 30);
+		// The freezes themselves are left in place.
+		assert!(Assets::account_is_frozen(0, &2));
+		assert!(Assets::asset_is_frozen(0));
 	});
 }
 
 #[test]
-fn set_team_should_work() {
+fn transfer_fee_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
-		assert_ok!(Assets::set_team(Origin::signed(1), 0, 2, 3, 4));
-
-		assert_ok!(Assets::mint(Origin::signed(2), 0, 2, 100));
-		assert_ok!(Assets::freeze(Origin::signed(4), 0, 2));
-		assert_ok!(Assets::thaw(Origin::signed(3), 0, 2));
-		assert_ok!(Assets::force_transfer(Origin::signed(3), 0, 2, 3, 100));
-		assert_ok!(Assets::burn(Origin::signed(3), 0, 3, 100));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 1000));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 9, 10));
+		let rate = Perbill::from_percent(10);
+		assert_noop!(Assets::set_transfer_fee(Origin::signed(2), 0, rate, 9), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, rate, 9));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::TransferFeeChanged(0, rate, 9)));
+
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 200));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 2, 180)));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::TransferFeePaid(0, 1, 9, 20)));
+		assert_eq!(Assets::balance(0, 1), 800);
+		assert_eq!(Assets::balance(0, 2), 180);
+		assert_eq!(Assets::balance(0, 9), 30);
+		assert_eq!(Assets::total_supply(0), 1010);
+
+		// The fee may not leave too little to create the destination.
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 3, 10), Error::<Test>::FeeTooLarge);
+		// No fee is charged when the beneficiary is either side of the transfer.
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 9, 100));
+		assert_eq!(Assets::balance(0, 9), 130);
+
+		// A zero rate removes the fee.
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, Perbill::zero(), 9));
+		assert!(Asset::<Test>::get(0).unwrap().transfer_fee().is_none());
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 3, 10));
+		assert_eq!(Assets::balance(0, 3), 10);
 	});
 }
 
 #[test]
-fn transferring_to_frozen_account_should_work() {
+fn transfer_fee_is_waived_when_the_beneficiary_cannot_receive_it() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
-		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
-		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
-		assert_eq!(Assets::balance(0, 1), 100);
-		assert_eq!(Assets::balance(0, 2), 100);
-		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 1000));
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, Perbill::from_percent(10), 9));
+
+		// A fee below the minimum balance can not create the beneficiary's account.
 		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
-		assert_eq!(Assets::balance(0, 2), 150);
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 2, 50)));
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_eq!(Assets::balance(0, 9), 0);
+
+		// Once the fee is large enough, it is charged.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 100));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::TransferFeePaid(0, 1, 9, 10)));
+		assert_eq!(Assets::balance(0, 2), 140);
+		assert_eq!(Assets::balance(0, 9), 10);
 	});
 }
 
 #[test]
-fn transferring_amount_more_than_available_balance_should_not_work() {
+fn transfer_to_many_should_charge_the_transfer_fee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 1000));
+		assert_ok!(Assets::set_transfer_fee(Origin::signed(1), 0, Perbill::from_percent(10), 9));
+
+		let targets: BoundedVec<(u64, u64), MaxFanOut> =
+			vec![(2, 200), (3, 100), (9, 100)].try_into().unwrap();
+		assert_ok!(Assets::transfer_to_many(Origin::signed(1), 0, targets));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 2, 180)));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::TransferFeePaid(0, 1, 9, 20)));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 3, 90)));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::TransferFeePaid(0, 1, 9, 10)));
+		assert_eq!(Assets::balance(0, 1), 600);
+		assert_eq!(Assets::balance(0, 2), 180);
+		assert_eq!(Assets::balance(0, 3), 90);
+		// No fee is charged on the transfer to the beneficiary itself.
+		assert_eq!(Assets::balance(0, 9), 130);
+		assert_eq!(Assets::total_supply(0), 1000);
+
+		// The fee may not leave too little to create a destination.
+		let targets: BoundedVec<(u64, u64), MaxFanOut> = vec![(2, 100), (4, 10)].try_into().unwrap();
+		assert_noop!(Assets::transfer_to_many(Origin::signed(1), 0, targets), Error::<Test>::FeeTooLarge);
+	});
+}
+
+#[test]
+fn swap_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
-		assert_eq!(Assets::balance(0, 1), 100);
-		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
-		assert_eq!(Assets::balance(0, 1), 50);
-		assert_eq!(Assets::balance(0, 2), 50);
-		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, u64::max_value()));
-		assert_eq!(Assets::balance(0, 1), 0);
-		assert_noop!(Assets::transfer(Origin::signed(1), 0, 1, 50), Error::<Test>::BalanceLow);
-		assert_noop!(Assets::transfer(Origin::signed(2), 0, 1, 51), Error::<Test>::BalanceLow);
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 2, 100));
+		assert_noop!(Assets::propose_swap(Origin::signed(1), 0, 0, 1, 50, 10, None), Error::<Test>::BalanceZero);
+		assert_noop!(Assets::propose_swap(Origin::signed(1), 0, 100, 1, 50, 10, None), Error::<Test>::BalanceLow);
+		assert_noop!(Assets::propose_swap(Origin::signed(1), 0, 60, 2, 50, 10, None), Error::<Test>::Unknown);
+
+		assert_ok!(Assets::propose_swap(Origin::signed(1), 0, 60, 1, 50, 10, Some(3)));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::SwapProposed(0, 1, 0, 60, 1, 50)));
+		// The offered funds are held.
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 40), Error::<Test>::BalanceLow);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 39));
+
+		// Both legs must succeed.
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1));
+		assert_noop!(Assets::accept_swap(Origin::signed(2), 0), Error::<Test>::AccountFrozen);
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
+		assert_noop!(Assets::accept_swap(Origin::signed(3), 0), Error::<Test>::BalanceLow);
+
+		assert_ok!(Assets::accept_swap(Origin::signed(2), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::SwapAccepted(0, 2)));
+		assert_eq!(Assets::balance(0, 1), 1);
+		assert_eq!(Assets::balance(0, 2), 99);
+		assert_eq!(Assets::balance(1, 2), 50);
+		assert_eq!(Assets::balance(1, 3), 50);
+		assert_eq!(Account::<Test>::get(0, 1).reserved(), 0);
+		assert_noop!(Assets::accept_swap(Origin::signed(2), 0), Error::<Test>::UnknownSwap);
+
+		// Expired proposals can't be accepted.
+		assert_ok!(Assets::propose_swap(Origin::signed(2), 1, 10, 0, 10, 10, None));
+		System::set_block_number(11);
+		assert_noop!(Assets::accept_swap(Origin::signed(1), 1), Error::<Test>::SwapExpired);
 	});
 }
 
 #[test]
-fn transferring_less_than_one_unit_is_fine() {
+fn cancel_swap_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
-		assert_eq!(Assets::balance(0, 1), 100);
-		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 0));
-		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 2, 0)));
+		assert_ok!(Assets::propose_swap(Origin::signed(1), 0, 60, 0, 50, 10, None));
+		assert_ok!(Assets::propose_swap(Origin::signed(1), 0, 30, 0, 50, 10, None));
+		assert_eq!(Account::<Test>::get(0, 1).reserved(), 90);
+
+		// The proposer may cancel at any time.
+		assert_ok!(Assets::cancel_swap(Origin::signed(1), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::SwapCancelled(0)));
+		assert_eq!(Account::<Test>::get(0, 1).reserved(), 30);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 60));
+
+		// Anyone else only once it has expired.
+		System::set_block_number(10);
+		assert_noop!(Assets::cancel_swap(Origin::signed(2), 1), Error::<Test>::SwapNotExpired);
+		System::set_block_number(11);
+		assert_ok!(Assets::cancel_swap(Origin::signed(2), 1));
+		assert_eq!(Account::<Test>::get(0, 1).reserved(), 0);
+		assert_noop!(Assets::cancel_swap(Origin::signed(1), 1), Error::<Test>::UnknownSwap);
 	});
 }
 
 #[test]
-fn transferring_more_units_than_total_supply_should_not_work() {
+fn scheduled_transfer_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
-		assert_eq!(Assets::balance(0, 1), 100);
-		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 101), Error::<Test>::BalanceLow);
+		assert_noop!(Assets::schedule_transfer(Origin::signed(1), 0, 2, 0, 5), Error::<Test>::BalanceZero);
+		assert_noop!(Assets::schedule_transfer(Origin::signed(1), 0, 2, 10, 1), Error::<Test>::ScheduleInPast);
+		assert_noop!(Assets::schedule_transfer(Origin::signed(1), 0, 2, 100, 5), Error::<Test>::BalanceLow);
+
+		assert_ok!(Assets::schedule_transfer(Origin::signed(1), 0, 2, 50, 5));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::TransferScheduled(0, 0, 1, 2, 50, 5)));
+		assert_ok!(Assets::schedule_transfer(Origin::signed(1), 0, 3, 20, 5));
+		assert_noop!(Assets::schedule_transfer(Origin::signed(1), 0, 4, 10, 5), Error::<Test>::TooManyScheduled);
+		assert_eq!(Account::<Test>::get(0, 1).reserved(), 70);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 4, 30), Error::<Test>::BalanceLow);
+
+		// Nothing happens before the scheduled block.
+		Assets::on_initialize(4);
+		assert_eq!(Assets::balance(0, 2), 0);
+
+		// A failing transfer has its amount released.
+		assert_ok!(Assets::set_min_balance(Origin::signed(1), 0, 30, 2));
+		Assets::on_initialize(5);
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::ScheduledTransferExecuted(0)));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::ScheduledTransferFailed(1)));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_eq!(Assets::balance(0, 3), 0);
+		assert_eq!(Account::<Test>::get(0, 1).reserved(), 0);
+		assert!(ScheduledTransfers::<Test>::iter_prefix(5).next().is_none());
 	});
 }
 
 #[test]
-fn burning_asset_balance_with_positive_balance_should_work() {
+fn cancel_scheduled_transfer_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
-		assert_eq!(Assets::balance(0, 1), 100);
-		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, u64::max_value()));
-		assert_eq!(Assets::balance(0, 1), 0);
+		assert_ok!(Assets::schedule_transfer(Origin::signed(1), 0, 2, 50, 5));
+		assert_noop!(Assets::cancel_scheduled_transfer(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_noop!(Assets::cancel_scheduled_transfer(Origin::signed(1), 1), Error::<Test>::UnknownSchedule);
+
+		assert_ok!(Assets::cancel_scheduled_transfer(Origin::signed(1), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::ScheduledTransferCancelled(0)));
+		assert_eq!(Account::<Test>::get(0, 1).reserved(), 0);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 100));
+
+		Assets::on_initialize(5);
+		assert_eq!(Assets::balance(0, 2), 100);
+		assert_noop!(Assets::cancel_scheduled_transfer(Origin::signed(1), 0), Error::<Test>::UnknownSchedule);
 	});
 }
 
 #[test]
-fn burning_asset_balance_with_zero_balance_does_nothing() {
+fn transferring_frozen_user_should_not_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
-		assert_eq!(Assets::balance(0, 2), 0);
-		assert_ok!(Assets::burn(Origin::signed(1), 0, 2, u64::max_value()));
-		assert_eq!(Assets::balance(0, 2), 0);
-		assert_eq!(Assets::total_supply(0), 100);
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_noop!(Assets::thaw(Origin::signed(1), 0, 1), Error::<Test>::NotFrozen);
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1));
+		assert_eq!(Assets::frozen_kind(0, &1), Some(FrozenKind::Account));
+		assert_noop!(Assets::freeze(Origin::signed(1), 0, 1), Error::<Test>::AlreadyFrozen);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::AccountFrozen);
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
+		assert_eq!(Assets::frozen_kind(0, &1), None);
+		assert_noop!(Assets::thaw(Origin::signed(1), 0, 1), Error::<Test>::NotFrozen);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
 	});
 }
 
 #[test]
-fn set_metadata_should_work() {
+fn bulk_freeze_and_thaw_should_work() {
 	new_test_ext().execute_with(|| {
-		// Cannot add metadata to unknown asset
-		assert_noop!(
-				Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12),
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		let targets: BoundedVec<u64, MaxFreezeBatch> = vec![1, 3, 2].try_into().unwrap();
+
+		assert_noop!(
+			Assets::bulk_freeze_accounts(Origin::signed(2), 0, targets.clone()),
+			Error::<Test>::NoPermission,
+		);
+		// Account 3 holds nothing and is skipped.
+		assert_ok!(Assets::bulk_freeze_accounts(Origin::signed(1), 0, targets.clone()));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::Frozen(0, 1)));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Frozen(0, 2)));
+		assert!(!Account::<Test>::contains_key(0, &3));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 3, 50), Error::<Test>::AccountFrozen);
+		assert_noop!(Assets::transfer(Origin::signed(2), 0, 3, 50), Error::<Test>::AccountFrozen);
+
+		assert_noop!(
+			Assets::bulk_thaw_accounts(Origin::signed(2), 0, targets.clone()),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Assets::bulk_thaw_accounts(Origin::signed(1), 0, targets));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Thawed(0, 2)));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 3, 50));
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 50));
+	});
+}
+
+#[test]
+fn transferring_frozen_asset_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0));
+		assert_eq!(Assets::frozen_kind(0, &1), Some(FrozenKind::AssetClass));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::AssetClassFrozen);
+		assert_ok!(Assets::thaw_asset(Origin::signed(1), 0));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+	});
+}
+
+#[test]
+fn freezing_asset_with_reason_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::set_team(Origin::signed(1), 0, 1, 2, 3));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(
+			Assets::freeze_asset_with_reason(Origin::signed(2), 0, b"audit".to_vec()),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(
+			Assets::freeze_asset_with_reason(Origin::signed(3), 0, vec![0u8; 51]),
+			Error::<Test>::BadReason,
+		);
+
+		assert_ok!(Assets::freeze_asset_with_reason(Origin::signed(3), 0, b"audit".to_vec()));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::AssetFrozen(0, Some(b"audit".to_vec()))));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::AssetClassFrozen);
+
+		assert_noop!(
+			Assets::thaw_asset_with_reason(Origin::signed(3), 0, b"cleared".to_vec()),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Assets::thaw_asset_with_reason(Origin::signed(2), 0, b"cleared".to_vec()));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::AssetThawed(0, Some(b"cleared".to_vec()))));
+		assert_ok!(Assets::freeze_asset(Origin::signed(3), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::AssetFrozen(0, None)));
+	});
+}
+
+#[test]
+fn minting_and_burning_frozen_asset_should_follow_policy() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		let details = Asset::<Test>::get(0).unwrap();
+		assert!(details.can_mint());
+
+		// Burning is refused as any other withdrawal from a frozen asset class is.
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0));
+		let details = Asset::<Test>::get(0).unwrap();
+		assert!(details.can_mint());
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::burn(Origin::signed(1), 0, 1, 50), Error::<Test>::AssetClassFrozen);
+		assert_eq!(Assets::balance(0, 1), 200);
+	});
+}
+
+#[test]
+fn setting_single_team_members_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_noop!(Assets::set_issuer(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::set_admin(Origin::signed(1), 1, 2), Error::<Test>::Unknown);
+
+		assert_ok!(Assets::set_issuer(Origin::signed(1), 0, 2));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::TeamChanged(0, 2, 1, 1)));
+		assert_ok!(Assets::set_admin(Origin::signed(1), 0, 3));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::TeamChanged(0, 2, 3, 1)));
+		assert_ok!(Assets::set_freezer(Origin::signed(1), 0, 4));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::TeamChanged(0, 2, 3, 4)));
+
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!((details.owner, details.issuer, details.admin, details.freezer), (1, 2, 3, 4));
+	});
+}
+
+#[test]
+fn force_set_owner_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
+		assert_eq!(Asset::<Test>::get(0).unwrap().deposit, 1);
+		assert_noop!(Assets::force_set_owner_deposit(Origin::signed(1), 0, 0), DispatchError::BadOrigin);
+		assert_noop!(Assets::force_set_owner_deposit(Origin::root(), 1, 0), Error::<Test>::Unknown);
+
+		// Only the record changes; nothing is reserved or unreserved.
+		assert_ok!(Assets::force_set_owner_deposit(Origin::root(), 0, 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::DepositCorrected(0, 1, 0)));
+		assert_eq!(Asset::<Test>::get(0).unwrap().deposit, 0);
+		assert_eq!(Balances::reserved_balance(&1), 1);
+	});
+}
+
+#[test]
+fn retiring_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, false, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 20));
+
+		assert_noop!(Assets::retire_asset(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_noop!(Assets::unretire_asset(Origin::signed(1), 0), Error::<Test>::IncorrectStatus);
+		assert_ok!(Assets::retire_asset(Origin::signed(1), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::AssetRetired(0)));
+		assert_eq!(Asset::<Test>::get(0).unwrap().status(), AssetStatus::Retiring);
+		assert_noop!(Assets::retire_asset(Origin::signed(1), 0), Error::<Test>::AssetNotLive);
+
+		// No new supply, accounts or approvals.
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 1, 100), Error::<Test>::AssetRetired);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 3, 50), Error::<Test>::AssetRetired);
+		assert_noop!(Assets::touch(Origin::signed(3), 0), Error::<Test>::AssetRetired);
+		assert_noop!(Assets::approve_transfer(Origin::signed(1), 0, 3, 20), Error::<Test>::AssetRetired);
+		assert_noop!(
+			Assets::approve_transfer_exact(Origin::signed(1), 0, 3, 20),
+			Error::<Test>::AssetRetired,
+		);
+		// Existing balances and approvals may still move, and be burned.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::transfer_approved(Origin::signed(3), 0, 1, 2, 20));
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 2, 10));
+		assert_eq!(Assets::balance(0, 1), 30);
+		assert_eq!(Assets::balance(0, 2), 160);
+
+		assert_noop!(Assets::unretire_asset(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::unretire_asset(Origin::signed(1), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::AssetUnretired(0)));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 100));
+
+		// A retiring asset may be destroyed.
+		assert_ok!(Assets::retire_asset(Origin::signed(1), 0));
+		assert_ok!(Assets::start_destroy(Origin::signed(1), 0));
+		assert_eq!(Asset::<Test>::get(0).unwrap().status(), AssetStatus::Destroying);
+	});
+}
+
+#[test]
+fn pausing_minting_should_work() {
+	use frame_support::traits::tokens::fungibles::Mutate;
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::set_team(Origin::signed(1), 0, 2, 1, 1));
+
+		assert_noop!(Assets::pause_minting(Origin::signed(1), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::pause_minting(Origin::signed(2), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::MintingPaused(0)));
+		assert!(!Asset::<Test>::get(0).unwrap().can_mint());
+
+		assert_noop!(Assets::mint(Origin::signed(2), 0, 1, 100), Error::<Test>::MintingDisabled);
+		assert_noop!(Assets::mint_into(0, &1, 100), Error::<Test>::MintingDisabled);
+		// Transfers and burns are unaffected.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 3, 50));
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 3, 10));
+
+		assert_noop!(Assets::resume_minting(Origin::signed(1), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::resume_minting(Origin::signed(2), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::MintingResumed(0)));
+		assert_ok!(Assets::mint(Origin::signed(2), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 150);
+
+		assert_ok!(Assets::force_asset_status(Origin::root(), 0, 1, 2, 1, 1, 1, true, false, None, true));
+		assert_noop!(Assets::mint(Origin::signed(2), 0, 1, 100), Error::<Test>::MintingDisabled);
+	});
+}
+
+#[test]
+fn transfer_rate_limit_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 1000));
+		assert_noop!(
+			Assets::set_transfer_rate_limit(Origin::signed(2), 0, 100, 10),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Assets::set_transfer_rate_limit(Origin::signed(1), 0, 100, 10));
+		System::assert_last_event(
+			mock::Event::pallet_assets(crate::Event::TransferRateLimitChanged(0, 100, 10))
+		);
+
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 60));
+		assert_noop!(Assets::transfer(Origin::signed(2), 0, 3, 50), Error::<Test>::TransferLimitExceeded);
+		assert_noop!(
+			Assets::force_transfer(Origin::signed(1), 0, 1, 3, 50),
+			Error::<Test>::TransferLimitExceeded,
+		);
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 40));
+		assert_eq!(TransferVolume::<Test>::get(0), Some((1, 100)));
+
+		// The window ends after 10 blocks; the next transfer starts a new one.
+		System::set_block_number(10);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 1), Error::<Test>::TransferLimitExceeded);
+		System::set_block_number(11);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 100));
+		assert_eq!(TransferVolume::<Test>::get(0), Some((11, 100)));
+
+		// A zero window removes the limit.
+		assert_ok!(Assets::set_transfer_rate_limit(Origin::signed(1), 0, 0, 0));
+		assert_eq!(TransferRateLimitConfig::<Test>::get(0), None);
+		assert_eq!(TransferVolume::<Test>::get(0), None);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 500));
+		assert_eq!(Assets::balance(0, 2), 620);
+	});
+}
+
+#[test]
+fn transfer_with_memo_should_work() {
+	new_test_ext().execute_with(|| {
+		let memo = |m: &[u8]| -> BoundedVec<u8, MemoLimit> { m.to_vec().try_into().unwrap() };
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+
+		assert_ok!(Assets::transfer_with_memo(Origin::signed(1), 0, 2, 50, memo(b"tag:1234")));
+		System::assert_has_event(
+			mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 2, 50))
+		);
+		System::assert_last_event(mock::Event::pallet_assets(
+			crate::Event::TransferredWithMemo(0, 1, 2, 50, b"tag:1234".to_vec())
+		));
+		assert_eq!(Assets::balance(0, 2), 50);
+
+		// Only valid UTF-8 is accepted unless raw memos are configured.
+		let invalid = memo(&[0xff, 0xfe]);
+		assert_noop!(
+			Assets::transfer_with_memo(Origin::signed(1), 0, 2, 10, invalid.clone()),
+			Error::<Test>::BadMemo,
+		);
+		AssetMemoEncoding::set(MemoEncoding::Raw);
+		assert_ok!(Assets::transfer_with_memo(Origin::signed(1), 0, 2, 10, invalid));
+		assert_eq!(Assets::balance(0, 2), 60);
+
+		assert_noop!(
+			Assets::transfer_with_memo(Origin::signed(1), 0, 2, 100, memo(b"")),
+			Error::<Test>::BalanceLow,
+		);
+	});
+}
+
+#[test]
+fn metadata_max_encoded_len_should_bound_its_encoding() {
+	use frame_support::traits::MaxEncodedLen;
+	let string: BoundedVec<u8, StringLimit> = vec![0u8; 50].try_into().unwrap();
+	let uri: BoundedVec<u8, UriLimit> = vec![0u8; 256].try_into().unwrap();
+	let metadata = AssetMetadata {
+		deposit: u64::max_value(),
+		name: string.clone(),
+		symbol: string,
+		decimals: u8::max_value(),
+		is_frozen: true,
+		image_hash: Some([u8::max_value(); 32]),
+		uri: Some(uri),
+	};
+	let max_len = AssetMetadata::<u64, BoundedVec<u8, StringLimit>, BoundedVec<u8, UriLimit>>::max_encoded_len();
+	assert!(metadata.encode().len() <= max_len);
+	assert_eq!(AssetBalance::<u64, u64, ()>::max_encoded_len(), 8 + 8 + 1 + 1 + 9);
+}
+
+#[test]
+fn transfer_below_destination_frozen_floor_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		set_frozen_balance(0, 2, 30);
+
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 39), Error::<Test>::WouldKillDestination);
+		let targets: BoundedVec<(u64, u64), MaxFanOut> = vec![(2, 20)].try_into().unwrap();
+		assert_noop!(
+			Assets::transfer_to_many(Origin::signed(1), 0, targets),
+			Error::<Test>::WouldKillDestination,
+		);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 40));
+		assert_eq!(Assets::balance(0, 2), 40);
+		// Once the floor is covered, smaller credits are fine.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 1));
+		clear_frozen_balance(0, 2);
+	});
+}
+
+#[test]
+fn accounts_and_approvals_iterators_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 20));
+
+		let mut accounts = Assets::accounts_for(0)
+			.map(|(who, account)| (who, account.balance()))
+			.collect::<Vec<_>>();
+		accounts.sort();
+		assert_eq!(accounts, vec![(1, 100), (2, 50)]);
+		let approvals = Assets::all_approvals_for(0)
+			.map(|(key, approval)| (key, approval.amount()))
+			.collect::<Vec<_>>();
+		assert_eq!(approvals, vec![((1, 3), 20)]);
+
+		// Unknown and destroyed assets yield nothing.
+		assert_eq!(Assets::accounts_for(1).count(), 0);
+		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Assets::destroy(Origin::root(), 0, w));
+		assert_eq!(Assets::accounts_for(0).count(), 0);
+		assert_eq!(Assets::all_approvals_for(0).count(), 0);
+	});
+}
+
+#[test]
+fn approval_count_for_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_eq!(Assets::approval_count_for(0, &1), 0);
+
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 20));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 4, 20));
+		assert_ok!(Assets::approve_transfer(Origin::signed(2), 0, 3, 20));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 1, 3, 20));
+		assert_eq!(Assets::approval_count_for(0, &1), 2);
+		assert_eq!(Assets::approval_count_for(0, &2), 1);
+		assert_eq!(Assets::approval_count_for(1, &1), 1);
+
+		assert_ok!(Assets::cancel_approval(Origin::signed(1), 0, 3));
+		assert_eq!(Assets::approval_count_for(0, &1), 1);
+	});
+}
+
+#[test]
+fn transfer_guard_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::set_transfer_guard(Origin::signed(2), 0, Some(9)), Error::<Test>::NoPermission);
+		assert_ok!(Assets::set_transfer_guard(Origin::signed(1), 0, Some(9)));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::TransferGuardChanged(0, Some(9))));
+		assert_eq!(Assets::transfer_guard(0), Some(9));
+
+		block_transfers(3);
+		let blocked = DispatchError::Other("Blocked");
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 3, 10), blocked);
+		let targets: BoundedVec<(u64, u64), MaxFanOut> = vec![(2, 10), (3, 10)].try_into().unwrap();
+		assert_noop!(Assets::transfer_to_many(Origin::signed(1), 0, targets), blocked);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_noop!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 10), blocked);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 10));
+
+		// The Admin may still force transfers.
+		assert_ok!(Assets::force_transfer(Origin::signed(1), 0, 1, 3, 10));
+		assert_eq!(Assets::balance(0, 3), 10);
+
+		assert_ok!(Assets::set_transfer_guard(Origin::signed(1), 0, None));
+		assert_eq!(Assets::transfer_guard(0), None);
+	});
+}
+
+#[test]
+fn revoke_all_approvals_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		for delegate in 3..6 {
+			assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, delegate, 10));
+		}
+		assert_ok!(Assets::approve_transfer(Origin::signed(2), 0, 3, 10));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 4);
+		assert_eq!(Balances::reserved_balance(&1), 3);
+
+		// At most `MaxRevocationBatch` approvals go at once.
+		assert_ok!(Assets::revoke_all_approvals(Origin::signed(1), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::ApprovalsRevoked(0, 1, 2)));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 2);
+		assert_eq!(Balances::reserved_balance(&1), 1);
+
+		let post_info = Assets::revoke_all_approvals(Origin::signed(1), 0).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as crate::WeightInfo>::revoke_all_approvals(1)));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 1);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Assets::approval_allowance(0, &1, &3), 0);
+
+		// Nothing left; approvals of other owners are untouched.
+		assert_ok!(Assets::revoke_all_approvals(Origin::signed(1), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::ApprovalsRevoked(0, 1, 0)));
+		assert_eq!(Assets::approval_allowance(0, &2, &3), 10);
+	});
+}
+
+#[test]
+fn origin_guards_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::transfer_ownership(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::set_team(Origin::signed(2), 0, 2, 2, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::freeze(Origin::signed(2), 0, 1), Error::<Test>::NoPermission);
+		assert_noop!(Assets::thaw(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::mint(Origin::signed(2), 0, 2, 100), Error::<Test>::NoPermission);
+		assert_noop!(Assets::burn(Origin::signed(2), 0, 1, 100), Error::<Test>::NoPermission);
+		assert_noop!(Assets::force_transfer(Origin::signed(2), 0, 1, 2, 100), Error::<Test>::NoPermission);
+		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
+		assert_noop!(Assets::destroy(Origin::signed(2), 0, w), Error::<Test>::NoPermission);
+	});
+}
+
+#[test]
+fn transfer_owner_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
+
+		assert_eq!(Balances::reserved_balance(&1), 1);
+
+		assert_ok!(Assets::transfer_ownership(Origin::signed(1), 0, 2));
+		assert_eq!(Balances::reserved_balance(&2), 1);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+
+		assert_noop!(Assets::transfer_ownership(Origin::signed(1), 0, 1), Error::<Test>::NoPermission);
+
+		// Set metadata now and make sure that deposit gets transferred back.
+		assert_ok!(Assets::set_metadata(Origin::signed(2), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_ok!(Assets::transfer_ownership(Origin::signed(2), 0, 1));
+		assert_eq!(Balances::reserved_balance(&1), 22);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+	});
+}
+
+#[test]
+fn force_transfer_ownership_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
+		assert_ok!(Assets::propose_new_owner(Origin::signed(1), 0, 3));
+
+		assert_noop!(Assets::force_transfer_ownership(Origin::signed(1), 0, 2), DispatchError::BadOrigin);
+		assert_noop!(Assets::force_transfer_ownership(Origin::root(), 1, 2), Error::<Test>::Unknown);
+		assert_ok!(Assets::force_transfer_ownership(Origin::root(), 0, 2));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::OwnerChanged(0, 2)));
+		assert_eq!(Asset::<Test>::get(0).unwrap().owner, 2);
+		assert_noop!(Assets::accept_ownership(Origin::signed(3), 0), Error::<Test>::Unknown);
+
+		// The deposit stays with the previous owner.
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+	});
+}
+
+#[test]
+fn deposits_follow_the_depositor_after_force_transfer_ownership() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_ok!(Assets::force_transfer_ownership(Origin::root(), 0, 2));
+		assert_eq!(Balances::reserved_balance(&1), 22);
+
+		// Destroying the asset returns the deposits to the account they were reserved from.
+		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Assets::destroy(Origin::signed(2), 0, w));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::free_balance(&1), 100);
+
+		// Transferring the ownership on moves the deposits from the previous owner.
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
+		assert_ok!(Assets::force_transfer_ownership(Origin::root(), 0, 2));
+		assert_ok!(Assets::transfer_ownership(Origin::signed(2), 0, 3));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&3), 1);
+		assert_eq!(Asset::<Test>::get(0).unwrap().depositor, 3);
+
+		// Changing the metadata moves the deposits to the new owner.
+		assert_ok!(Assets::force_transfer_ownership(Origin::root(), 0, 2));
+		assert_ok!(Assets::set_metadata(Origin::signed(2), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_eq!(Balances::reserved_balance(&3), 0);
+		assert_eq!(Balances::reserved_balance(&2), 22);
+		assert_ok!(Assets::force_transfer_ownership(Origin::root(), 0, 1));
+		assert_ok!(Assets::clear_metadata(Origin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(&2), 1);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn two_step_ownership_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_eq!(Balances::reserved_balance(&1), 22);
+
+		assert_noop!(Assets::propose_new_owner(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::accept_ownership(Origin::signed(2), 0), Error::<Test>::Unknown);
+		assert_eq!(Assets::pending_owner(0), None);
+		assert_ok!(Assets::propose_new_owner(Origin::signed(1), 0, 3));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::PendingOwnerChanged(0, Some(3))));
+		assert_eq!(Assets::pending_owner(0), Some(3));
+		// A new proposal replaces the old one, and no deposit moves yet.
+		assert_ok!(Assets::propose_new_owner(Origin::signed(1), 0, 2));
+		assert_eq!(Assets::pending_owner(0), Some(2));
+		assert_noop!(Assets::accept_ownership(Origin::signed(3), 0), Error::<Test>::NoPermission);
+		assert_eq!(Balances::reserved_balance(&1), 22);
+
+		assert_ok!(Assets::accept_ownership(Origin::signed(2), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::OwnershipAccepted(0, 2)));
+		assert_eq!(Asset::<Test>::get(0).unwrap().owner, 2);
+		assert_eq!(Assets::pending_owner(0), None);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 22);
+		assert_noop!(Assets::accept_ownership(Origin::signed(2), 0), Error::<Test>::Unknown);
+
+		assert_ok!(Assets::propose_new_owner(Origin::signed(2), 0, 1));
+		assert_noop!(Assets::cancel_proposed_owner(Origin::signed(1), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::cancel_proposed_owner(Origin::signed(2), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::PendingOwnerChanged(0, None)));
+		assert_eq!(Assets::pending_owner(0), None);
+		assert_noop!(Assets::accept_ownership(Origin::signed(1), 0), Error::<Test>::Unknown);
+		assert_noop!(Assets::cancel_proposed_owner(Origin::signed(2), 0), Error::<Test>::Unknown);
+
+		// Transferring ownership directly clears any pending proposal.
+		assert_ok!(Assets::propose_new_owner(Origin::signed(2), 0, 3));
+		assert_ok!(Assets::transfer_ownership(Origin::signed(2), 0, 1));
+		assert_noop!(Assets::accept_ownership(Origin::signed(3), 0), Error::<Test>::Unknown);
+	});
+}
+
+#[test]
+fn renounce_ownership_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_ok!(Assets::propose_new_owner(Origin::signed(1), 0, 2));
+		assert_eq!(Balances::reserved_balance(&1), 22);
+
+		assert_noop!(Assets::renounce_ownership(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::renounce_ownership(Origin::signed(1), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::OwnershipRenounced(0)));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Asset::<Test>::get(0).unwrap().owner, 0);
+
+		assert_noop!(Assets::accept_ownership(Origin::signed(2), 0), Error::<Test>::Unknown);
+		assert_noop!(Assets::transfer_ownership(Origin::signed(1), 0, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::set_team(Origin::signed(1), 0, 2, 2, 2), Error::<Test>::NoPermission);
+		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
+		assert_noop!(Assets::destroy(Origin::signed(1), 0, w), Error::<Test>::NoPermission);
+		// The team keeps its roles, and the force origin may still manage the asset.
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::force_asset_status(Origin::root(), 0, 2, 2, 2, 2, 1, true, false, None, false));
+		assert_eq!(Asset::<Test>::get(0).unwrap().owner, 2);
+	});
+}
+
+#[test]
+fn set_team_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::set_team(Origin::signed(1), 0, 2, 3, 4));
+
+		assert_ok!(Assets::mint(Origin::signed(2), 0, 2, 100));
+		assert_ok!(Assets::freeze(Origin::signed(4), 0, 2));
+		assert_ok!(Assets::thaw(Origin::signed(3), 0, 2));
+		assert_ok!(Assets::force_transfer(Origin::signed(3), 0, 2, 3, 100));
+		assert_ok!(Assets::burn(Origin::signed(3), 0, 3, 100));
+	});
+}
+
+#[test]
+fn asset_details_should_work() {
+	new_test_ext().execute_with(|| {
+		assert!(Assets::asset_details(0).is_none());
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, Some(500)));
+		assert_ok!(Assets::set_team(Origin::signed(1), 0, 2, 3, 4));
+		assert_ok!(Assets::mint(Origin::signed(2), 0, 5, 100));
+		assert_ok!(Assets::freeze_asset(Origin::signed(4), 0));
+
+		let details = Assets::asset_details(0).unwrap();
+		assert_eq!(
+			(details.owner(), details.issuer(), details.admin(), details.freezer()),
+			(&1, &2, &3, &4),
+		);
+		assert_eq!((details.supply(), details.max_supply(), details.min_balance()), (100, Some(500), 10));
+		assert_eq!(details.deposit(), 0);
+		assert!(details.is_sufficient());
+		assert_eq!((details.accounts(), details.sufficients(), details.approvals()), (1, 1, 0));
+		assert!(details.is_frozen());
+		assert_eq!(details.status(), AssetStatus::Live);
+	});
+}
+
+#[test]
+fn minimum_balance_of_should_work() {
+	use frame_support::traits::tokens::fungibles::Inspect;
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::minimum_balance_of(0), None);
+		assert_eq!(<Assets as Inspect<_>>::minimum_balance(0), 0);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 7, None));
+		assert_eq!(Assets::minimum_balance_of(0), Some(7));
+	});
+}
+
+#[test]
+fn asset_and_account_is_frozen_should_work() {
+	new_test_ext().execute_with(|| {
+		assert!(!Assets::asset_is_frozen(0));
+		assert!(!Assets::account_is_frozen(0, &1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1));
+		assert!(Assets::account_is_frozen(0, &1));
+		assert!(!Assets::account_is_frozen(0, &2));
+		assert!(!Assets::asset_is_frozen(0));
+
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0));
+		assert!(Assets::asset_is_frozen(0));
+		assert!(!Assets::account_is_frozen(0, &2));
+
+		assert_ok!(Assets::thaw_asset(Origin::signed(1), 0));
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
+		assert!(!Assets::asset_is_frozen(0));
+		assert!(!Assets::account_is_frozen(0, &1));
+	});
+}
+
+#[test]
+fn destroy_witness_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::destroy_witness(0), None);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		Balances::make_free_balance_be(&1, 10);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+
+		let witness = Assets::destroy_witness(0).unwrap();
+		assert_eq!((witness.accounts(), witness.sufficients(), witness.approvals()), (2, 2, 1));
+		assert_ok!(Assets::destroy(Origin::root(), 0, witness));
+	});
+}
+
+#[test]
+fn transferring_to_frozen_account_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::balance(0, 2), 100);
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::balance(0, 2), 150);
+	});
+}
+
+#[test]
+fn transferring_amount_more_than_available_balance_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, u64::max_value()));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 1, 50), Error::<Test>::BalanceLow);
+		assert_noop!(Assets::transfer(Origin::signed(2), 0, 1, 51), Error::<Test>::BalanceLow);
+	});
+}
+
+#[test]
+fn transferring_less_than_one_unit_is_fine() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Transferred(0, 1, 2, 0)));
+	});
+}
+
+#[test]
+fn transferring_more_units_than_total_supply_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 101), Error::<Test>::BalanceLow);
+	});
+}
+
+#[test]
+fn burning_asset_balance_with_positive_balance_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, u64::max_value()));
+		assert_eq!(Assets::balance(0, 1), 0);
+	});
+}
+
+#[test]
+fn burning_asset_balance_with_zero_balance_does_nothing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 2, u64::max_value()));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_eq!(Assets::total_supply(0), 100);
+	});
+}
+
+#[test]
+fn soft_burn_should_leave_min_balance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_noop!(Assets::soft_burn(Origin::signed(2), 0, 2, 50), Error::<Test>::NoPermission);
+
+		assert_ok!(Assets::soft_burn(Origin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_ok!(Assets::soft_burn(Origin::signed(1), 0, 2, u64::max_value()));
+		assert_eq!(Assets::balance(0, 2), 10);
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Burned(0, 2, 40)));
+
+		// Nothing more can be burned, and neither from an account which does not exist.
+		assert_ok!(Assets::soft_burn(Origin::signed(1), 0, 2, u64::max_value()));
+		assert_ok!(Assets::soft_burn(Origin::signed(1), 0, 3, u64::max_value()));
+		assert_eq!(Assets::balance(0, 2), 10);
+		assert_eq!(Assets::total_supply(0), 10);
+	});
+}
+
+#[test]
+fn total_frozen_balance_should_sum_frozen_amounts() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::total_frozen_balance(0), 0);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 3, 20));
+		set_frozen_balance(0, 1, 30);
+		set_frozen_balance(0, 2, 50);
+		// Only the balance itself can be frozen.
+		set_frozen_balance(0, 3, 50);
+
+		assert_eq!(Assets::total_frozen_balance(0), 100);
+		assert_eq!(Assets::total_transferable_balance(0), 120);
+	});
+}
+
+#[test]
+fn approvals_should_expire_in_on_idle() {
+	use frame_support::traits::OnIdle;
+	new_test_ext().execute_with(|| {
+		ApprovalExpiry::set(Some(10));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		Balances::make_free_balance_be(&1, 10);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		System::set_block_number(5);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 50));
+		assert_eq!(Balances::reserved_balance(&1), 2);
+
+		// Topping up the first approval restarts its expiry period.
+		System::set_block_number(8);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 10));
+
+		// No sweeping happens without spare weight.
+		assert_eq!(Assets::on_idle(20, 0), 0);
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 2);
+
+		let idle = Assets::on_idle(15, Weight::max_value());
+		assert!(idle > 0);
+		assert_eq!(Assets::approval_allowance(0, &1, &3), 0);
+		assert_eq!(Assets::approval_allowance(0, &1, &2), 60);
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::ApprovalExpired(0, 1, 3)));
+
+		Assets::on_idle(18, Weight::max_value());
+		assert_eq!(Assets::approval_allowance(0, &1, &2), 0);
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 0);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn removing_an_approval_clears_its_expiry() {
+	new_test_ext().execute_with(|| {
+		ApprovalExpiry::set(Some(10));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 10);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 4, 50));
+		assert_eq!(ApprovalExpiryQueue::<Test>::iter().count(), 3);
+
+		assert_ok!(Assets::cancel_approval(Origin::signed(1), 0, 2));
+		assert_ok!(Assets::force_cancel_approval(Origin::signed(1), 0, 1, 3));
+		assert_ok!(Assets::transfer_approved(Origin::signed(4), 0, 1, 5, 50));
+		assert_eq!(ApprovalExpiresAt::<Test>::iter().count(), 0);
+		assert_eq!(ApprovalExpiryQueue::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn transfer_ownership_by_operator_should_work() {
+	use sp_runtime::testing::TestSignature;
+	new_test_ext().execute_with(|| {
+		let sign = |signer: u64, new_owner: u64, nonce: u32, expiry: u64| {
+			TestSignature(signer, Assets::transfer_ownership_payload(0, &new_owner, nonce, expiry))
+		};
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+
+		// The payload is tagged and bound to the chain and pallet instance.
+		let untagged = TestSignature(1, (0u32, 2u64, 0u32, 10u64).encode());
+		assert_noop!(
+			Assets::transfer_ownership_by_operator(Origin::signed(3), 0, 2, 10, untagged),
+			Error::<Test>::BadSignature,
+		);
+		assert!(Assets::transfer_ownership_payload(0, &2, 0, 10).starts_with(&TRANSFER_OWNERSHIP_TAG.encode()));
+		// Only the owner may authorise, for the current nonce and before the expiry.
+		assert_noop!(
+			Assets::transfer_ownership_by_operator(Origin::signed(3), 0, 2, 10, sign(2, 2, 0, 10)),
+			Error::<Test>::BadSignature,
+		);
+		assert_noop!(
+			Assets::transfer_ownership_by_operator(Origin::signed(3), 0, 2, 10, sign(1, 2, 1, 10)),
+			Error::<Test>::BadSignature,
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			Assets::transfer_ownership_by_operator(Origin::signed(3), 0, 2, 10, sign(1, 2, 0, 10)),
+			Error::<Test>::AuthorisationExpired,
+		);
+
+		assert_ok!(
+			Assets::transfer_ownership_by_operator(Origin::signed(3), 0, 2, 20, sign(1, 2, 0, 20))
+		);
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!((details.owner, details.owner_nonce()), (2, 1));
+		assert_eq!(Balances::reserved_balance(&2), 1);
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::OwnerChanged(0, 2)));
+
+		// The authorisation cannot be replayed.
+		assert_ok!(Assets::transfer_ownership(Origin::signed(2), 0, 1));
+		assert_noop!(
+			Assets::transfer_ownership_by_operator(Origin::signed(3), 0, 2, 20, sign(1, 2, 0, 20)),
+			Error::<Test>::BadSignature,
+		);
+
+		// The nonce is never wrapped back to one already used.
+		Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().owner_nonce = u32::max_value());
+		assert_noop!(
+			Assets::transfer_ownership_by_operator(
+				Origin::signed(3), 0, 2, 20, sign(1, 2, u32::max_value(), 20)
+			),
+			ArithmeticError::Overflow,
+		);
+	});
+}
+
+#[test]
+fn set_asset_sufficient_should_only_affect_new_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(
+			Assets::set_asset_sufficient(Origin::signed(2), 0, false),
+			Error::<Test>::NoPermission,
+		);
+
+		assert_ok!(Assets::set_asset_sufficient(Origin::signed(1), 0, false));
+		System::assert_last_event(mock::Event::pallet_assets(
+			crate::Event::SufficiencyChanged(0, false)
+		));
+		assert!(!Asset::<Test>::get(0).unwrap().is_sufficient());
+
+		// The existing account keeps its sufficient reference, but new ones need a provider.
+		assert_eq!(System::sufficients(&1), 1);
+		assert_noop!(
+			Assets::transfer(Origin::signed(1), 0, 2, 50),
+			Error::<Test>::InsufficientProviders,
+		);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(System::sufficients(&2), 0);
+		assert_eq!(Asset::<Test>::get(0).unwrap().sufficients(), 1);
+
+		// Removing the old account releases its sufficient reference.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(System::sufficients(&1), 0);
+		assert_eq!(Asset::<Test>::get(0).unwrap().sufficients(), 0);
+
+		// Only the force origin may make the asset sufficient again.
+		assert_noop!(
+			Assets::set_asset_sufficient(Origin::signed(1), 0, true),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Assets::force_asset_status(Origin::root(), 0, 1, 1, 1, 1, 1, true, false, None, false));
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 10));
+		assert_eq!(System::sufficients(&3), 1);
+	});
+}
+
+#[test]
+fn aggregated_balance_should_track_held_assets() {
+	new_test_ext().execute_with(|| {
+		for id in 0..3 {
+			assert_ok!(Assets::force_create(Origin::root(), id, 1, true, 1, None));
+			assert_ok!(Assets::mint(Origin::signed(1), id, 2, 10 * (id as u64 + 1)));
+		}
+		let sorted = |mut balances: Vec<(u32, u64)>| { balances.sort(); balances };
+		assert_eq!(sorted(Assets::aggregated_balance(&2)), vec![(0, 10), (1, 20), (2, 30)]);
+		assert!(Assets::aggregated_balance(&3).is_empty());
+
+		// Accounts which die leave the index.
+		assert_ok!(Assets::transfer(Origin::signed(2), 1, 3, 20));
+		assert_eq!(sorted(Assets::aggregated_balance(&2)), vec![(0, 10), (2, 30)]);
+		assert_eq!(Assets::aggregated_balance(&3), vec![(1, 20)]);
+		assert_ok!(Assets::transfer(Origin::signed(3), 1, 2, 20));
+		assert!(AccountAssets::<Test>::iter_prefix(&3).next().is_none());
+
+		// Any number of assets may be held, so dust in many assets can't block receiving another.
+		for id in 3..40 {
+			assert_ok!(Assets::force_create(Origin::root(), id, 1, true, 1, None));
+			assert_ok!(Assets::mint(Origin::signed(1), id, 3, 1));
+		}
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 40, 1, true, 1, None));
+		assert_ok!(Assets::touch(Origin::signed(3), 40));
+		assert_eq!(Assets::aggregated_balance(&3).len(), 37);
+	});
+}
+
+#[test]
+fn create_with_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		// Oversized metadata or an insufficient deposit leaves no asset behind.
+		assert_noop!(
+			Assets::create_with_metadata(Origin::signed(1), 0, 1, 1, None, vec![0u8; 100], vec![0u8; 10], 12),
+			Error::<Test>::BadMetadata,
+		);
+		assert_noop!(
+			Assets::create_with_metadata(Origin::signed(2), 0, 1, 1, None, vec![0u8; 10], vec![0u8; 10], 12),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+
+		assert_ok!(Assets::create_with_metadata(Origin::signed(1), 0, 1, 1, None, vec![0u8; 10], vec![0u8; 5], 12));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::Created(0, 1, 1)));
+		System::assert_last_event(mock::Event::pallet_assets(
+			crate::Event::MetadataSet(0, vec![0u8; 10], vec![0u8; 5], 12, false, None, None)
+		));
+		assert_eq!(Balances::reserved_balance(&1), 1 + 16);
+		assert_eq!(Metadata::<Test>::get(0).deposit, 16);
+		assert_noop!(
+			Assets::create_with_metadata(Origin::signed(1), 0, 1, 1, None, vec![0u8; 10], vec![0u8; 5], 12),
+			Error::<Test>::InUse,
+		);
+
+		// The deposits are returned as usual.
+		assert_ok!(Assets::clear_metadata(Origin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+
+		assert_ok!(Assets::force_create_with_metadata(
+			Origin::root(), 1, 2, true, 1, None, vec![0u8; 10], vec![0u8; 5], 12, true,
+		));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::ForceCreated(1, 2)));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert!(Asset::<Test>::get(1).unwrap().is_sufficient);
+		assert!(Metadata::<Test>::get(1).is_frozen);
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(2), 1, vec![0u8; 10], vec![0u8; 5], 12),
+			Error::<Test>::NoPermission,
+		);
+	});
+}
+
+#[test]
+fn create_auto_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_noop!(Assets::create_auto(Origin::signed(1), 1, 0), Error::<Test>::MinBalanceZero);
+		assert_ok!(Assets::create_auto(Origin::signed(1), 2, 1));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Created(0, 1, 2)));
+		assert_eq!(NextAssetId::<Test>::get(), 1);
+
+		// Identifiers already in use are skipped.
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, true, 1, None));
+		assert_ok!(Assets::force_create(Origin::root(), 2, 1, true, 1, None));
+		assert_ok!(Assets::create_auto(Origin::signed(1), 1, 1));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Created(3, 1, 1)));
+		assert_eq!(NextAssetId::<Test>::get(), 4);
+		assert_eq!(Balances::reserved_balance(&1), 2);
+
+		// The counter wraps on overflow.
+		NextAssetId::<Test>::put(u32::max_value());
+		assert_ok!(Assets::create_auto(Origin::signed(1), 1, 1));
+		assert!(Asset::<Test>::contains_key(u32::max_value()));
+		assert_eq!(NextAssetId::<Test>::get(), 0);
+		assert_ok!(Assets::create_auto(Origin::signed(1), 1, 1));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Created(4, 1, 1)));
+
+		// Only so many identifiers are tried.
+		for id in 5..5 + MAX_ASSET_ID_PROBES {
+			assert_ok!(Assets::force_create(Origin::root(), id, 1, true, 1, None));
+		}
+		assert_noop!(Assets::create_auto(Origin::signed(1), 1, 1), Error::<Test>::InUse);
+		let w = Asset::<Test>::get(20).unwrap().destroy_witness();
+		assert_ok!(Assets::destroy(Origin::root(), 20, w));
+		assert_ok!(Assets::create_auto(Origin::signed(1), 1, 1));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Created(20, 1, 1)));
+	});
+}
+
+#[test]
+fn migrate_asset_id_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 20));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+
+		assert_noop!(Assets::migrate_asset_id(Origin::signed(1), 0, 2), DispatchError::BadOrigin);
+		assert_noop!(Assets::migrate_asset_id(Origin::root(), 0, 1), Error::<Test>::InUse);
+		assert_noop!(Assets::migrate_asset_id(Origin::root(), 3, 2), Error::<Test>::Unknown);
+		assert_ok!(Assets::schedule_transfer(Origin::signed(1), 0, 2, 10, 5));
+		assert_noop!(Assets::migrate_asset_id(Origin::root(), 0, 2), Error::<Test>::InUse);
+		assert_ok!(Assets::cancel_scheduled_transfer(Origin::signed(1), 0));
+
+		assert_ok!(Assets::migrate_asset_id(Origin::root(), 0, 2));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::AssetIdMigrated(0, 2)));
+		assert!(!Asset::<Test>::contains_key(0));
+		assert!(!Metadata::<Test>::contains_key(0));
+		assert_eq!(Account::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(Assets::balance(2, 1), 100);
+		assert_eq!(Assets::balance(2, 2), 50);
+		assert_eq!(Assets::total_supply(2), 150);
+		assert_eq!(Assets::approval_allowance(2, &1, &3), 20);
+		assert_eq!(Metadata::<Test>::get(2).decimals, 12);
+		assert_ok!(Assets::transfer_approved(Origin::signed(3), 2, 1, 3, 20));
+		assert_ok!(Assets::transfer(Origin::signed(2), 2, 1, 50));
+		assert_eq!(Assets::balance(2, 1), 130);
+	});
+}
+
+#[test]
+fn set_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		// Cannot add metadata to unknown asset
+		assert_noop!(
+				Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12),
 				Error::<Test>::Unknown,
 			);
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		// Cannot add metadata to unowned asset
 		assert_noop!(
 				Assets::set_metadata(Origin::signed(2), 0, vec![0u8; 10], vec![0u8; 10], 12),
@@ -470,25 +2101,96 @@ fn set_metadata_should_work() {
 		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 15], 12));
 		assert_eq!(Balances::free_balance(&1), 4);
 
-		// Cannot over-reserve
+		// Cannot over-reserve
+		assert_noop!(
+				Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 20], vec![0u8; 20], 12),
+				BalancesError::<Test, _>::InsufficientBalance,
+			);
+
+		// Clear Metadata
+		assert!(Metadata::<Test>::contains_key(0));
+		assert_noop!(Assets::clear_metadata(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_noop!(Assets::clear_metadata(Origin::signed(1), 1), Error::<Test>::Unknown);
+		assert_ok!(Assets::clear_metadata(Origin::signed(1), 0));
+		assert!(!Metadata::<Test>::contains_key(0));
+		System::assert_last_event(mock::Event::pallet_assets(
+			crate::Event::MetadataCleared(0, vec![0u8; 10])
+		));
+	});
+}
+
+#[test]
+fn set_metadata_extended_should_work() {
+	new_test_ext().execute_with(|| {
+		let hash = Some([7u8; 32]);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		Balances::make_free_balance_be(&1, 30);
+		assert_noop!(
+			Assets::set_metadata_extended(Origin::signed(2), 0, vec![0u8; 10], vec![0u8; 5], 12, hash),
+			Error::<Test>::NoPermission,
+		);
+
+		// The deposit is the same as for `set_metadata`.
+		assert_ok!(
+			Assets::set_metadata_extended(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 5], 12, hash)
+		);
+		assert_eq!(Balances::free_balance(&1), 14);
+		assert_eq!(Metadata::<Test>::get(0).image_hash(), Some(&[7u8; 32]));
+		System::assert_last_event(mock::Event::pallet_assets(
+			crate::Event::MetadataSet(0, vec![0u8; 10], vec![0u8; 5], 12, false, hash, None)
+		));
+
+		// Plain `set_metadata` clears the hash.
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 5], 12));
+		assert_eq!(Metadata::<Test>::get(0).image_hash(), None);
+	});
+}
+
+#[test]
+fn set_metadata_url_should_work() {
+	new_test_ext().execute_with(|| {
+		let uri = b"ipfs://token.json".to_vec();
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		Balances::make_free_balance_be(&1, 50);
+		assert_noop!(Assets::set_metadata_url(Origin::signed(1), 0, Some(uri.clone())), Error::<Test>::Unknown);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 5], 12));
+		assert_eq!(Metadata::<Test>::get(0).deposit, 16);
 		assert_noop!(
-				Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 20], vec![0u8; 20], 12),
-				BalancesError::<Test, _>::InsufficientBalance,
-			);
+			Assets::set_metadata_url(Origin::signed(2), 0, Some(uri.clone())),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(
+			Assets::set_metadata_url(Origin::signed(1), 0, Some(vec![0u8; 257])),
+			Error::<Test>::BadMetadata,
+		);
 
-		// Clear Metadata
-		assert!(Metadata::<Test>::contains_key(0));
-		assert_noop!(Assets::clear_metadata(Origin::signed(2), 0), Error::<Test>::NoPermission);
-		assert_noop!(Assets::clear_metadata(Origin::signed(1), 1), Error::<Test>::Unknown);
-		assert_ok!(Assets::clear_metadata(Origin::signed(1), 0));
-		assert!(!Metadata::<Test>::contains_key(0));
+		// The URI is paid for by the byte on top of the rest of the metadata.
+		assert_ok!(Assets::set_metadata_url(Origin::signed(1), 0, Some(uri.clone())));
+		System::assert_last_event(mock::Event::pallet_assets(
+			crate::Event::MetadataSet(0, vec![0u8; 10], vec![0u8; 5], 12, false, None, Some(uri.clone()))
+		));
+		assert_eq!(Metadata::<Test>::get(0).uri().map(|u| u.to_vec()), Some(uri.clone()));
+		assert_eq!(Metadata::<Test>::get(0).deposit, 16 + 17);
+		assert_eq!(Balances::reserved_balance(&1), 16 + 17);
+
+		// Setting the rest of the metadata keeps the URI and its deposit.
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 5], vec![0u8; 5], 12));
+		assert_eq!(Metadata::<Test>::get(0).uri().map(|u| u.to_vec()), Some(uri));
+		assert_eq!(Balances::reserved_balance(&1), 11 + 17);
+
+		assert_ok!(Assets::set_metadata_url(Origin::signed(1), 0, None));
+		assert_eq!(Metadata::<Test>::get(0).uri(), None);
+		assert_eq!(Balances::reserved_balance(&1), 11);
+
+		assert_ok!(Assets::freeze_metadata(Origin::signed(1), 0));
+		assert_noop!(Assets::set_metadata_url(Origin::signed(1), 0, None), Error::<Test>::NoPermission);
 	});
 }
 
 #[test]
 fn freezer_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
 		assert_eq!(Assets::balance(0, 1), 100);
 
@@ -523,12 +2225,151 @@ fn freezer_should_work() {
 	});
 }
 
+#[test]
+fn reducible_balance_should_respect_keep_alive() {
+	use frame_support::traits::tokens::fungibles::Inspect;
+	new_test_ext().execute_with(|| {
+		assert_eq!(<Assets as Inspect<u64>>::reducible_balance(0, &1, false), 0);
+
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(<Assets as Inspect<u64>>::reducible_balance(0, &1, false), 100);
+		assert_eq!(<Assets as Inspect<u64>>::reducible_balance(0, &1, true), 90);
+
+		// A frozen balance always keeps the account alive.
+		set_frozen_balance(0, 1, 50);
+		assert_eq!(<Assets as Inspect<u64>>::reducible_balance(0, &1, false), 40);
+		assert_eq!(<Assets as Inspect<u64>>::reducible_balance(0, &1, true), 40);
+		clear_frozen_balance(0, 1);
+
+		// Nothing is reducible from a frozen account.
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1));
+		assert_eq!(<Assets as Inspect<u64>>::reducible_balance(0, &1, false), 0);
+	});
+}
+
+#[test]
+fn asset_details_builder_should_work() {
+	let details: AssetDetails<u64, u64, u64> = AssetDetailsBuilder::new(1)
+		.admin(2)
+		.min_balance(10)
+		.is_sufficient(true)
+		.deposit(5)
+		.build();
+	assert_eq!((details.owner(), details.issuer(), details.admin(), details.freezer()), (&1, &1, &2, &1));
+	assert_eq!((details.min_balance(), details.deposit(), details.supply()), (10, 5, 0));
+	assert!(details.is_sufficient());
+	assert_eq!(details.status(), AssetStatus::Live);
+}
+
+#[test]
+#[should_panic(expected = "Min balance should not be zero")]
+fn asset_details_builder_should_refuse_zero_min_balance() {
+	let _: AssetDetails<u64, u64, u64> = AssetDetailsBuilder::new(1).build();
+}
+
+#[test]
+fn inherent_reducible_balance_should_report_errors() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Assets::reducible_balance(0, &1, false), Error::<Test>::Unknown);
+
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::reducible_balance(0, &1, false), Ok(100));
+		assert_eq!(Assets::reducible_balance(0, &1, true), Ok(90));
+
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1));
+		assert_noop!(Assets::reducible_balance(0, &1, false), Error::<Test>::AccountFrozen);
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0));
+		assert_noop!(Assets::reducible_balance(0, &1, false), Error::<Test>::AssetClassFrozen);
+	});
+}
+
+#[test]
+fn inspect_asset_exists_and_is_account_frozen_should_work() {
+	use frame_support::traits::tokens::fungibles::Inspect;
+	new_test_ext().execute_with(|| {
+		assert!(!<Assets as Inspect<u64>>::asset_exists(0));
+		assert!(!<Assets as Inspect<u64>>::is_account_frozen(0, &1));
+
+		// An asset exists even with no issuance.
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert!(<Assets as Inspect<u64>>::asset_exists(0));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1));
+		assert!(<Assets as Inspect<u64>>::is_account_frozen(0, &1));
+		assert!(!<Assets as Inspect<u64>>::is_account_frozen(0, &2));
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
+		assert!(!<Assets as Inspect<u64>>::is_account_frozen(0, &1));
+
+		// Freezing the asset class freezes every account.
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0));
+		assert!(<Assets as Inspect<u64>>::is_account_frozen(0, &2));
+	});
+}
+
+#[test]
+fn roles_should_work() {
+	use frame_support::traits::tokens::fungibles::Roles;
+	new_test_ext().execute_with(|| {
+		assert_eq!(<Assets as Roles<u64>>::owner(0), None);
+		assert_noop!(<Assets as Roles<u64>>::ensure_owner(0, &1), DispatchError::BadOrigin);
+
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::set_team(Origin::signed(1), 0, 2, 3, 4));
+		assert_eq!(<Assets as Roles<u64>>::owner(0), Some(1));
+		assert_eq!(<Assets as Roles<u64>>::issuer(0), Some(2));
+		assert_eq!(<Assets as Roles<u64>>::admin(0), Some(3));
+		assert_eq!(<Assets as Roles<u64>>::freezer(0), Some(4));
+		assert_ok!(<Assets as Roles<u64>>::ensure_owner(0, &1));
+		assert_noop!(<Assets as Roles<u64>>::ensure_owner(0, &2), DispatchError::BadOrigin);
+	});
+}
+
+#[test]
+fn extra_mutator_try_commit_should_fail_for_dead_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		let mut extra = Assets::adjust_extra(0, &1).unwrap();
+		*extra = ();
+		assert_eq!(extra.try_commit(), Ok(()));
+
+		*extra = ();
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 100));
+		assert_eq!(extra.try_commit(), Err(()));
+		// Nothing is left pending, nor does dropping with pending changes panic.
+		assert_eq!(extra.commit(), Ok(()));
+		*extra = ();
+		drop(extra);
+		assert!(!Account::<Test>::contains_key(0, 1));
+	});
+}
+
+#[test]
+fn extra_mutator_revert_should_not_recreate_dead_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		let mut extra = Assets::adjust_extra(0, &1).unwrap();
+		*extra = ();
+		assert_eq!(extra.try_commit(), Ok(()));
+
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, 100));
+		assert!(!Account::<Test>::contains_key(0, 1));
+		assert_eq!(extra.revert(), Err(()));
+		assert!(!Account::<Test>::contains_key(0, 1));
+	});
+}
+
 #[test]
 fn imbalances_should_work() {
 	use frame_support::traits::tokens::fungibles::Balanced;
 
 	new_test_ext().execute_with(|| {
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 
 		let imb = Assets::issue(0, 100);
 		assert_eq!(Assets::total_supply(0), 100);
@@ -551,7 +2392,7 @@ fn imbalances_should_work() {
 fn force_metadata_should_work() {
 	new_test_ext().execute_with(|| {
 		//force set metadata works
-		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
 		assert_ok!(Assets::force_set_metadata(Origin::root(), 0, vec![0u8; 10], vec![0u8; 10], 8, false));
 		assert!(Metadata::<Test>::contains_key(0));
 
@@ -581,23 +2422,73 @@ fn force_metadata_should_work() {
 		assert!(Metadata::<Test>::contains_key(0));
 		assert_ok!(Assets::force_clear_metadata(Origin::root(), 0));
 		assert!(!Metadata::<Test>::contains_key(0));
+		System::assert_last_event(mock::Event::pallet_assets(
+			crate::Event::MetadataCleared(0, vec![1u8; 10])
+		));
 
 		//Error handles clearing non-existent asset class
 		assert_noop!(Assets::force_clear_metadata(Origin::root(), 1), Error::<Test>::Unknown);
 	});
 }
 
+#[test]
+fn metadata_getter_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
+		assert_eq!(Assets::metadata(0), None);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, b"Token".to_vec(), b"TKN".to_vec(), 12));
+		let metadata = Assets::metadata(0).unwrap();
+		assert_eq!(&metadata.name()[..], b"Token");
+		assert_eq!(&metadata.symbol()[..], b"TKN");
+		assert_eq!(metadata.decimals(), 12);
+		assert!(!metadata.is_frozen());
+	});
+}
+
+#[test]
+fn freeze_and_thaw_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, None));
+		assert_noop!(Assets::freeze_metadata(Origin::signed(1), 0), Error::<Test>::Unknown);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		let metadata = Metadata::<Test>::get(0);
+
+		assert_noop!(Assets::freeze_metadata(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::freeze_metadata(Origin::signed(1), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::MetadataFrozen(0)));
+		assert!(Metadata::<Test>::get(0).is_frozen);
+		assert_eq!(Metadata::<Test>::get(0).name, metadata.name);
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(1), 0, vec![1u8; 10], vec![1u8; 10], 12),
+			Error::<Test>::NoPermission,
+		);
+
+		// Only the force origin may thaw.
+		assert_noop!(Assets::thaw_metadata(Origin::signed(1), 0), DispatchError::BadOrigin);
+		assert_ok!(Assets::thaw_metadata(Origin::root(), 0));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::MetadataThawed(0)));
+		assert_eq!(Metadata::<Test>::get(0), metadata);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![1u8; 10], vec![1u8; 10], 12));
+
+		assert_ok!(Assets::freeze_metadata(Origin::root(), 0));
+		assert!(Metadata::<Test>::get(0).is_frozen);
+		assert_noop!(Assets::thaw_metadata(Origin::root(), 1), Error::<Test>::Unknown);
+	});
+}
+
 #[test]
 fn force_asset_status_should_work(){
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&1, 10);
 		Balances::make_free_balance_be(&2, 10);
-		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 30));
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 30, None));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 50));
 		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 150));
 
 		//force asset status to change min_balance > balance
-		assert_ok!(Assets::force_asset_status(Origin::root(), 0, 1, 1, 1, 1, 100, true, false));
+		assert_ok!(Assets::force_asset_status(Origin::root(), 0, 1, 1, 1, 1, 100, true, false, None, false));
 		assert_eq!(Assets::balance(0, 1), 50);
 
 		//account can recieve assets for balance < min_balance
@@ -613,15 +2504,539 @@ fn force_asset_status_should_work(){
 
 		//force asset status will not execute for non-existent class
 		assert_noop!(
-			Assets::force_asset_status(Origin::root(), 1, 1, 1, 1, 1, 90, true, false),
+			Assets::force_asset_status(Origin::root(), 1, 1, 1, 1, 1, 90, true, false, None, false),
 			Error::<Test>::Unknown
 		);
 
 		//account drains to completion when funds dip below min_balance
-		assert_ok!(Assets::force_asset_status(Origin::root(), 0, 1, 1, 1, 1, 110, true, false));
+		assert_ok!(Assets::force_asset_status(Origin::root(), 0, 1, 1, 1, 1, 110, true, false, None, false));
 		assert_ok!(Assets::transfer(Origin::signed(2), 0, 1, 110));
 		assert_eq!(Assets::balance(0, 1), 200);
 		assert_eq!(Assets::balance(0, 2), 0);
 		assert_eq!(Assets::total_supply(0), 200);
 	});
 }
+
+#[test]
+fn supply_cap_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1, Some(100)));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 60));
+
+		// Cannot mint beyond the cap...
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 2, 41), Error::<Test>::SupplyCapExceeded);
+		// ...but can mint right up to it.
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 40));
+		assert_eq!(Assets::total_supply(0), 100);
+
+		// Only the owner may change the cap, and never to below the current supply.
+		assert_noop!(Assets::set_supply_cap(Origin::signed(2), 0, Some(200)), Error::<Test>::NoPermission);
+		assert_noop!(Assets::set_supply_cap(Origin::signed(1), 0, Some(99)), Error::<Test>::SupplyCapExceeded);
+
+		assert_ok!(Assets::set_supply_cap(Origin::signed(1), 0, Some(150)));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::CapChanged(0, Some(150))));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 50));
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 2, 1), Error::<Test>::SupplyCapExceeded);
+
+		// Burning frees up room under the cap.
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 10));
+
+		// Removing the cap allows unlimited minting again.
+		assert_ok!(Assets::force_set_supply_cap(Origin::root(), 0, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 1_000));
+		assert_eq!(Assets::total_supply(0), 1_150);
+	});
+}
+
+#[test]
+fn burn_excess_above_cap_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 30));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 70));
+		assert_noop!(Assets::burn_excess_above_cap(Origin::signed(1), 0, false), Error::<Test>::NoExcessSupply);
+
+		// The force origin may set the cap below the supply.
+		assert_ok!(Assets::force_set_supply_cap(Origin::root(), 0, Some(50)));
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 1, 1), Error::<Test>::SupplyCapExceeded);
+		assert_noop!(Assets::burn_excess_above_cap(Origin::signed(2), 0, false), Error::<Test>::NoPermission);
+		assert_noop!(Assets::burn_excess_above_cap(Origin::signed(1), 0, false), Error::<Test>::BalanceLow);
+
+		assert_ok!(Assets::burn_excess_above_cap(Origin::signed(1), 0, true));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::SupplyCapEnforced(0, 30)));
+		assert_eq!(Assets::total_supply(0), 70);
+
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 1, 40));
+		assert_ok!(Assets::burn_excess_above_cap(Origin::signed(1), 0, false));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::SupplyCapEnforced(0, 20)));
+		assert_eq!(Assets::total_supply(0), 50);
+		assert_eq!(Assets::balance(0, 1), 20);
+	});
+}
+
+#[test]
+fn set_min_balance_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 30));
+
+		assert_noop!(Assets::set_min_balance(Origin::signed(2), 0, 20, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::set_min_balance(Origin::signed(1), 1, 20, 2), Error::<Test>::Unknown);
+		assert_noop!(Assets::set_min_balance(Origin::signed(1), 0, 0, 2), Error::<Test>::MinBalanceZero);
+		assert_noop!(Assets::set_min_balance(Origin::signed(1), 0, 20, 1), Error::<Test>::BadWitness);
+		// Account 2 only holds 30.
+		assert_noop!(Assets::set_min_balance(Origin::signed(1), 0, 31, 2), Error::<Test>::MinBalanceTooHigh);
+
+		assert_ok!(Assets::set_min_balance(Origin::signed(1), 0, 30, 2));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::MinBalanceChanged(0, 10, 30)));
+		assert_eq!(Asset::<Test>::get(0).unwrap().min_balance, 30);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 3, 29), TokenError::BelowMinimum);
+
+		// Lowering is always possible, without inspecting any account.
+		assert_ok!(Assets::set_min_balance(Origin::signed(1), 0, 1, 0));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 3, 1));
+	});
+}
+
+#[test]
+fn touch_and_refund_should_work() {
+	use frame_support::traits::tokens::fungibles::MutateHold;
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, false, 1, None));
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_noop!(Assets::touch(Origin::signed(2), 1), Error::<Test>::Unknown);
+
+		assert_ok!(Assets::touch(Origin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 10);
+		assert!(Account::<Test>::contains_key(0, &2));
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 1);
+		assert_eq!(Asset::<Test>::get(0).unwrap().sufficients, 1);
+		assert_eq!(System::sufficients(&2), 1);
+		assert_noop!(Assets::touch(Origin::signed(2), 0), Error::<Test>::AlreadyExists);
+
+		// A touched account survives its balance dropping to zero.
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert!(Account::<Test>::contains_key(0, &2));
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 2);
+
+		// Only accounts created with `touch` may be refunded.
+		assert_noop!(Assets::refund(Origin::signed(1), 0, false), Error::<Test>::NoDeposit);
+
+		// The balance must be zero unless burning it is allowed.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 30));
+		assert_noop!(Assets::refund(Origin::signed(2), 0, false), Error::<Test>::BalanceZero);
+		// Burning the balance respects freezes and holds.
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 2));
+		assert_noop!(Assets::refund(Origin::signed(2), 0, true), Error::<Test>::AccountFrozen);
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 2));
+		assert_ok!(<Assets as MutateHold<u64>>::hold(0, &2, 10));
+		assert_noop!(Assets::refund(Origin::signed(2), 0, true), Error::<Test>::BalanceLow);
+		assert_ok!(<Assets as MutateHold<u64>>::release(0, &2, 10, false));
+		set_frozen_balance(0, 2, 10);
+		assert_noop!(Assets::refund(Origin::signed(2), 0, true), Error::<Test>::BalanceLow);
+		clear_frozen_balance(0, 2);
+		assert_ok!(Assets::refund(Origin::signed(2), 0, true));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::Burned(0, 2, 30)));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert!(!Account::<Test>::contains_key(0, &2));
+		assert_eq!(Asset::<Test>::get(0).unwrap().accounts, 1);
+		assert_eq!(Asset::<Test>::get(0).unwrap().sufficients, 0);
+		assert_eq!(System::sufficients(&2), 0);
+		assert_eq!(Assets::total_supply(0), 70);
+		assert_noop!(Assets::refund(Origin::signed(2), 0, true), Error::<Test>::NoDeposit);
+	});
+}
+
+#[test]
+fn destroy_returns_touch_deposits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, false, 1, None));
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::touch(Origin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 10);
+
+		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Assets::destroy(Origin::root(), 0, w));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(System::sufficients(&2), 0);
+	});
+}
+
+#[test]
+fn destroy_returns_approval_deposits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 50));
+		assert_eq!(Balances::reserved_balance(&1), 2);
+
+		let w = Asset::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Assets::destroy(Origin::root(), 0, w));
+		System::assert_last_event(mock::Event::pallet_assets(crate::Event::Destroyed(0, 1, 2, 100)));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Approvals::<Test>::iter_prefix((0,)).count(), 0);
+	});
+}
+
+#[test]
+fn composed_freezers_respect_the_greatest_frozen_balance() {
+	struct Ten;
+	impl FrozenBalance<u32, u64, u64> for Ten {
+		fn frozen_balance(_: u32, _: &u64) -> Option<u64> { Some(10) }
+		fn freeze_reasons(_: u32, _: &u64) -> Vec<(&'static [u8], u64)> { vec![(b"ten", 10)] }
+		fn died(_: u32, _: &u64) {}
+	}
+	struct Fifteen;
+	impl FrozenBalance<u32, u64, u64> for Fifteen {
+		fn frozen_balance(_: u32, _: &u64) -> Option<u64> { Some(15) }
+		fn died(_: u32, _: &u64) {}
+	}
+
+	assert_eq!(<(Ten, Fifteen)>::frozen_balance(0, &1), Some(15));
+	assert_eq!(<(Fifteen, Ten)>::frozen_balance(0, &1), Some(15));
+	assert_eq!(<(Ten, ())>::frozen_balance(0, &1), Some(10));
+	assert_eq!(<((), ()) as FrozenBalance<u32, u64, u64>>::frozen_balance(0, &1), None);
+	assert_eq!(<(Ten, (), Fifteen)>::frozen_balance(0, &1), Some(15));
+
+	// The reasons of every freezer are given, in order.
+	assert_eq!(<(Fifteen, Ten)>::freeze_reasons(0, &1), vec![(&b"ten"[..], 10)]);
+	assert_eq!(<(Ten, (), Ten)>::freeze_reasons(0, &1), vec![(&b"ten"[..], 10), (&b"ten"[..], 10)]);
+
+	new_test_ext().execute_with(|| {
+		// Both freezers are told when an account dies.
+		<(TestFreezer, TestFreezer)>::died(0, &1);
+		assert_eq!(hooks(), vec![Hook::Died(0, 1), Hook::Died(0, 1)]);
+	});
+}
+
+#[test]
+fn enumerating_assets_and_holders_should_work() {
+	use frame_support::traits::tokens::fungibles::InspectEnumerable;
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, true, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 3, 10));
+
+		let mut ids = Assets::asset_ids().collect::<Vec<_>>();
+		ids.sort();
+		assert_eq!(ids, vec![0, 1]);
+
+		let mut holders = Assets::account_balances(0).collect::<Vec<_>>();
+		holders.sort();
+		assert_eq!(holders, vec![(1, 100), (2, 50)]);
+		assert_eq!(Assets::account_balances(1).collect::<Vec<_>>(), vec![(3, 10)]);
+		assert_eq!(Assets::account_balances(2).count(), 0);
+	});
+}
+
+#[test]
+fn migration_to_v2_should_work() {
+	new_test_ext().execute_with(|| {
+		// owner, issuer, admin, freezer, supply, deposit, min_balance, is_sufficient, accounts,
+		// sufficients, approvals, is_frozen
+		let old_details = (1u64, 2u64, 3u64, 4u64, 150u64, 10u64, 5u64, true, 2u32, 2u32, 1u32, true);
+		sp_io::storage::set(&Asset::<Test>::hashed_key_for(0), &old_details.encode());
+		// balance, is_frozen, sufficient, extra
+		sp_io::storage::set(&Account::<Test>::hashed_key_for(0, 1), &(100u64, false, true, ()).encode());
+		sp_io::storage::set(&Account::<Test>::hashed_key_for(0, 2), &(50u64, true, true, ()).encode());
+		let limit = StringLimit::get() as usize;
+		let old_metadata = (3u64, vec![0u8; limit + 10], vec![1u8; 3], 12u8, true);
+		sp_io::storage::set(&Metadata::<Test>::hashed_key_for(0), &old_metadata.encode());
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V1);
+
+		crate::migration::migrate_to_v2::<Test, ()>();
+
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!((details.owner, details.issuer, details.admin, details.freezer), (1, 2, 3, 4));
+		assert_eq!((details.supply, details.deposit, details.min_balance), (150, 10, 5));
+		assert!(details.is_sufficient);
+		assert_eq!((details.accounts, details.sufficients, details.approvals), (2, 2, 1));
+		assert!(details.is_frozen);
+		assert_eq!(details.max_supply, None);
+		assert_eq!(details.depositor, 1);
+		assert_eq!(details.status, AssetStatus::Live);
+		assert_eq!(details.transfer_fee, None);
+		assert!(!details.disable_mint);
+		assert_eq!(details.owner_nonce, 0);
+
+		let account = Account::<Test>::get(0, 1);
+		assert_eq!((account.balance(), account.reserved(), account.is_frozen()), (100, 0, false));
+		let account = Account::<Test>::get(0, 2);
+		assert_eq!((account.balance(), account.reserved(), account.is_frozen()), (50, 0, true));
+		assert_eq!(account.deposit, None);
+		assert!(AccountAssets::<Test>::contains_key(&1, 0));
+		assert!(AccountAssets::<Test>::contains_key(&2, 0));
+
+		let metadata = Metadata::<Test>::get(0);
+		assert_eq!(metadata.deposit, 3);
+		assert_eq!(metadata.name, vec![0u8; limit]);
+		assert_eq!(metadata.symbol, vec![1u8; 3]);
+		assert_eq!(metadata.decimals, 12);
+		assert!(metadata.is_frozen);
+		assert_eq!(metadata.image_hash, None);
+
+		// Migrating again does nothing.
+		crate::migration::migrate_to_v2::<Test, ()>();
+		assert_eq!(Account::<Test>::get(0, 1).balance(), 100);
+	});
+}
+
+#[cfg(feature = "emit-account-events")]
+#[test]
+fn account_lifecycle_events_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::AccountCreated(0, 1)));
+
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 100));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::AccountCreated(0, 2)));
+		System::assert_has_event(mock::Event::pallet_assets(crate::Event::AccountDied(0, 1)));
+	});
+}
+
+#[cfg(feature = "emit-balance-changed-events")]
+#[test]
+fn balance_changed_events_should_work() {
+	new_test_ext().execute_with(|| {
+		let balance_changed = |who, old, new| {
+			mock::Event::pallet_assets(crate::Event::BalanceChanged(0, who, old, new))
+		};
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		System::assert_has_event(balance_changed(1, 0, 100));
+
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, 20));
+		System::assert_has_event(balance_changed(1, 100, 80));
+
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 30));
+		System::assert_has_event(balance_changed(1, 80, 50));
+		System::assert_has_event(balance_changed(2, 0, 30));
+
+		let targets: BoundedVec<(u64, u64), MaxFanOut> = vec![(2, 20), (3, 20)].try_into().unwrap();
+		assert_ok!(Assets::transfer_to_many(Origin::signed(1), 0, targets));
+		System::assert_has_event(balance_changed(1, 50, 10));
+		System::assert_has_event(balance_changed(2, 30, 50));
+		System::assert_has_event(balance_changed(3, 0, 20));
+	});
+}
+
+#[test]
+fn can_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+
+		assert_eq!(Assets::can_transfer(0, &1, &2, 50, false), Ok(50));
+		// Dust is taken along with the transfer.
+		assert_eq!(Assets::can_transfer(0, &1, &2, 95, false), Ok(100));
+		assert_noop!(Assets::can_transfer(0, &1, &2, 95, true), Error::<Test>::BalanceLow);
+		assert_noop!(Assets::can_transfer(0, &1, &2, 5, false), TokenError::BelowMinimum);
+		assert_noop!(Assets::can_transfer(0, &1, &2, 101, false), Error::<Test>::BalanceLow);
+		assert_noop!(Assets::can_transfer(1, &1, &2, 50, false), Error::<Test>::Unknown);
+
+		// Nothing was changed.
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::balance(0, 2), 0);
+	});
+}
+
+#[test]
+fn genesis_config_should_work() {
+	use frame_support::traits::GenesisBuild;
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let config = crate::GenesisConfig::<Test> {
+		assets: vec![(0, 1, true, 10), (1, 2, false, 1)],
+		metadata: vec![(0, b"Token".to_vec(), b"TKN".to_vec(), 12)],
+		accounts: vec![(0, 1, 100), (0, 2, 50)],
+		next_asset_id: 7,
+	};
+	GenesisBuild::<Test>::assimilate_storage(&config, &mut t).unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!(details.owner, 1);
+		assert_eq!(details.supply, 150);
+		assert_eq!(details.accounts, 2);
+		assert_eq!(details.sufficients, 2);
+		assert!(Asset::<Test>::get(1).is_some());
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::balance(0, 2), 50);
+
+		let metadata = Metadata::<Test>::get(0);
+		assert_eq!(metadata.name, b"Token".to_vec());
+		assert_eq!(metadata.symbol, b"TKN".to_vec());
+		assert_eq!(metadata.decimals, 12);
+		assert_eq!(metadata.deposit, 0);
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
+		assert_eq!(NextAssetId::<Test>::get(), 7);
+		assert_eq!(Assets::aggregated_balance(&1), vec![(0, 100)]);
+	});
+}
+
+#[test]
+fn check_invariants_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1, None));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, false, 1, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 1, 100));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 50));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 2);
+		assert_ok!(Assets::check_invariants());
+
+		assert_ok!(Assets::transfer_approved(Origin::signed(2), 0, 1, 4, 50));
+		assert_ok!(Assets::cancel_approval(Origin::signed(1), 0, 3));
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 0);
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 100));
+		assert_ok!(Assets::check_invariants());
+
+		// Accounts created with `touch` may be empty, but no others.
+		Balances::make_free_balance_be(&5, 100);
+		assert_ok!(Assets::touch(Origin::signed(5), 1));
+		assert_ok!(Assets::check_invariants());
+		Account::<Test>::insert(1, 6, AssetBalance::default());
+		Asset::<Test>::mutate(1, |d| d.as_mut().unwrap().accounts += 1);
+		assert_eq!(
+			Assets::check_invariants(),
+			Err("Asset has a stored account with neither a balance nor a deposit"),
+		);
+		Account::<Test>::remove(1, 6);
+		Asset::<Test>::mutate(1, |d| d.as_mut().unwrap().accounts -= 1);
+		assert_ok!(Assets::check_invariants());
+
+		Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().supply = 1);
+		assert_eq!(Assets::check_invariants(), Err("Asset supply does not match the stored balances"));
+		Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().accounts = 1);
+		assert_eq!(Assets::check_invariants(), Err("Asset accounts do not match the stored accounts"));
+	});
+}
+
+#[test]
+fn on_transfer_hooks_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 0));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		// Taking the source below the minimum balance transfers the dust too.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 45));
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 2, 20));
+		assert_noop!(Assets::transfer(Origin::signed(2), 0, 3, 100), Error::<Test>::BalanceLow);
+
+		assert_eq!(movements(), vec![
+			Movement::Minted(0, 1, 100),
+			Movement::Transferred(0, 1, 2, 0),
+			Movement::Transferred(0, 1, 2, 50),
+			Movement::Transferred(0, 1, 2, 50),
+			Movement::Burned(0, 2, 20),
+		]);
+	});
+}
+
+#[test]
+fn max_zombies_migration_should_work() {
+	new_test_ext().execute_with(|| {
+		// owner, issuer, admin, freezer, supply, deposit, max_zombies, min_balance, zombies,
+		// accounts, is_frozen
+		let old_details = (1u64, 2u64, 3u64, 4u64, 150u64, 10u64, 5u32, 1u64, 1u32, 2u32, false);
+		sp_io::storage::set(&Asset::<Test>::hashed_key_for(0), &old_details.encode());
+		sp_io::storage::set(&Account::<Test>::hashed_key_for(0, 1), &(100u64, false, false).encode());
+		sp_io::storage::set(&Account::<Test>::hashed_key_for(0, 2), &(50u64, true, true).encode());
+		crate::migration::migrate_from_max_zombies::<Test, ()>();
+
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V1);
+		<Assets as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+		let details = Asset::<Test>::get(0).unwrap();
+		assert_eq!((details.owner, details.issuer, details.admin, details.freezer), (1, 2, 3, 4));
+		assert_eq!(details.supply, 150);
+		assert_eq!(details.deposit, 10);
+		assert_eq!(details.min_balance, 1);
+		assert!(!details.is_sufficient);
+		assert_eq!(details.accounts, 2);
+		assert_eq!(details.sufficients, 0);
+		assert_eq!(details.status, AssetStatus::Live);
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert!(Account::<Test>::get(0, 2).is_frozen);
+		assert!(!Account::<Test>::get(0, 2).sufficient);
+		assert_eq!(Account::<Test>::get(0, 2).deposit, None);
+		assert_ok!(Assets::check_invariants());
+	});
+}
+
+#[test]
+fn increase_balance_best_effort_should_work() {
+	use frame_support::traits::tokens::{DepositConsequence, fungibles::Unbalanced};
+	new_test_ext().execute_with(|| {
+		assert!(matches!(Assets::increase_balance_best_effort(0, &1, 100), (0, DepositConsequence::UnknownAsset)));
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, false, 10, None));
+
+		assert!(matches!(Assets::increase_balance_best_effort(0, &1, 5), (0, DepositConsequence::BelowMinimum)));
+		assert!(matches!(Assets::increase_balance_best_effort(0, &1, 100), (0, DepositConsequence::CannotCreate)));
+		Balances::make_free_balance_be(&1, 100);
+		assert!(matches!(Assets::increase_balance_best_effort(0, &1, 100), (100, DepositConsequence::Success)));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert!(matches!(Assets::increase_balance_best_effort(0, &1, u64::MAX), (0, DepositConsequence::Overflow)));
+		assert_eq!(Assets::increase_balance_at_most(0, &1, u64::MAX), 0);
+
+		// Pausing minting only holds back `do_mint`.
+		assert_ok!(Assets::pause_minting(Origin::signed(1), 0));
+		assert!(matches!(Assets::increase_balance_best_effort(0, &1, 100), (100, DepositConsequence::Success)));
+		assert_eq!(Assets::balance(0, 1), 200);
+
+		// A new account of a retiring asset cannot be created.
+		assert_ok!(Assets::retire_asset(Origin::signed(1), 0));
+		Balances::make_free_balance_be(&2, 100);
+		assert!(matches!(Assets::increase_balance_best_effort(0, &2, 100), (0, DepositConsequence::CannotCreate)));
+		assert!(matches!(Assets::increase_balance_best_effort(0, &1, 100), (100, DepositConsequence::Success)));
+	});
+}
+
+#[test]
+fn fungibles_hold_should_work() {
+	use frame_support::traits::tokens::fungibles::{InspectHold, MutateHold};
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 10, None));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert!(<Assets as InspectHold<_>>::can_hold(0, &1, 90));
+		assert!(!<Assets as InspectHold<_>>::can_hold(0, &1, 91));
+		assert_noop!(<Assets as MutateHold<_>>::hold(0, &2, 0), Error::<Test>::BalanceLow);
+
+		assert_ok!(<Assets as MutateHold<_>>::hold(0, &1, 60));
+		assert_eq!(<Assets as InspectHold<_>>::balance_on_hold(0, &1), 60);
+		assert_eq!(Assets::reducible_balance(0, &1, false), Ok(30));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 31), Error::<Test>::BalanceLow);
+
+		assert_noop!(<Assets as MutateHold<_>>::release(0, &1, 61, false), Error::<Test>::BalanceLow);
+		assert_eq!(<Assets as MutateHold<_>>::release(0, &1, 10, false), Ok(10));
+		assert_eq!(<Assets as MutateHold<_>>::transfer_held(0, &1, &2, 30, false, false), Ok(30));
+		assert_eq!(Assets::balance(0, 2), 30);
+		assert_eq!(<Assets as InspectHold<_>>::balance_on_hold(0, &1), 20);
+
+		// Moving more than the minimum balance onto hold in a new account fails.
+		assert_noop!(
+			<Assets as MutateHold<_>>::transfer_held(0, &1, &3, 20, false, true),
+			Error::<Test>::BalanceLow,
+		);
+		assert_eq!(<Assets as MutateHold<_>>::transfer_held(0, &1, &2, 50, true, true), Ok(20));
+		assert_eq!(Account::<Test>::get(0, 1).reserved(), 0);
+		assert_eq!(Account::<Test>::get(0, 2).reserved(), 20);
+		assert_eq!(Assets::balance(0, 2), 50);
+	});
+}
@@ -18,11 +18,17 @@
 //! Various basic types for use in the assets pallet.
 
 use super::*;
+use frame_support::traits::MaxEncodedLen;
 
 pub(super) type DepositBalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
+pub(super) type MetadataOf<T, I = ()> = AssetMetadata<
+	DepositBalanceOf<T, I>,
+	BoundedVec<u8, <T as Config<I>>::StringLimit>,
+	BoundedVec<u8, <T as Config<I>>::UriLimit>,
+>;
 
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
 pub struct AssetDetails<
 	Balance,
 	AccountId,
@@ -38,8 +44,13 @@ pub struct AssetDetails<
 	pub(super) freezer: AccountId,
 	/// The total supply across all accounts.
 	pub(super) supply: Balance,
+	/// The maximum total supply which may ever be in existence, if any.
+	pub(super) max_supply: Option<Balance>,
 	/// The balance deposited for this asset. This pays for the data stored here.
 	pub(super) deposit: DepositBalance,
+	/// The account from which `deposit` and the deposit of the asset's metadata are reserved.
+	/// This is the Owner, unless the ownership was forced to another account.
+	pub(super) depositor: AccountId,
 	/// The ED for virtual accounts.
 	pub(super) min_balance: Balance,
 	/// If `true`, then any account with this asset is given a provider reference. Otherwise, it
@@ -53,6 +64,35 @@ pub struct AssetDetails<
 	pub(super) approvals: u32,
 	/// Whether the asset is frozen for non-admin transfers.
 	pub(super) is_frozen: bool,
+	/// The status of the asset.
+	pub(super) status: AssetStatus,
+	/// The proportion of each transfer taken as a fee, and the account the fee is paid to.
+	pub(super) transfer_fee: Option<(Perbill, AccountId)>,
+	/// Whether minting is paused, independently of `is_frozen`.
+	pub(super) disable_mint: bool,
+	/// The number of ownership transfers authorised by an off-chain signature of the owner, used
+	/// to prevent such an authorisation from being replayed.
+	pub(super) owner_nonce: u32,
+}
+
+/// The lifecycle status of an asset class.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub enum AssetStatus {
+	/// The asset is active and able to be used.
+	Live,
+	/// The asset is being destroyed with `destroy_accounts` and `destroy_approvals`. It may no
+	/// longer be minted, burned or transferred.
+	Destroying,
+	/// The asset is being wound down after `retire_asset`. It may still be burned and transferred
+	/// between existing accounts, but it may not be minted and neither new accounts nor new
+	/// approvals may be created.
+	Retiring,
+}
+
+impl Default for AssetStatus {
+	fn default() -> Self {
+		AssetStatus::Live
+	}
 }
 
 impl<Balance, AccountId, DepositBalance> AssetDetails<Balance, AccountId, DepositBalance> {
@@ -63,10 +103,209 @@ impl<Balance, AccountId, DepositBalance> AssetDetails<Balance, AccountId, Deposi
 			approvals: self.approvals,
 		}
 	}
+
+	/// The account which can change the `owner`, `issuer`, `freezer` and `admin` accounts.
+	pub fn owner(&self) -> &AccountId {
+		&self.owner
+	}
+
+	/// The account which can mint tokens.
+	pub fn issuer(&self) -> &AccountId {
+		&self.issuer
+	}
+
+	/// The account which can thaw tokens, force transfers and burn tokens from any account.
+	pub fn admin(&self) -> &AccountId {
+		&self.admin
+	}
+
+	/// The account which can freeze tokens.
+	pub fn freezer(&self) -> &AccountId {
+		&self.freezer
+	}
+
+	/// Whether any account with this asset is given a provider reference.
+	pub fn is_sufficient(&self) -> bool {
+		self.is_sufficient
+	}
+
+	/// The total number of accounts.
+	pub fn accounts(&self) -> u32 {
+		self.accounts
+	}
+
+	/// The total number of accounts for which a self-sufficient reference is placed.
+	pub fn sufficients(&self) -> u32 {
+		self.sufficients
+	}
+
+	/// The total number of approvals.
+	pub fn approvals(&self) -> u32 {
+		self.approvals
+	}
+
+	/// Whether the asset is frozen for non-admin transfers.
+	pub fn is_frozen(&self) -> bool {
+		self.is_frozen
+	}
+
+	/// The status of the asset.
+	pub fn status(&self) -> AssetStatus {
+		self.status
+	}
+
+	/// The proportion of each transfer taken as a fee and the account the fee is paid to, if any.
+	pub fn transfer_fee(&self) -> Option<&(Perbill, AccountId)> {
+		self.transfer_fee.as_ref()
+	}
+
+	/// Whether new units of the asset may currently be minted.
+	pub fn can_mint(&self) -> bool {
+		!self.disable_mint
+	}
+
+	/// The nonce which the next `transfer_ownership_by_operator` authorisation must sign.
+	pub fn owner_nonce(&self) -> u32 {
+		self.owner_nonce
+	}
+}
+
+impl<Balance: Copy, AccountId, DepositBalance: Copy>
+	AssetDetails<Balance, AccountId, DepositBalance>
+{
+	/// The total supply across all accounts.
+	pub fn supply(&self) -> Balance {
+		self.supply
+	}
+
+	/// The maximum total supply which may ever be in existence, if any.
+	pub fn max_supply(&self) -> Option<Balance> {
+		self.max_supply
+	}
+
+	/// The balance deposited for this asset.
+	pub fn deposit(&self) -> DepositBalance {
+		self.deposit
+	}
+
+	/// The minimum balance any single account may have.
+	pub fn min_balance(&self) -> Balance {
+		self.min_balance
+	}
+}
+
+/// Builds the `AssetDetails` of a new, empty asset class, both for the pallet's own dispatchables
+/// and outside of them, such as in tests and the genesis configuration of other pallets.
+///
+/// All roles default to the owner, the deposit to zero, the asset has no maximum supply and it is
+/// not sufficient.
+#[derive(Clone, RuntimeDebug)]
+pub struct AssetDetailsBuilder<Balance, AccountId, DepositBalance> {
+	owner: AccountId,
+	issuer: AccountId,
+	admin: AccountId,
+	freezer: AccountId,
+	max_supply: Option<Balance>,
+	min_balance: Balance,
+	is_sufficient: bool,
+	deposit: DepositBalance,
+}
+
+impl<Balance: Zero, AccountId: Clone, DepositBalance: Zero>
+	AssetDetailsBuilder<Balance, AccountId, DepositBalance>
+{
+	/// Start building an asset class owned by `owner`.
+	pub fn new(owner: AccountId) -> Self {
+		Self {
+			owner: owner.clone(),
+			issuer: owner.clone(),
+			admin: owner.clone(),
+			freezer: owner,
+			max_supply: None,
+			min_balance: Zero::zero(),
+			is_sufficient: false,
+			deposit: Zero::zero(),
+		}
+	}
+
+	/// Set the account which can change the `owner`, `issuer`, `freezer` and `admin` accounts.
+	pub fn owner(mut self, owner: AccountId) -> Self {
+		self.owner = owner;
+		self
+	}
+
+	/// Set the account which can mint tokens.
+	pub fn issuer(mut self, issuer: AccountId) -> Self {
+		self.issuer = issuer;
+		self
+	}
+
+	/// Set the account which can thaw tokens, force transfers and burn tokens from any account.
+	pub fn admin(mut self, admin: AccountId) -> Self {
+		self.admin = admin;
+		self
+	}
+
+	/// Set the account which can freeze tokens.
+	pub fn freezer(mut self, freezer: AccountId) -> Self {
+		self.freezer = freezer;
+		self
+	}
+
+	/// Set the maximum total supply of the asset, if any.
+	pub fn max_supply(mut self, max_supply: Option<Balance>) -> Self {
+		self.max_supply = max_supply;
+		self
+	}
+
+	/// Set the minimum balance any single account may have. This must be set to a non-zero value.
+	pub fn min_balance(mut self, min_balance: Balance) -> Self {
+		self.min_balance = min_balance;
+		self
+	}
+
+	/// Set whether any account with this asset is given a provider reference.
+	pub fn is_sufficient(mut self, is_sufficient: bool) -> Self {
+		self.is_sufficient = is_sufficient;
+		self
+	}
+
+	/// Set the balance deposited for this asset.
+	pub fn deposit(mut self, deposit: DepositBalance) -> Self {
+		self.deposit = deposit;
+		self
+	}
+
+	/// Construct the `AssetDetails` of a live asset with no supply, accounts or approvals.
+	///
+	/// Panics if the minimum balance is zero.
+	pub fn build(self) -> AssetDetails<Balance, AccountId, DepositBalance> {
+		assert!(!self.min_balance.is_zero(), "Min balance should not be zero");
+		AssetDetails {
+			owner: self.owner.clone(),
+			issuer: self.issuer,
+			admin: self.admin,
+			freezer: self.freezer,
+			supply: Zero::zero(),
+			max_supply: self.max_supply,
+			deposit: self.deposit,
+			depositor: self.owner,
+			min_balance: self.min_balance,
+			is_sufficient: self.is_sufficient,
+			accounts: 0,
+			sufficients: 0,
+			approvals: 0,
+			is_frozen: false,
+			status: AssetStatus::Live,
+			transfer_fee: None,
+			disable_mint: false,
+			owner_nonce: 0,
+		}
+	}
 }
 
 /// Data concerning an approval.
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, MaxEncodedLen)]
 pub struct Approval<Balance, DepositBalance> {
 	/// The amount of funds approved for the balance transfer from the owner to some delegated
 	/// target.
@@ -75,32 +314,189 @@ pub struct Approval<Balance, DepositBalance> {
 	pub(super) deposit: DepositBalance,
 }
 
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
-pub struct AssetBalance<Balance, Extra> {
+impl<Balance: Copy, DepositBalance> Approval<Balance, DepositBalance> {
+	/// The amount of funds the delegate may still transfer.
+	pub fn amount(&self) -> Balance {
+		self.amount
+	}
+}
+
+/// Why withdrawals of an asset from an account are suspended.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub enum FrozenKind {
+	/// The account itself has been frozen by the asset's Freezer.
+	Account,
+	/// The whole asset class has been frozen, suspending all transfers of it.
+	AssetClass,
+}
+
+/// The identifier of a swap proposal.
+pub type SwapId = u32;
+
+/// A proposal to swap some of one asset for some of another.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub struct SwapProposal<AssetId, AccountId, Balance, BlockNumber> {
+	/// The account which made the proposal and whose offered funds are held.
+	pub(super) proposer: AccountId,
+	/// The asset offered by the proposer.
+	pub(super) offer_asset: AssetId,
+	/// The amount of the offered asset, which is held until the proposal is accepted or
+	/// cancelled.
+	pub(super) offer_amount: Balance,
+	/// The asset wanted in return.
+	pub(super) want_asset: AssetId,
+	/// The amount of the wanted asset.
+	pub(super) want_amount: Balance,
+	/// The account which receives the wanted asset.
+	pub(super) dest: AccountId,
+	/// The last block in which the proposal may be accepted.
+	pub(super) expiry: BlockNumber,
+}
+
+/// The identifier of a scheduled transfer.
+pub type ScheduleId = u32;
+
+/// A transfer to be executed in a future block.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub struct ScheduledTransferRecord<AssetId, AccountId, Balance> {
+	/// The asset to be transferred.
+	pub(super) id: AssetId,
+	/// The account from which the asset is transferred and whose funds are held until then.
+	pub(super) source: AccountId,
+	/// The account to which the asset is transferred.
+	pub(super) dest: AccountId,
+	/// The amount to be transferred.
+	pub(super) amount: Balance,
+}
+
+/// One of the transfers made together by `multi_asset_transfer`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct MultiAssetTransfer<AssetId, AccountId, Balance> {
+	/// The asset to be transferred.
+	pub asset: AssetId,
+	/// The account from which the asset is transferred. This is either the sender or an account
+	/// which has approved the sender to transfer at least `amount`.
+	pub source: AccountId,
+	/// The account to which the asset is transferred.
+	pub dest: AccountId,
+	/// The amount to be transferred.
+	pub amount: Balance,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, MaxEncodedLen)]
+pub struct AssetBalance<Balance, DepositBalance, Extra> {
 	/// The balance.
 	pub(super) balance: Balance,
+	/// The part of `balance` reserved by holds, such as those of open swap proposals and pending
+	/// scheduled transfers. This may not be moved out of the account until it is released.
+	pub(super) reserved: Balance,
 	/// Whether the account is frozen.
 	pub(super) is_frozen: bool,
 	/// `true` if this balance gave the account a self-sufficient reference.
 	pub(super) sufficient: bool,
+	/// The amount reserved by the account holder in order to explicitly create this account with
+	/// `touch`, if any. An account with a deposit is not removed when its balance reaches zero;
+	/// it must be removed with `refund`.
+	pub(super) deposit: Option<DepositBalance>,
 	/// Additional "sidecar" data, in case some other pallet wants to use this storage item.
 	pub(super) extra: Extra,
 }
 
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default)]
-pub struct AssetMetadata<DepositBalance> {
+impl<Balance: Copy, DepositBalance, Extra> AssetBalance<Balance, DepositBalance, Extra> {
+	/// The balance.
+	pub fn balance(&self) -> Balance {
+		self.balance
+	}
+
+	/// The part of the balance reserved by holds.
+	pub fn reserved(&self) -> Balance {
+		self.reserved
+	}
+
+	/// Whether the account is frozen.
+	pub fn is_frozen(&self) -> bool {
+		self.is_frozen
+	}
+
+	/// The additional "sidecar" data of the account.
+	pub fn extra(&self) -> &Extra {
+		&self.extra
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, MaxEncodedLen)]
+pub struct AssetMetadata<DepositBalance, BoundedString, BoundedUri> {
 	/// The balance deposited for this metadata.
 	///
-	/// This pays for the data stored in this struct.
+	/// This pays for the data stored in this struct, including any `uri`.
 	pub(super) deposit: DepositBalance,
 	/// The user friendly name of this asset. Limited in length by `StringLimit`.
-	pub(super) name: Vec<u8>,
+	pub(super) name: BoundedString,
 	/// The ticker symbol for this asset. Limited in length by `StringLimit`.
-	pub(super) symbol: Vec<u8>,
+	pub(super) symbol: BoundedString,
 	/// The number of decimals this asset uses to represent one unit.
 	pub(super) decimals: u8,
 	/// Whether the asset metadata may be changed by a non Force origin.
 	pub(super) is_frozen: bool,
+	/// A hash identifying the icon of this asset off-chain, if any. It is not validated.
+	pub(super) image_hash: Option<[u8; 32]>,
+	/// A URI locating richer metadata of this asset off-chain, if any. Limited in length by
+	/// `UriLimit`.
+	pub(super) uri: Option<BoundedUri>,
+}
+
+impl<DepositBalance, BoundedString, BoundedUri>
+	AssetMetadata<DepositBalance, BoundedString, BoundedUri>
+{
+	/// The user friendly name of this asset.
+	pub fn name(&self) -> &BoundedString {
+		&self.name
+	}
+
+	/// The ticker symbol for this asset.
+	pub fn symbol(&self) -> &BoundedString {
+		&self.symbol
+	}
+
+	/// The number of decimals this asset uses to represent one unit.
+	pub fn decimals(&self) -> u8 {
+		self.decimals
+	}
+
+	/// Whether the asset metadata may be changed by a non Force origin.
+	pub fn is_frozen(&self) -> bool {
+		self.is_frozen
+	}
+
+	/// A hash identifying the icon of this asset off-chain, if any.
+	pub fn image_hash(&self) -> Option<&[u8; 32]> {
+		self.image_hash.as_ref()
+	}
+
+	/// A URI locating richer metadata of this asset off-chain, if any.
+	pub fn uri(&self) -> Option<&BoundedUri> {
+		self.uri.as_ref()
+	}
+}
+
+// A value placed in storage that represents the current version of the Assets storage.
+// This value is used by the `on_runtime_upgrade` logic to determine whether we run
+// storage migration logic.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+pub(super) enum Releases {
+	/// The layout of the first release, before the storage version was recorded.
+	V1,
+	/// `AssetDetails` track `max_supply`, `depositor`, `status`, `transfer_fee`, `disable_mint` and
+	/// `owner_nonce`; `AssetBalance` tracks the `reserved` part of each account's balance and its
+	/// `deposit`; `AccountAssets` indexes the assets held by each account; and metadata has its
+	/// `name` and `symbol` bounded by `StringLimit`, together with an `image_hash` and `uri`.
+	V2,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V1
+	}
 }
 
 /// Witness data for the destroy transactions.
@@ -117,6 +513,42 @@ pub struct DestroyWitness {
 	pub(super) approvals: u32,
 }
 
+impl DestroyWitness {
+	/// The number of accounts holding the asset.
+	pub fn accounts(&self) -> u32 {
+		self.accounts
+	}
+
+	/// The number of accounts holding the asset with a self-sufficient reference.
+	pub fn sufficients(&self) -> u32 {
+		self.sufficients
+	}
+
+	/// The number of transfer-approvals of the asset.
+	pub fn approvals(&self) -> u32 {
+		self.approvals
+	}
+}
+
+/// Which memos are accepted by `transfer_with_memo`.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub enum MemoEncoding {
+	/// The memo must be valid UTF-8.
+	Utf8,
+	/// The memo may be any bytes.
+	Raw,
+}
+
+impl MemoEncoding {
+	/// Whether `memo` is valid in this encoding.
+	pub fn validate(&self, memo: &[u8]) -> bool {
+		match self {
+			MemoEncoding::Utf8 => sp_std::str::from_utf8(memo).is_ok(),
+			MemoEncoding::Raw => true,
+		}
+	}
+}
+
 /// Trait for allowing a minimum balance on the account to be specified, beyond the
 /// `minimum_balance` of the asset. This is additive - the `minimum_balance` of the asset must be
 /// met *and then* anything here in addition.
@@ -134,6 +566,15 @@ pub trait FrozenBalance<AssetId, AccountId, Balance> {
 	/// privileged intervention), then `melted` is called to do any cleanup.
 	fn frozen_balance(asset: AssetId, who: &AccountId) -> Option<Balance>;
 
+	/// Return why the balance is frozen: a label naming each source of the freeze, such as
+	/// `b"vesting"`, together with the amount it freezes.
+	///
+	/// This is for display only, such as by wallets explaining why funds are locked; nothing is
+	/// enforced from it. By default, no reasons are given.
+	fn freeze_reasons(_asset: AssetId, _who: &AccountId) -> Vec<(&'static [u8], Balance)> {
+		Vec::new()
+	}
+
 	/// Called when an account has been removed.
 	fn died(asset: AssetId, who: &AccountId);
 }
@@ -143,6 +584,126 @@ impl<AssetId, AccountId, Balance> FrozenBalance<AssetId, AccountId, Balance> for
 	fn died(_: AssetId, _: &AccountId) {}
 }
 
+/// Trait for hooking into the movement of asset balances.
+///
+/// The hooks are called once the movement has been made and cannot fail.
+pub trait OnTransfer<AssetId, AccountId, Balance> {
+	/// Called after `amount` of `asset` has been transferred from `from` to `to`, including for
+	/// transfers of zero.
+	fn on_transfer(asset: AssetId, from: &AccountId, to: &AccountId, amount: Balance);
+
+	/// Called after `amount` of `asset` has been minted into the account of `who`.
+	fn on_mint(asset: AssetId, who: &AccountId, amount: Balance);
+
+	/// Called after `amount` of `asset` has been burned from the account of `who`.
+	fn on_burn(asset: AssetId, who: &AccountId, amount: Balance);
+}
+
+impl<AssetId, AccountId, Balance> OnTransfer<AssetId, AccountId, Balance> for () {
+	fn on_transfer(_: AssetId, _: &AccountId, _: &AccountId, _: Balance) {}
+	fn on_mint(_: AssetId, _: &AccountId, _: Balance) {}
+	fn on_burn(_: AssetId, _: &AccountId, _: Balance) {}
+}
+
+/// Trait for approving transfers of asset balances before they are made, such as for compliance
+/// checks.
+///
+/// The guard of an asset set by its Owner, if any, can be found with `Pallet::transfer_guard`.
+pub trait AssetTransferGuard<AssetId, AccountId, Balance> {
+	/// Return `Ok` if `amount` of `asset` may be transferred from `from` to `to`. Any error aborts
+	/// the transfer and is returned by it.
+	///
+	/// Called before every transfer other than those forced by the asset's Admin.
+	fn allow_transfer(
+		asset: AssetId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
+}
+
+impl<AssetId, AccountId, Balance> AssetTransferGuard<AssetId, AccountId, Balance> for () {
+	fn allow_transfer(_: AssetId, _: &AccountId, _: &AccountId, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Combine two optional frozen balances, such that both are respected.
+fn max_frozen<Balance: Ord>(a: Option<Balance>, b: Option<Balance>) -> Option<Balance> {
+	match (a, b) {
+		(Some(a), Some(b)) => Some(a.max(b)),
+		(a, None) => a,
+		(None, b) => b,
+	}
+}
+
+/// Two freezers, both of which must be respected; the frozen balance is the greater of the two.
+impl<AssetId, AccountId, Balance, A, B> FrozenBalance<AssetId, AccountId, Balance> for (A, B) where
+	AssetId: Copy,
+	Balance: Ord,
+	A: FrozenBalance<AssetId, AccountId, Balance>,
+	B: FrozenBalance<AssetId, AccountId, Balance>,
+{
+	fn frozen_balance(asset: AssetId, who: &AccountId) -> Option<Balance> {
+		max_frozen(A::frozen_balance(asset, who), B::frozen_balance(asset, who))
+	}
+
+	fn freeze_reasons(asset: AssetId, who: &AccountId) -> Vec<(&'static [u8], Balance)> {
+		let mut reasons = A::freeze_reasons(asset, who);
+		reasons.extend(B::freeze_reasons(asset, who));
+		reasons
+	}
+
+	fn died(asset: AssetId, who: &AccountId) {
+		A::died(asset, who);
+		B::died(asset, who);
+	}
+}
+
+/// Three freezers, all of which must be respected; the frozen balance is the greatest of the three.
+impl<AssetId, AccountId, Balance, A, B, C> FrozenBalance<AssetId, AccountId, Balance> for (A, B, C) where
+	AssetId: Copy,
+	Balance: Ord,
+	A: FrozenBalance<AssetId, AccountId, Balance>,
+	B: FrozenBalance<AssetId, AccountId, Balance>,
+	C: FrozenBalance<AssetId, AccountId, Balance>,
+{
+	fn frozen_balance(asset: AssetId, who: &AccountId) -> Option<Balance> {
+		max_frozen(<(A, B)>::frozen_balance(asset, who), C::frozen_balance(asset, who))
+	}
+
+	fn freeze_reasons(asset: AssetId, who: &AccountId) -> Vec<(&'static [u8], Balance)> {
+		let mut reasons = <(A, B)>::freeze_reasons(asset, who);
+		reasons.extend(C::freeze_reasons(asset, who));
+		reasons
+	}
+
+	fn died(asset: AssetId, who: &AccountId) {
+		<(A, B)>::died(asset, who);
+		C::died(asset, who);
+	}
+}
+
+/// An asset identifier which can be allocated sequentially by `create_auto`.
+pub trait Incrementable {
+	/// The identifier following this one, wrapping around on overflow.
+	fn increment(&self) -> Self;
+}
+
+macro_rules! impl_incrementable {
+	($($t:ty),+) => {
+		$(
+			impl Incrementable for $t {
+				fn increment(&self) -> Self {
+					self.wrapping_add(1)
+				}
+			}
+		)+
+	};
+}
+
+impl_incrementable!(u8, u16, u32, u64, u128);
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub(super) struct TransferFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
@@ -155,6 +716,9 @@ pub(super) struct TransferFlags {
 	/// Any additional funds debited (due to minimum balance requirements) should be burned rather
 	/// than credited to the destination account.
 	pub(super) burn_dust: bool,
+	/// Any freezes on the account or on the asset class should be respected. Balance frozen by
+	/// `T::Freezer` is respected regardless.
+	pub(super) respect_freezer: bool,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -166,6 +730,9 @@ pub(super) struct DebitFlags {
 	/// successful. If `false`, then the amount debited will always be at least the amount
 	/// specified.
 	pub(super) best_effort: bool,
+	/// Any freezes on the account or on the asset class should be respected. Balance frozen by
+	/// `T::Freezer` is respected regardless.
+	pub(super) respect_freezer: bool,
 }
 
 impl From<TransferFlags> for DebitFlags {
@@ -173,6 +740,7 @@ impl From<TransferFlags> for DebitFlags {
 		Self {
 			keep_alive: f.keep_alive,
 			best_effort: f.best_effort,
+			respect_freezer: f.respect_freezer,
 		}
 	}
 }
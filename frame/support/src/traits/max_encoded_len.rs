@@ -18,6 +18,7 @@
 use codec::{Compact, Encode};
 use impl_trait_for_tuples::impl_for_tuples;
 use sp_std::{mem, marker::PhantomData};
+use sp_arithmetic::{PerThing, PerU16, Perbill, Percent, Permill, Perquintill};
 
 /// Items implementing `MaxEncodedLen` have a statically known maximum encoded size.
 ///
@@ -69,6 +70,20 @@ impl_compact!(
 	u128 => 17;
 );
 
+macro_rules! impl_per_things {
+	( $($t:ty),+ ) => {
+		$(
+			impl MaxEncodedLen for $t {
+				fn max_encoded_len() -> usize {
+					<<$t as PerThing>::Inner as MaxEncodedLen>::max_encoded_len()
+				}
+			}
+		)+
+	};
+}
+
+impl_per_things!(Percent, PerU16, Permill, Perbill, Perquintill);
+
 // impl_for_tuples for values 19 and higher fails because that's where the WrapperTypeEncode impl stops.
 #[impl_for_tuples(18)]
 impl MaxEncodedLen for Tuple {
@@ -129,4 +144,23 @@ mod tests {
 		fn compact_u64(u64);
 		fn compact_u128(u128);
 	);
+
+	macro_rules! test_per_thing_length {
+		($(fn $name:ident($t:ty);)*) => {
+			$(
+				#[test]
+				fn $name() {
+					assert_eq!(<$t>::one().encode().len(), <$t>::max_encoded_len());
+				}
+			)*
+		};
+	}
+
+	test_per_thing_length!(
+		fn percent(Percent);
+		fn per_u16(PerU16);
+		fn permill(Permill);
+		fn perbill(Perbill);
+		fn perquintill(Perquintill);
+	);
 }
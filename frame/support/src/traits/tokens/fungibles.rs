@@ -20,7 +20,7 @@
 use super::*;
 use crate::dispatch::{DispatchError, DispatchResult};
 use super::misc::{AssetId, Balance};
-use sp_runtime::traits::Saturating;
+use sp_runtime::traits::{Saturating, Zero};
 
 mod balanced;
 pub use balanced::{Balanced, Unbalanced};
@@ -58,6 +58,19 @@ pub trait Inspect<AccountId> {
 		who: &AccountId,
 		amount: Self::Balance,
 	) -> WithdrawConsequence<Self::Balance>;
+
+	/// Returns `true` if `asset` exists.
+	///
+	/// The default implementation only recognises assets with some issuance; implementations
+	/// able to tell apart existing assets with no issuance should override it.
+	fn asset_exists(asset: Self::AssetId) -> bool {
+		!Self::total_issuance(asset).is_zero()
+	}
+
+	/// Returns `true` if `who` is frozen from transferring any of `asset`.
+	fn is_account_frozen(_asset: Self::AssetId, _who: &AccountId) -> bool {
+		false
+	}
 }
 
 /// Trait for providing a set of named fungible assets which can be created and destroyed.
@@ -140,6 +153,50 @@ pub trait Transfer<AccountId>: Inspect<AccountId> {
 	) -> Result<Self::Balance, DispatchError>;
 }
 
+/// Trait for enumerating a set of named fungible assets and their holders.
+pub trait InspectEnumerable<AccountId>: Inspect<AccountId> {
+	/// Iterator over all existing asset identifiers.
+	type AssetsIterator: Iterator<Item = Self::AssetId>;
+
+	/// Iterator over all accounts holding some asset, together with their balances.
+	type AccountBalancesIterator: Iterator<Item = (AccountId, Self::Balance)>;
+
+	/// Returns an iterator of all existing assets.
+	///
+	/// Entries are read lazily; nothing is loaded into memory ahead of iteration.
+	fn asset_ids() -> Self::AssetsIterator;
+
+	/// Returns an iterator of all accounts holding `asset` and their balances.
+	///
+	/// Entries are read lazily; nothing is loaded into memory ahead of iteration.
+	fn account_balances(asset: Self::AssetId) -> Self::AccountBalancesIterator;
+}
+
+/// Trait for querying the accounts holding the privileged roles of a set of named fungible assets.
+pub trait Roles<AccountId>: Inspect<AccountId> {
+	/// The Owner of `asset`, if it exists.
+	fn owner(asset: Self::AssetId) -> Option<AccountId>;
+
+	/// The Issuer of `asset`, if it exists.
+	fn issuer(asset: Self::AssetId) -> Option<AccountId>;
+
+	/// The Admin of `asset`, if it exists.
+	fn admin(asset: Self::AssetId) -> Option<AccountId>;
+
+	/// The Freezer of `asset`, if it exists.
+	fn freezer(asset: Self::AssetId) -> Option<AccountId>;
+
+	/// Returns `Ok` if `who` is the Owner of `asset`, and `BadOrigin` otherwise.
+	fn ensure_owner(asset: Self::AssetId, who: &AccountId) -> DispatchResult where
+		AccountId: PartialEq,
+	{
+		match Self::owner(asset) {
+			Some(ref owner) if owner == who => Ok(()),
+			_ => Err(DispatchError::BadOrigin),
+		}
+	}
+}
+
 /// Trait for inspecting a set of named fungible assets which can be placed on hold.
 pub trait InspectHold<AccountId>: Inspect<AccountId> {
 	/// Amount of funds held in hold.
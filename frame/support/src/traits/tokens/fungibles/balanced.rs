@@ -254,18 +254,32 @@ pub trait Unbalanced<AccountId>: Inspect<AccountId> {
 	/// Return the imbalance by which the account was increased.
 	fn increase_balance_at_most(asset: Self::AssetId, who: &AccountId, amount: Self::Balance)
 		-> Self::Balance
+	{
+		Self::increase_balance_best_effort(asset, who, amount).0
+	}
+
+	/// Increase the `asset` balance of `who` by the most that is possible, up to `amount`, as
+	/// `increase_balance_at_most` does.
+	///
+	/// Return the imbalance by which the account was increased, together with the consequence
+	/// explaining why it falls short of `amount`, or `DepositConsequence::Success` if it doesn't.
+	fn increase_balance_best_effort(asset: Self::AssetId, who: &AccountId, amount: Self::Balance)
+		-> (Self::Balance, DepositConsequence)
 	{
 		let old_balance = Self::balance(asset, who);
 		let mut new_balance = old_balance.saturating_add(amount);
-		let mut amount = new_balance - old_balance;
+		let mut increase = new_balance - old_balance;
 		if new_balance < Self::minimum_balance(asset) {
 			new_balance = Zero::zero();
-			amount = Zero::zero();
+			increase = Zero::zero();
+		}
+		if old_balance != new_balance && Self::set_balance(asset, who, new_balance).is_err() {
+			increase = Zero::zero();
 		}
-		if old_balance == new_balance || Self::set_balance(asset, who, new_balance).is_ok() {
-			amount
+		if increase == amount {
+			(increase, DepositConsequence::Success)
 		} else {
-			Zero::zero()
+			(increase, Self::can_deposit(asset, who, amount))
 		}
 	}
 }